@@ -0,0 +1,726 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::{aes, keystream, stream};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum AesAlgorithm {
+    Aes128Gcm,
+    Aes192Gcm,
+    Aes256Gcm,
+    Aes128Ctr,
+    Aes192Ctr,
+    Aes256Ctr,
+    Aes128Ccm,
+    Aes192Ccm,
+    Aes256Ccm,
+    Aes128Siv,
+    Aes256Siv,
+    Aes128GcmSiv,
+    Aes256GcmSiv,
+    Aes128Cbc,
+    Aes192Cbc,
+    Aes256Cbc,
+    Aes128Ecb,
+    Aes192Ecb,
+    Aes256Ecb,
+    Aes128Cfb8,
+    Aes192Cfb8,
+    Aes256Cfb8,
+    Aes128Cfb128,
+    Aes192Cfb128,
+    Aes256Cfb128,
+    Aes128Ofb,
+    Aes192Ofb,
+    Aes256Ofb,
+    Aes128Kw,
+    Aes192Kw,
+    Aes256Kw,
+    Aes128Kwp,
+    Aes192Kwp,
+    Aes256Kwp,
+    Aes128Xts,
+    Aes256Xts,
+    Aes128Ocb3,
+    Aes192Ocb3,
+    Aes256Ocb3,
+    Aes128Eax,
+    Aes192Eax,
+    Aes256Eax,
+}
+
+impl From<AesAlgorithm> for aes::AesAlgorithm {
+    fn from(algo: AesAlgorithm) -> Self {
+        match algo {
+            AesAlgorithm::Aes128Gcm => Self::Aes128Gcm,
+            AesAlgorithm::Aes192Gcm => Self::Aes192Gcm,
+            AesAlgorithm::Aes256Gcm => Self::Aes256Gcm,
+            AesAlgorithm::Aes128Ctr => Self::Aes128Ctr,
+            AesAlgorithm::Aes192Ctr => Self::Aes192Ctr,
+            AesAlgorithm::Aes256Ctr => Self::Aes256Ctr,
+            AesAlgorithm::Aes128Ccm => Self::Aes128Ccm,
+            AesAlgorithm::Aes192Ccm => Self::Aes192Ccm,
+            AesAlgorithm::Aes256Ccm => Self::Aes256Ccm,
+            AesAlgorithm::Aes128Siv => Self::Aes128Siv,
+            AesAlgorithm::Aes256Siv => Self::Aes256Siv,
+            AesAlgorithm::Aes128GcmSiv => Self::Aes128GcmSiv,
+            AesAlgorithm::Aes256GcmSiv => Self::Aes256GcmSiv,
+            AesAlgorithm::Aes128Cbc => Self::Aes128Cbc,
+            AesAlgorithm::Aes192Cbc => Self::Aes192Cbc,
+            AesAlgorithm::Aes256Cbc => Self::Aes256Cbc,
+            AesAlgorithm::Aes128Ecb => Self::Aes128Ecb,
+            AesAlgorithm::Aes192Ecb => Self::Aes192Ecb,
+            AesAlgorithm::Aes256Ecb => Self::Aes256Ecb,
+            AesAlgorithm::Aes128Cfb8 => Self::Aes128Cfb8,
+            AesAlgorithm::Aes192Cfb8 => Self::Aes192Cfb8,
+            AesAlgorithm::Aes256Cfb8 => Self::Aes256Cfb8,
+            AesAlgorithm::Aes128Cfb128 => Self::Aes128Cfb128,
+            AesAlgorithm::Aes192Cfb128 => Self::Aes192Cfb128,
+            AesAlgorithm::Aes256Cfb128 => Self::Aes256Cfb128,
+            AesAlgorithm::Aes128Ofb => Self::Aes128Ofb,
+            AesAlgorithm::Aes192Ofb => Self::Aes192Ofb,
+            AesAlgorithm::Aes256Ofb => Self::Aes256Ofb,
+            AesAlgorithm::Aes128Kw => Self::Aes128Kw,
+            AesAlgorithm::Aes192Kw => Self::Aes192Kw,
+            AesAlgorithm::Aes256Kw => Self::Aes256Kw,
+            AesAlgorithm::Aes128Kwp => Self::Aes128Kwp,
+            AesAlgorithm::Aes192Kwp => Self::Aes192Kwp,
+            AesAlgorithm::Aes256Kwp => Self::Aes256Kwp,
+            AesAlgorithm::Aes128Xts => Self::Aes128Xts,
+            AesAlgorithm::Aes256Xts => Self::Aes256Xts,
+            AesAlgorithm::Aes128Ocb3 => Self::Aes128Ocb3,
+            AesAlgorithm::Aes192Ocb3 => Self::Aes192Ocb3,
+            AesAlgorithm::Aes256Ocb3 => Self::Aes256Ocb3,
+            AesAlgorithm::Aes128Eax => Self::Aes128Eax,
+            AesAlgorithm::Aes192Eax => Self::Aes192Eax,
+            AesAlgorithm::Aes256Eax => Self::Aes256Eax,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum Padding {
+    Pkcs7,
+    Iso7816,
+    AnsiX923,
+    Iso10126,
+    ZeroPadding,
+    NoPadding,
+}
+
+impl From<Padding> for aes::Padding {
+    fn from(padding: Padding) -> Self {
+        match padding {
+            Padding::Pkcs7 => Self::Pkcs7,
+            Padding::Iso7816 => Self::Iso7816,
+            Padding::AnsiX923 => Self::AnsiX923,
+            Padding::Iso10126 => Self::Iso10126,
+            Padding::ZeroPadding => Self::ZeroPadding,
+            Padding::NoPadding => Self::NoPadding,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum CounterLayout {
+    Ctr32BE,
+    Ctr64BE,
+    Ctr128BE,
+    Ctr32LE,
+}
+
+impl From<CounterLayout> for keystream::CounterLayout {
+    fn from(layout: CounterLayout) -> Self {
+        match layout {
+            CounterLayout::Ctr32BE => Self::Ctr32BE,
+            CounterLayout::Ctr64BE => Self::Ctr64BE,
+            CounterLayout::Ctr128BE => Self::Ctr128BE,
+            CounterLayout::Ctr32LE => Self::Ctr32LE,
+        }
+    }
+}
+
+const PADDING_WITH_AAD: &str = "padding cannot be combined with associated data";
+
+/// `padding` applies to CBC and ECB, which default to PKCS#7 without it.
+#[wasm_bindgen]
+pub fn encrypt(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce_or_iv: Uint8Array,
+    algo: AesAlgorithm,
+    aad: Option<Uint8Array>,
+    padding: Option<Padding>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+
+    let key = secret_bytes(&key);
+
+    let nonce = input_bytes(&nonce_or_iv);
+
+    let aad = aad.as_ref().map(input_bytes);
+
+    let encrypted = match padding {
+        Some(_) if aad.is_some() => return Err(JsValue::from_str(PADDING_WITH_AAD)),
+        Some(padding) => aes::encrypt_padded(&data, &key, &nonce, padding.into(), algo.into()),
+        None => aes::encrypt_with_aad(
+            &data,
+            &key,
+            &nonce,
+            aad.as_deref().unwrap_or_default(),
+            algo.into(),
+        ),
+    }
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(encrypted.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn decrypt(
+    ciphertext: Uint8Array,
+    key: Uint8Array,
+    nonce_or_iv: Uint8Array,
+    algo: AesAlgorithm,
+    aad: Option<Uint8Array>,
+    padding: Option<Padding>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+
+    let key = secret_bytes(&key);
+
+    let nonce = input_bytes(&nonce_or_iv);
+
+    let aad = aad.as_ref().map(input_bytes);
+
+    let decrypted = match padding {
+        Some(_) if aad.is_some() => return Err(JsValue::from_str(PADDING_WITH_AAD)),
+        Some(padding) => aes::decrypt_padded(&data, &key, &nonce, padding.into(), algo.into()),
+        None => aes::decrypt_with_aad(
+            &data,
+            &key,
+            &nonce,
+            aad.as_deref().unwrap_or_default(),
+            algo.into(),
+        ),
+    }
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(decrypted.as_slice()))
+}
+
+/// AES-KW (RFC 3394) or AES-KWP (RFC 5649), selected by `algo`.
+#[wasm_bindgen]
+pub fn wrap_key(
+    key_data: Uint8Array,
+    kek: Uint8Array,
+    algo: AesAlgorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = secret_bytes(&key_data);
+
+    let kek = secret_bytes(&kek);
+
+    let wrapped =
+        aes::wrap_key(&data, &kek, algo.into()).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(wrapped.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn unwrap_key(
+    wrapped: Uint8Array,
+    kek: Uint8Array,
+    algo: AesAlgorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&wrapped);
+
+    let kek = secret_bytes(&kek);
+
+    let unwrapped =
+        aes::unwrap_key(&data, &kek, algo.into()).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(unwrapped.as_slice()))
+}
+
+/// AES-XTS over consecutive `sector_size`-byte sectors numbered from
+/// `first_sector`.
+#[wasm_bindgen]
+pub fn xts_encrypt_sectors(
+    data: Uint8Array,
+    key: Uint8Array,
+    sector_size: usize,
+    first_sector: u64,
+    algo: AesAlgorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&data);
+
+    let key = secret_bytes(&key);
+
+    let encrypted = aes::xts_encrypt_sectors(&data, &key, sector_size, first_sector, algo.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(encrypted.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn xts_decrypt_sectors(
+    data: Uint8Array,
+    key: Uint8Array,
+    sector_size: usize,
+    first_sector: u64,
+    algo: AesAlgorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&data);
+
+    let key = secret_bytes(&key);
+
+    let decrypted = aes::xts_decrypt_sectors(&data, &key, sector_size, first_sector, algo.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(decrypted.as_slice()))
+}
+
+/// Collects a JS array of `Uint8Array`s into SIV headers.
+fn siv_headers(headers: &Array) -> Result<Vec<Uint8Array>, JsValue> {
+    headers
+        .iter()
+        .map(|h| {
+            h.dyn_into::<Uint8Array>()
+                .map_err(|_| JsValue::from_str("SIV headers must be Uint8Arrays"))
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+pub fn siv_encrypt(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    headers: Array,
+    algo: AesAlgorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+
+    let key = secret_bytes(&key);
+
+    let headers = siv_headers(&headers)?;
+    let headers: Vec<_> = headers.iter().map(input_bytes).collect();
+    let headers: Vec<&[u8]> = headers.iter().map(|h| h.as_ref()).collect();
+
+    let encrypted = aes::siv_encrypt(&data, &key, &headers, algo.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(encrypted.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn siv_decrypt(
+    ciphertext: Uint8Array,
+    key: Uint8Array,
+    headers: Array,
+    algo: AesAlgorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+
+    let key = secret_bytes(&key);
+
+    let headers = siv_headers(&headers)?;
+    let headers: Vec<_> = headers.iter().map(input_bytes).collect();
+    let headers: Vec<&[u8]> = headers.iter().map(|h| h.as_ref()).collect();
+
+    let decrypted = aes::siv_decrypt(&data, &key, &headers, algo.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(decrypted.as_slice()))
+}
+
+/// Returns `[ciphertext, tag]`. `tag_length` defaults to 16 bytes.
+#[wasm_bindgen]
+pub fn encrypt_detached(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    algo: AesAlgorithm,
+    aad: Option<Uint8Array>,
+    tag_length: Option<usize>,
+) -> Result<Array, JsValue> {
+    let data = input_bytes(&plaintext);
+
+    let key = secret_bytes(&key);
+
+    let nonce = input_bytes(&nonce);
+
+    let aad = aad.as_ref().map(input_bytes);
+
+    let (ct, tag) = aes::encrypt_detached(
+        &data,
+        &key,
+        &nonce,
+        aad.as_deref().unwrap_or_default(),
+        tag_length.unwrap_or(16),
+        algo.into(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let arr = Array::new();
+    arr.push(&Uint8Array::from(ct.as_slice()));
+    arr.push(&Uint8Array::from(tag.as_slice()));
+    Ok(arr)
+}
+
+#[wasm_bindgen]
+pub fn decrypt_detached(
+    ciphertext: Uint8Array,
+    tag: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    algo: AesAlgorithm,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+
+    let tag = input_bytes(&tag);
+
+    let key = secret_bytes(&key);
+
+    let nonce = input_bytes(&nonce);
+
+    let aad = aad.as_ref().map(input_bytes);
+
+    let decrypted = aes::decrypt_detached(
+        &data,
+        &tag,
+        &key,
+        &nonce,
+        aad.as_deref().unwrap_or_default(),
+        algo.into(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(decrypted.as_slice()))
+}
+
+/// STREAM encryption over AES-GCM for inputs too large to buffer; see
+/// [`cryptographer_core::cipher::stream`].
+#[wasm_bindgen]
+pub struct StreamingEncryptor {
+    inner: stream::StreamingEncryptor,
+}
+
+#[wasm_bindgen]
+impl StreamingEncryptor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        nonce: Uint8Array,
+        algo: AesAlgorithm,
+        segment_size: Option<usize>,
+    ) -> Result<StreamingEncryptor, JsValue> {
+        let key = secret_bytes(&key);
+
+        let nonce = input_bytes(&nonce);
+
+        let inner = aes::AesAlgorithm::from(algo)
+            .try_into()
+            .and_then(|algo| {
+                stream::StreamingEncryptor::new(
+                    &key,
+                    &nonce,
+                    algo,
+                    segment_size.unwrap_or(stream::DEFAULT_SEGMENT_SIZE),
+                )
+            })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingEncryptor { inner })
+    }
+
+    pub fn update(&mut self, plaintext: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+
+        let encrypted = self
+            .inner
+            .update(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+
+    pub fn finalize(&mut self) -> Result<Uint8Array, JsValue> {
+        let encrypted = self
+            .inner
+            .finalize()
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+}
+
+#[wasm_bindgen]
+pub struct StreamingDecryptor {
+    inner: stream::StreamingDecryptor,
+}
+
+#[wasm_bindgen]
+impl StreamingDecryptor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        nonce: Uint8Array,
+        algo: AesAlgorithm,
+        segment_size: Option<usize>,
+    ) -> Result<StreamingDecryptor, JsValue> {
+        let key = secret_bytes(&key);
+
+        let nonce = input_bytes(&nonce);
+
+        let inner = aes::AesAlgorithm::from(algo)
+            .try_into()
+            .and_then(|algo| {
+                stream::StreamingDecryptor::new(
+                    &key,
+                    &nonce,
+                    algo,
+                    segment_size.unwrap_or(stream::DEFAULT_SEGMENT_SIZE),
+                )
+            })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingDecryptor { inner })
+    }
+
+    pub fn update(&mut self, ciphertext: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+
+        let decrypted = self
+            .inner
+            .update(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+
+    pub fn finalize(&mut self) -> Result<Uint8Array, JsValue> {
+        let decrypted = self
+            .inner
+            .finalize()
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+}
+
+/// AES-GCM keyed once. `free()` drops the expanded key, which is zeroized.
+#[wasm_bindgen]
+pub struct AesGcmCipher {
+    inner: aes::AesGcmCipher,
+}
+
+#[wasm_bindgen]
+impl AesGcmCipher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(key: Uint8Array, algo: AesAlgorithm) -> Result<AesGcmCipher, JsValue> {
+        let key = secret_bytes(&key);
+
+        let inner = aes::AesGcmCipher::new(&key, algo.into())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(AesGcmCipher { inner })
+    }
+
+    pub fn encrypt(
+        &self,
+        plaintext: Uint8Array,
+        nonce: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+
+        let nonce = input_bytes(&nonce);
+
+        let aad = aad.as_ref().map(input_bytes);
+
+        let encrypted = self
+            .inner
+            .encrypt(&data, &nonce, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+
+    pub fn decrypt(
+        &self,
+        ciphertext: Uint8Array,
+        nonce: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+
+        let nonce = input_bytes(&nonce);
+
+        let aad = aad.as_ref().map(input_bytes);
+
+        let decrypted = self
+            .inner
+            .decrypt(&data, &nonce, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+}
+
+/// AES-SIV keyed once. `free()` drops the key, which is zeroized.
+#[wasm_bindgen]
+pub struct AesSivCipher {
+    inner: aes::AesSivCipher,
+}
+
+#[wasm_bindgen]
+impl AesSivCipher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(key: Uint8Array, algo: AesAlgorithm) -> Result<AesSivCipher, JsValue> {
+        let key = secret_bytes(&key);
+
+        let inner = aes::AesSivCipher::new(&key, algo.into())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(AesSivCipher { inner })
+    }
+
+    pub fn encrypt(
+        &mut self,
+        plaintext: Uint8Array,
+        nonce: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+
+        let nonce = input_bytes(&nonce);
+
+        let aad = aad.as_ref().map(input_bytes);
+
+        let encrypted = self
+            .inner
+            .encrypt(&data, &nonce, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+
+    pub fn decrypt(
+        &mut self,
+        ciphertext: Uint8Array,
+        nonce: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+
+        let nonce = input_bytes(&nonce);
+
+        let aad = aad.as_ref().map(input_bytes);
+
+        let decrypted = self
+            .inner
+            .decrypt(&data, &nonce, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+
+    /// RFC 5297 header vector, as [`siv_encrypt`].
+    pub fn encrypt_with_headers(
+        &mut self,
+        plaintext: Uint8Array,
+        headers: Array,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+
+        let headers = siv_headers(&headers)?;
+        let headers: Vec<_> = headers.iter().map(input_bytes).collect();
+        let headers: Vec<&[u8]> = headers.iter().map(|h| h.as_ref()).collect();
+
+        let encrypted = self
+            .inner
+            .encrypt_with_headers(&data, &headers)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+
+    pub fn decrypt_with_headers(
+        &mut self,
+        ciphertext: Uint8Array,
+        headers: Array,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+
+        let headers = siv_headers(&headers)?;
+        let headers: Vec<_> = headers.iter().map(input_bytes).collect();
+        let headers: Vec<&[u8]> = headers.iter().map(|h| h.as_ref()).collect();
+
+        let decrypted = self
+            .inner
+            .decrypt_with_headers(&data, &headers)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+}
+
+/// Seekable AES-CTR keystream; see [`cryptographer_core::cipher::keystream`].
+/// `free()` drops the key, which is zeroized.
+#[wasm_bindgen]
+pub struct Keystream {
+    inner: keystream::Keystream,
+}
+
+#[wasm_bindgen]
+impl Keystream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        iv: Uint8Array,
+        algo: AesAlgorithm,
+        layout: Option<CounterLayout>,
+    ) -> Result<Keystream, JsValue> {
+        let key = secret_bytes(&key);
+        let iv = input_bytes(&iv);
+
+        let inner = keystream::KeystreamAlgorithm::try_from(aes::AesAlgorithm::from(algo))
+            .and_then(|algo| {
+                let layout = layout.map_or(algo.default_layout(), Into::into);
+                keystream::Keystream::new(&key, &iv, algo, layout)
+            })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Keystream { inner })
+    }
+
+    /// XORs the keystream into `data` (encrypts or decrypts) and advances.
+    pub fn apply(&mut self, data: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&data);
+
+        let out = self
+            .inner
+            .apply(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(out.as_slice()))
+    }
+
+    /// Moves to byte `offset` of the keystream.
+    pub fn seek(&mut self, offset: u64) -> Result<(), JsValue> {
+        self.inner
+            .seek(offset)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u64 {
+        self.inner.position()
+    }
+}
@@ -0,0 +1,18 @@
+//! Thin wrapper around [`cryptographer_core::cipher::ascon`] and
+//! [`cryptographer_core::sha::ascon`].
+//!
+//! With the `wasm` feature enabled this crate exports the wasm-bindgen
+//! bindings used by the Node package; without it, it re-exports the native
+//! Rust API so the crate can be built and tested on any target.
+
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "wasm")]
+pub use wasm::*;
+
+#[cfg(not(feature = "wasm"))]
+pub use cryptographer_core::cipher::ascon::*;
+
+#[cfg(not(feature = "wasm"))]
+pub use cryptographer_core::sha::ascon::*;
@@ -0,0 +1,95 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::ascon;
+use cryptographer_core::sha::ascon::{self as ascon_hash, AsconHasher, AsconType};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn encrypt(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let key = secret_bytes(&key);
+    let nonce = input_bytes(&nonce);
+    let aad = aad.as_ref().map(input_bytes);
+
+    let ct = ascon::encrypt(&data, &key, &nonce, aad.as_deref().unwrap_or_default())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(ct.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn decrypt(
+    ciphertext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+    let key = secret_bytes(&key);
+    let nonce = input_bytes(&nonce);
+    let aad = aad.as_ref().map(input_bytes);
+
+    let pt = ascon::decrypt(&data, &key, &nonce, aad.as_deref().unwrap_or_default())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(pt.as_slice()))
+}
+
+#[derive(Deserialize)]
+struct HashOptions {
+    algo: AsconType,
+    #[serde(default)]
+    pub hash_length: Option<usize>,
+}
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
+    let opts: HashOptions =
+        serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let input_slice = input_bytes(&input);
+
+    ascon_hash::hash(&input_slice, opts.algo, opts.hash_length)
+        .map(Vec::into_boxed_slice)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+pub struct StreamingHasher {
+    inner: AsconHasher,
+    hash_length: Option<usize>,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    /// `hash_length` given here is used when `finalize` is called without one.
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: JsValue) -> Result<StreamingHasher, JsValue> {
+        let opts: HashOptions = serde_wasm_bindgen::from_value(options)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Self {
+            inner: AsconHasher::new(opts.algo),
+            hash_length: opts.hash_length,
+        })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) {
+        let input_slice = input_bytes(&input);
+
+        self.inner.update(&input_slice);
+    }
+
+    pub fn finalize(&mut self, hash_length: Option<usize>) -> Result<Box<[u8]>, JsValue> {
+        std::mem::replace(&mut self.inner, AsconHasher::new(AsconType::Hash256))
+            .finalize(hash_length.or(self.hash_length))
+            .map(Vec::into_boxed_slice)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
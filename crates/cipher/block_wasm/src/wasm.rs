@@ -0,0 +1,116 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::block;
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum BlockAlgorithm {
+    Camellia128Gcm,
+    Camellia192Gcm,
+    Camellia256Gcm,
+    Camellia128Cbc,
+    Camellia192Cbc,
+    Camellia256Cbc,
+    Camellia128Ctr,
+    Camellia192Ctr,
+    Camellia256Ctr,
+    Aria128Gcm,
+    Aria192Gcm,
+    Aria256Gcm,
+    Aria128Cbc,
+    Aria192Cbc,
+    Aria256Cbc,
+    Aria128Ctr,
+    Aria192Ctr,
+    Aria256Ctr,
+    Sm4Gcm,
+    Sm4Cbc,
+    Sm4Ctr,
+}
+
+impl From<BlockAlgorithm> for block::BlockAlgorithm {
+    fn from(algo: BlockAlgorithm) -> Self {
+        match algo {
+            BlockAlgorithm::Camellia128Gcm => Self::Camellia128Gcm,
+            BlockAlgorithm::Camellia192Gcm => Self::Camellia192Gcm,
+            BlockAlgorithm::Camellia256Gcm => Self::Camellia256Gcm,
+            BlockAlgorithm::Camellia128Cbc => Self::Camellia128Cbc,
+            BlockAlgorithm::Camellia192Cbc => Self::Camellia192Cbc,
+            BlockAlgorithm::Camellia256Cbc => Self::Camellia256Cbc,
+            BlockAlgorithm::Camellia128Ctr => Self::Camellia128Ctr,
+            BlockAlgorithm::Camellia192Ctr => Self::Camellia192Ctr,
+            BlockAlgorithm::Camellia256Ctr => Self::Camellia256Ctr,
+            BlockAlgorithm::Aria128Gcm => Self::Aria128Gcm,
+            BlockAlgorithm::Aria192Gcm => Self::Aria192Gcm,
+            BlockAlgorithm::Aria256Gcm => Self::Aria256Gcm,
+            BlockAlgorithm::Aria128Cbc => Self::Aria128Cbc,
+            BlockAlgorithm::Aria192Cbc => Self::Aria192Cbc,
+            BlockAlgorithm::Aria256Cbc => Self::Aria256Cbc,
+            BlockAlgorithm::Aria128Ctr => Self::Aria128Ctr,
+            BlockAlgorithm::Aria192Ctr => Self::Aria192Ctr,
+            BlockAlgorithm::Aria256Ctr => Self::Aria256Ctr,
+            BlockAlgorithm::Sm4Gcm => Self::Sm4Gcm,
+            BlockAlgorithm::Sm4Cbc => Self::Sm4Cbc,
+            BlockAlgorithm::Sm4Ctr => Self::Sm4Ctr,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn encrypt(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce_or_iv: Uint8Array,
+    algo: BlockAlgorithm,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+
+    let key = secret_bytes(&key);
+
+    let nonce = input_bytes(&nonce_or_iv);
+
+    let aad = aad.as_ref().map(input_bytes);
+
+    let encrypted = block::encrypt_with_aad(
+        &data,
+        &key,
+        &nonce,
+        aad.as_deref().unwrap_or_default(),
+        algo.into(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(encrypted.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn decrypt(
+    ciphertext: Uint8Array,
+    key: Uint8Array,
+    nonce_or_iv: Uint8Array,
+    algo: BlockAlgorithm,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+
+    let key = secret_bytes(&key);
+
+    let nonce = input_bytes(&nonce_or_iv);
+
+    let aad = aad.as_ref().map(input_bytes);
+
+    let decrypted = block::decrypt_with_aad(
+        &data,
+        &key,
+        &nonce,
+        aad.as_deref().unwrap_or_default(),
+        algo.into(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(decrypted.as_slice()))
+}
@@ -0,0 +1,408 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::{chacha20, keystream, salsa20, stream};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum ChaCha20Algorithm {
+    /// Raw ChaCha20 stream cipher (no authentication)
+    Chacha20,
+    /// ChaCha20-Poly1305 AEAD (authenticated encryption)
+    Chacha20Poly1305,
+    /// XChaCha20 with a 24-byte nonce (no authentication)
+    XChacha20,
+    /// XChaCha20-Poly1305 AEAD with a 24-byte nonce
+    XChacha20Poly1305,
+}
+
+impl From<ChaCha20Algorithm> for chacha20::ChaCha20Algorithm {
+    fn from(algo: ChaCha20Algorithm) -> Self {
+        match algo {
+            ChaCha20Algorithm::Chacha20 => Self::Chacha20,
+            ChaCha20Algorithm::Chacha20Poly1305 => Self::Chacha20Poly1305,
+            ChaCha20Algorithm::XChacha20 => Self::XChacha20,
+            ChaCha20Algorithm::XChacha20Poly1305 => Self::XChacha20Poly1305,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn encrypt(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    algo: ChaCha20Algorithm,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let key = secret_bytes(&key);
+    let nonce_vec = input_bytes(&nonce);
+    let aad = aad.as_ref().map(input_bytes);
+
+    let ct = chacha20::encrypt_with_aad(
+        &data,
+        &key,
+        &nonce_vec,
+        aad.as_deref().unwrap_or_default(),
+        algo.into(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(ct.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn decrypt(
+    ciphertext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    algo: ChaCha20Algorithm,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+    let key = secret_bytes(&key);
+    let nonce_vec = input_bytes(&nonce);
+    let aad = aad.as_ref().map(input_bytes);
+
+    let pt = chacha20::decrypt_with_aad(
+        &data,
+        &key,
+        &nonce_vec,
+        aad.as_deref().unwrap_or_default(),
+        algo.into(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(pt.as_slice()))
+}
+
+/// ChaCha20-Poly1305 only; returns `[ciphertext, tag]`.
+#[wasm_bindgen]
+pub fn encrypt_detached(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    aad: Option<Uint8Array>,
+) -> Result<Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let key = secret_bytes(&key);
+    let nonce_vec = input_bytes(&nonce);
+    let aad = aad.as_ref().map(input_bytes);
+
+    let (ct, tag) =
+        chacha20::encrypt_detached(&data, &key, &nonce_vec, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let arr = Array::new();
+    arr.push(&Uint8Array::from(ct.as_slice()));
+    arr.push(&Uint8Array::from(tag.as_slice()));
+    Ok(arr)
+}
+
+#[wasm_bindgen]
+pub fn decrypt_detached(
+    ciphertext: Uint8Array,
+    tag: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    aad: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+    let tag = input_bytes(&tag);
+    let key = secret_bytes(&key);
+    let nonce_vec = input_bytes(&nonce);
+    let aad = aad.as_ref().map(input_bytes);
+
+    let pt = chacha20::decrypt_detached(
+        &data,
+        &tag,
+        &key,
+        &nonce_vec,
+        aad.as_deref().unwrap_or_default(),
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(pt.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn hchacha20(key: Uint8Array, input: Uint8Array) -> Result<Uint8Array, JsValue> {
+    let key = secret_bytes(&key);
+    let input = input_bytes(&input);
+
+    let subkey =
+        chacha20::hchacha20(&key, &input).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(subkey.as_slice()))
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum Salsa20Algorithm {
+    /// Salsa20/20 with an 8-byte nonce (no authentication)
+    Salsa20,
+    /// XSalsa20 with a 24-byte nonce (no authentication)
+    XSalsa20,
+}
+
+impl From<Salsa20Algorithm> for salsa20::Salsa20Algorithm {
+    fn from(algo: Salsa20Algorithm) -> Self {
+        match algo {
+            Salsa20Algorithm::Salsa20 => Self::Salsa20,
+            Salsa20Algorithm::XSalsa20 => Self::XSalsa20,
+        }
+    }
+}
+
+/// Salsa20/XSalsa20 keystream XOR; encryption and decryption are the same.
+#[wasm_bindgen]
+pub fn salsa20_xor(
+    data: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+    algo: Salsa20Algorithm,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&data);
+    let key = secret_bytes(&key);
+    let nonce = input_bytes(&nonce);
+
+    let out = salsa20::encrypt(&data, &key, &nonce, algo.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(out.as_slice()))
+}
+
+/// libsodium `crypto_secretbox_easy`: tag followed by ciphertext.
+#[wasm_bindgen]
+pub fn secretbox_seal(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let key = secret_bytes(&key);
+    let nonce = input_bytes(&nonce);
+
+    let boxed = salsa20::secretbox_seal(&data, &key, &nonce)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(boxed.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn secretbox_open(
+    boxed: Uint8Array,
+    key: Uint8Array,
+    nonce: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&boxed);
+    let key = secret_bytes(&key);
+    let nonce = input_bytes(&nonce);
+
+    let plaintext = salsa20::secretbox_open(&data, &key, &nonce)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(plaintext.as_slice()))
+}
+
+/// STREAM encryption over ChaCha20-Poly1305 for inputs too large to buffer;
+/// see [`cryptographer_core::cipher::stream`].
+#[wasm_bindgen]
+pub struct StreamingEncryptor {
+    inner: stream::StreamingEncryptor,
+}
+
+#[wasm_bindgen]
+impl StreamingEncryptor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        nonce: Uint8Array,
+        segment_size: Option<usize>,
+    ) -> Result<StreamingEncryptor, JsValue> {
+        let key = secret_bytes(&key);
+
+        let nonce = input_bytes(&nonce);
+
+        let inner = stream::StreamingEncryptor::new(
+            &key,
+            &nonce,
+            stream::StreamAlgorithm::Chacha20Poly1305,
+            segment_size.unwrap_or(stream::DEFAULT_SEGMENT_SIZE),
+        )
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingEncryptor { inner })
+    }
+
+    pub fn update(&mut self, plaintext: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+
+        let encrypted = self
+            .inner
+            .update(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+
+    pub fn finalize(&mut self) -> Result<Uint8Array, JsValue> {
+        let encrypted = self
+            .inner
+            .finalize()
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(encrypted.as_slice()))
+    }
+}
+
+#[wasm_bindgen]
+pub struct StreamingDecryptor {
+    inner: stream::StreamingDecryptor,
+}
+
+#[wasm_bindgen]
+impl StreamingDecryptor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        nonce: Uint8Array,
+        segment_size: Option<usize>,
+    ) -> Result<StreamingDecryptor, JsValue> {
+        let key = secret_bytes(&key);
+
+        let nonce = input_bytes(&nonce);
+
+        let inner = stream::StreamingDecryptor::new(
+            &key,
+            &nonce,
+            stream::StreamAlgorithm::Chacha20Poly1305,
+            segment_size.unwrap_or(stream::DEFAULT_SEGMENT_SIZE),
+        )
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingDecryptor { inner })
+    }
+
+    pub fn update(&mut self, ciphertext: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+
+        let decrypted = self
+            .inner
+            .update(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+
+    pub fn finalize(&mut self) -> Result<Uint8Array, JsValue> {
+        let decrypted = self
+            .inner
+            .finalize()
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(decrypted.as_slice()))
+    }
+}
+
+/// ChaCha20-Poly1305 or XChaCha20-Poly1305 keyed once. `free()` drops the
+/// key, which is zeroized.
+#[wasm_bindgen]
+pub struct ChaCha20Poly1305Cipher {
+    inner: chacha20::ChaCha20Poly1305Cipher,
+}
+
+#[wasm_bindgen]
+impl ChaCha20Poly1305Cipher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        algo: ChaCha20Algorithm,
+    ) -> Result<ChaCha20Poly1305Cipher, JsValue> {
+        let key = secret_bytes(&key);
+        let inner = chacha20::ChaCha20Poly1305Cipher::new(&key, algo.into())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(ChaCha20Poly1305Cipher { inner })
+    }
+
+    pub fn encrypt(
+        &self,
+        plaintext: Uint8Array,
+        nonce: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+        let nonce = input_bytes(&nonce);
+        let aad = aad.as_ref().map(input_bytes);
+
+        let ct = self
+            .inner
+            .encrypt(&data, &nonce, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(ct.as_slice()))
+    }
+
+    pub fn decrypt(
+        &self,
+        ciphertext: Uint8Array,
+        nonce: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+        let nonce = input_bytes(&nonce);
+        let aad = aad.as_ref().map(input_bytes);
+
+        let pt = self
+            .inner
+            .decrypt(&data, &nonce, aad.as_deref().unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(pt.as_slice()))
+    }
+}
+
+/// Seekable ChaCha20 or XChaCha20 keystream; see [`cryptographer_core::cipher::keystream`].
+/// `free()` drops the key, which is zeroized.
+#[wasm_bindgen]
+pub struct Keystream {
+    inner: keystream::Keystream,
+}
+
+#[wasm_bindgen]
+impl Keystream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        iv: Uint8Array,
+        algo: ChaCha20Algorithm,
+    ) -> Result<Keystream, JsValue> {
+        let key = secret_bytes(&key);
+        let iv = input_bytes(&iv);
+
+        let inner =
+            keystream::KeystreamAlgorithm::try_from(chacha20::ChaCha20Algorithm::from(algo))
+                .and_then(|algo| keystream::Keystream::new(&key, &iv, algo, algo.default_layout()))
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Keystream { inner })
+    }
+
+    /// XORs the keystream into `data` (encrypts or decrypts) and advances.
+    pub fn apply(&mut self, data: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&data);
+
+        let out = self
+            .inner
+            .apply(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(out.as_slice()))
+    }
+
+    /// Moves to byte `offset` of the keystream.
+    pub fn seek(&mut self, offset: u64) -> Result<(), JsValue> {
+        self.inner
+            .seek(offset)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u64 {
+        self.inner.position()
+    }
+}
@@ -0,0 +1,348 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::{des, keystream};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum DesAlgorithm {
+    /// DES in CBC mode with PKCS7 padding
+    DesCbc,
+    /// 3DES (EDE3) in CBC mode with PKCS7 padding
+    TdesCbc,
+    /// DES in CTR mode (no padding)
+    DesCtr,
+    /// 3DES (EDE3) in CTR mode (no padding)
+    TdesCtr,
+    /// DES in ECB mode with PKCS7 padding (takes no IV)
+    DesEcb,
+    /// 3DES (EDE3) in ECB mode with PKCS7 padding (takes no IV)
+    TdesEcb,
+    /// Two-key 3DES (EDE2) in CBC mode with PKCS7 padding
+    TdesEde2Cbc,
+    /// Two-key 3DES (EDE2) in CTR mode (no padding)
+    TdesEde2Ctr,
+    /// Two-key 3DES (EDE2) in ECB mode with PKCS7 padding (takes no IV)
+    TdesEde2Ecb,
+}
+
+impl From<DesAlgorithm> for des::DesAlgorithm {
+    fn from(algo: DesAlgorithm) -> Self {
+        match algo {
+            DesAlgorithm::DesCbc => Self::DesCbc,
+            DesAlgorithm::TdesCbc => Self::TdesCbc,
+            DesAlgorithm::DesCtr => Self::DesCtr,
+            DesAlgorithm::TdesCtr => Self::TdesCtr,
+            DesAlgorithm::DesEcb => Self::DesEcb,
+            DesAlgorithm::TdesEcb => Self::TdesEcb,
+            DesAlgorithm::TdesEde2Cbc => Self::TdesEde2Cbc,
+            DesAlgorithm::TdesEde2Ctr => Self::TdesEde2Ctr,
+            DesAlgorithm::TdesEde2Ecb => Self::TdesEde2Ecb,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum Padding {
+    Pkcs7,
+    Iso7816,
+    AnsiX923,
+    Iso10126,
+    ZeroPadding,
+    NoPadding,
+}
+
+impl From<Padding> for des::Padding {
+    fn from(padding: Padding) -> Self {
+        match padding {
+            Padding::Pkcs7 => Self::Pkcs7,
+            Padding::Iso7816 => Self::Iso7816,
+            Padding::AnsiX923 => Self::AnsiX923,
+            Padding::Iso10126 => Self::Iso10126,
+            Padding::ZeroPadding => Self::ZeroPadding,
+            Padding::NoPadding => Self::NoPadding,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum CounterLayout {
+    Ctr32BE,
+    Ctr64BE,
+    Ctr32LE,
+}
+
+impl From<CounterLayout> for keystream::CounterLayout {
+    fn from(layout: CounterLayout) -> Self {
+        match layout {
+            CounterLayout::Ctr32BE => Self::Ctr32BE,
+            CounterLayout::Ctr64BE => Self::Ctr64BE,
+            CounterLayout::Ctr32LE => Self::Ctr32LE,
+        }
+    }
+}
+
+/// `padding` applies to CBC and ECB, which default to PKCS#7 without it.
+#[wasm_bindgen]
+pub fn encrypt(
+    plaintext: Uint8Array,
+    key: Uint8Array,
+    iv: Uint8Array,
+    algo: DesAlgorithm,
+    padding: Option<Padding>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let key = secret_bytes(&key);
+    let iv_vec = input_bytes(&iv);
+
+    let ciphertext = match padding {
+        Some(padding) => des::encrypt_padded(&data, &key, &iv_vec, padding.into(), algo.into()),
+        None => des::encrypt(&data, &key, &iv_vec, algo.into()),
+    }
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(ciphertext.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn decrypt(
+    ciphertext: Uint8Array,
+    key: Uint8Array,
+    iv: Uint8Array,
+    algo: DesAlgorithm,
+    padding: Option<Padding>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&ciphertext);
+    let key = secret_bytes(&key);
+    let iv_vec = input_bytes(&iv);
+
+    let plaintext = match padding {
+        Some(padding) => des::decrypt_padded(&data, &key, &iv_vec, padding.into(), algo.into()),
+        None => des::decrypt(&data, &key, &iv_vec, algo.into()),
+    }
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(plaintext.as_slice()))
+}
+
+/// Result of [`des_check_key`]; see [`cryptographer_core::cipher::des::check_key`].
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct DesKeyCheck {
+    /// Every byte has odd parity
+    pub odd_parity: bool,
+    /// Some component is one of the four DES weak keys
+    pub weak: bool,
+    /// Some component is one of the twelve DES semi-weak keys
+    pub semi_weak: bool,
+    /// Adjacent 3DES components are equal, so the key reduces to single DES
+    pub degenerate: bool,
+}
+
+/// Inspects an 8-, 16- or 24-byte DES/3DES key. Parity bits are ignored
+/// when matching weak keys.
+#[wasm_bindgen]
+pub fn des_check_key(key: Uint8Array) -> Result<DesKeyCheck, JsValue> {
+    let key = secret_bytes(&key);
+
+    let check = des::check_key(&key).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(DesKeyCheck {
+        odd_parity: check.odd_parity,
+        weak: check.weak,
+        semi_weak: check.semi_weak,
+        degenerate: check.degenerate,
+    })
+}
+
+/// Returns a copy of `key` with the low bit of every byte set for odd parity.
+#[wasm_bindgen]
+pub fn des_set_odd_parity(key: Uint8Array) -> Uint8Array {
+    let mut key = secret_bytes(&key);
+
+    des::set_odd_parity(&mut key);
+
+    Uint8Array::from(&key[..])
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum MacAlgorithm {
+    /// ISO 9797-1 algorithm 1: CBC-MAC under an 8, 16 or 24-byte key
+    Algorithm1,
+    /// ISO 9797-1 algorithm 3 (ANSI X9.19 retail MAC) under a 16-byte key
+    Algorithm3,
+}
+
+impl From<MacAlgorithm> for des::MacAlgorithm {
+    fn from(algo: MacAlgorithm) -> Self {
+        match algo {
+            MacAlgorithm::Algorithm1 => Self::Algorithm1,
+            MacAlgorithm::Algorithm3 => Self::Algorithm3,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum MacPadding {
+    /// ISO 9797-1 padding method 1 (zeros)
+    Method1,
+    /// ISO 9797-1 padding method 2 (0x80 then zeros)
+    Method2,
+}
+
+impl From<MacPadding> for des::MacPadding {
+    fn from(padding: MacPadding) -> Self {
+        match padding {
+            MacPadding::Method1 => Self::Method1,
+            MacPadding::Method2 => Self::Method2,
+        }
+    }
+}
+
+/// Full 8-byte ISO 9797-1 MAC of `data`.
+#[wasm_bindgen]
+pub fn des_mac(
+    data: Uint8Array,
+    key: Uint8Array,
+    algo: MacAlgorithm,
+    padding: MacPadding,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&data);
+    let key = secret_bytes(&key);
+
+    let mac = des::mac(&data, &key, algo.into(), padding.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(mac.as_slice()))
+}
+
+/// Checks a full or truncated (4 to 8-byte) MAC in constant time.
+#[wasm_bindgen]
+pub fn des_mac_verify(
+    data: Uint8Array,
+    key: Uint8Array,
+    tag: Uint8Array,
+    algo: MacAlgorithm,
+    padding: MacPadding,
+) -> Result<bool, JsValue> {
+    let data = input_bytes(&data);
+    let key = secret_bytes(&key);
+    let tag = input_bytes(&tag);
+
+    des::mac_verify(&data, &key, &tag, algo.into(), padding.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Incremental ISO 9797-1 MAC; `free()` drops the key schedule.
+#[wasm_bindgen]
+pub struct StreamingMac {
+    inner: Option<des::DesMac>,
+}
+
+#[wasm_bindgen]
+impl StreamingMac {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        algo: MacAlgorithm,
+        padding: MacPadding,
+    ) -> Result<StreamingMac, JsValue> {
+        let key = secret_bytes(&key);
+
+        let inner = des::DesMac::new(&key, algo.into(), padding.into())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingMac { inner: Some(inner) })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input = input_bytes(&input);
+
+        match self.inner.as_mut() {
+            Some(mac) => mac.update(&input),
+            None => return Err(JsValue::from_str("DES MAC has been finalized")),
+        }
+
+        Ok(())
+    }
+
+    pub fn finalize(&mut self) -> Result<Uint8Array, JsValue> {
+        let inner = self
+            .inner
+            .take()
+            .ok_or_else(|| JsValue::from_str("Already finalized"))?;
+
+        Ok(Uint8Array::from(inner.finalize().as_slice()))
+    }
+
+    pub fn verify(&mut self, tag: Uint8Array) -> Result<bool, JsValue> {
+        let tag = input_bytes(&tag);
+
+        let inner = self
+            .inner
+            .take()
+            .ok_or_else(|| JsValue::from_str("Already finalized"))?;
+
+        inner
+            .verify(&tag)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+/// Seekable DES/3DES-CTR keystream; see [`cryptographer_core::cipher::keystream`].
+/// `free()` drops the key, which is zeroized.
+#[wasm_bindgen]
+pub struct Keystream {
+    inner: keystream::Keystream,
+}
+
+#[wasm_bindgen]
+impl Keystream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: Uint8Array,
+        iv: Uint8Array,
+        algo: DesAlgorithm,
+        layout: Option<CounterLayout>,
+    ) -> Result<Keystream, JsValue> {
+        let key = secret_bytes(&key);
+        let iv = input_bytes(&iv);
+
+        let inner = keystream::KeystreamAlgorithm::try_from(des::DesAlgorithm::from(algo))
+            .and_then(|algo| {
+                let layout = layout.map_or(algo.default_layout(), Into::into);
+                keystream::Keystream::new(&key, &iv, algo, layout)
+            })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Keystream { inner })
+    }
+
+    /// XORs the keystream into `data` (encrypts or decrypts) and advances.
+    pub fn apply(&mut self, data: Uint8Array) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&data);
+
+        let out = self
+            .inner
+            .apply(&data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Uint8Array::from(out.as_slice()))
+    }
+
+    /// Moves to byte `offset` of the keystream.
+    pub fn seek(&mut self, offset: u64) -> Result<(), JsValue> {
+        self.inner
+            .seek(offset)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u64 {
+        self.inner.position()
+    }
+}
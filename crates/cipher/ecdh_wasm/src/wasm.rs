@@ -0,0 +1,40 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::ecdh::{self, EcdhCurve};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum EcdhCurveJs {
+    P256,
+    P384,
+}
+
+impl From<EcdhCurveJs> for EcdhCurve {
+    fn from(curve: EcdhCurveJs) -> Self {
+        match curve {
+            EcdhCurveJs::P256 => EcdhCurve::P256,
+            EcdhCurveJs::P384 => EcdhCurve::P384,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn ecdh_generate_keypair(curve: EcdhCurveJs) -> Result<js_sys::Array, JsValue> {
+    let (sk, pk) = ecdh::generate_keypair(curve.into());
+    let arr = js_sys::Array::new();
+    arr.push(&Uint8Array::from(sk.as_slice()));
+    arr.push(&Uint8Array::from(pk.as_slice()));
+    Ok(arr)
+}
+
+#[wasm_bindgen]
+pub fn ecdh_derive_shared_secret(curve: EcdhCurveJs, private_key: Uint8Array, peer_public_key: Uint8Array) -> Result<Uint8Array, JsValue> {
+    let sk_bytes = secret_bytes(&private_key);
+    let pk_bytes = input_bytes(&peer_public_key);
+    let secret = ecdh::derive_shared_secret(curve.into(), &sk_bytes, &pk_bytes)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(secret.as_slice()))
+}
@@ -0,0 +1,102 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::ecies::{self, EciesOptions};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum EciesCurve {
+    Secp256k1,
+    P256,
+}
+
+impl From<EciesCurve> for ecies::EciesCurve {
+    fn from(curve: EciesCurve) -> Self {
+        match curve {
+            EciesCurve::Secp256k1 => Self::Secp256k1,
+            EciesCurve::P256 => Self::P256,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum EciesAead {
+    Aes256Gcm,
+    XChaCha20Poly1305,
+}
+
+impl From<EciesAead> for ecies::EciesAead {
+    fn from(aead: EciesAead) -> Self {
+        match aead {
+            EciesAead::Aes256Gcm => Self::Aes256Gcm,
+            EciesAead::XChaCha20Poly1305 => Self::XChaCha20Poly1305,
+        }
+    }
+}
+
+/// A curve and wire format; see [`cryptographer_core::cipher::ecies`].
+#[wasm_bindgen]
+pub struct Ecies {
+    curve: ecies::EciesCurve,
+    opts: EciesOptions,
+}
+
+#[wasm_bindgen]
+impl Ecies {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        curve: EciesCurve,
+        aead: EciesAead,
+        aes_nonce_len: usize,
+        compressed_ephemeral_key: bool,
+        compressed_hkdf_key: bool,
+    ) -> Ecies {
+        Ecies {
+            curve: curve.into(),
+            opts: EciesOptions {
+                aead: aead.into(),
+                aes_nonce_len,
+                compressed_ephemeral_key,
+                compressed_hkdf_key,
+            },
+        }
+    }
+
+    /// `[private_key, uncompressed_public_key]`
+    pub fn generate_keypair(&self) -> Array {
+        let (sk, pk) = ecies::generate_keypair(self.curve);
+        let arr = Array::new();
+        arr.push(&Uint8Array::from(sk.as_slice()));
+        arr.push(&Uint8Array::from(pk.as_slice()));
+        arr
+    }
+
+    pub fn encrypt(
+        &self,
+        receiver_public_key: Uint8Array,
+        plaintext: Uint8Array,
+    ) -> Result<Uint8Array, JsValue> {
+        let pk = input_bytes(&receiver_public_key);
+        let data = input_bytes(&plaintext);
+
+        let ct = ecies::encrypt(self.curve, &pk, &data, &self.opts)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(ct.as_slice()))
+    }
+
+    pub fn decrypt(
+        &self,
+        receiver_private_key: Uint8Array,
+        ciphertext: Uint8Array,
+    ) -> Result<Uint8Array, JsValue> {
+        let sk = secret_bytes(&receiver_private_key);
+        let data = input_bytes(&ciphertext);
+
+        let pt = ecies::decrypt(self.curve, &sk, &data, &self.opts)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(pt.as_slice()))
+    }
+}
@@ -0,0 +1,240 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::hpke::{self, HpkeOptions};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum HpkeKem {
+    /// DHKEM(X25519, HKDF-SHA256)
+    X25519HkdfSha256,
+    /// DHKEM(P-256, HKDF-SHA256)
+    P256HkdfSha256,
+    /// DHKEM(P-384, HKDF-SHA384)
+    P384HkdfSha384,
+}
+
+impl From<HpkeKem> for hpke::HpkeKem {
+    fn from(kem: HpkeKem) -> Self {
+        match kem {
+            HpkeKem::X25519HkdfSha256 => Self::X25519HkdfSha256,
+            HpkeKem::P256HkdfSha256 => Self::P256HkdfSha256,
+            HpkeKem::P384HkdfSha384 => Self::P384HkdfSha384,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum HpkeKdf {
+    HkdfSha256,
+    HkdfSha384,
+    HkdfSha512,
+}
+
+impl From<HpkeKdf> for hpke::HpkeKdf {
+    fn from(kdf: HpkeKdf) -> Self {
+        match kdf {
+            HpkeKdf::HkdfSha256 => Self::HkdfSha256,
+            HpkeKdf::HkdfSha384 => Self::HkdfSha384,
+            HpkeKdf::HkdfSha512 => Self::HkdfSha512,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum HpkeAead {
+    Aes128Gcm,
+    Aes256Gcm,
+    ChaCha20Poly1305,
+    /// No AEAD: contexts can only export secrets
+    ExportOnly,
+}
+
+impl From<HpkeAead> for hpke::HpkeAead {
+    fn from(aead: HpkeAead) -> Self {
+        match aead {
+            HpkeAead::Aes128Gcm => Self::Aes128Gcm,
+            HpkeAead::Aes256Gcm => Self::Aes256Gcm,
+            HpkeAead::ChaCha20Poly1305 => Self::ChaCha20Poly1305,
+            HpkeAead::ExportOnly => Self::ExportOnly,
+        }
+    }
+}
+
+fn keypair_array((sk, pk): (Vec<u8>, Vec<u8>)) -> Array {
+    let arr = Array::new();
+    arr.push(&Uint8Array::from(sk.as_slice()));
+    arr.push(&Uint8Array::from(pk.as_slice()));
+    arr
+}
+
+/// An HPKE suite; see [`cryptographer_core::cipher::hpke`].
+#[wasm_bindgen]
+pub struct Hpke {
+    suite: hpke::HpkeSuite,
+}
+
+#[wasm_bindgen]
+impl Hpke {
+    #[wasm_bindgen(constructor)]
+    pub fn new(kem: HpkeKem, kdf: HpkeKdf, aead: HpkeAead) -> Hpke {
+        Hpke {
+            suite: hpke::HpkeSuite::new(kem.into(), kdf.into(), aead.into()),
+        }
+    }
+
+    /// `[private_key, public_key]`
+    pub fn generate_keypair(&self) -> Array {
+        keypair_array(hpke::generate_keypair(self.suite.kem))
+    }
+
+    /// `[private_key, public_key]` from `DeriveKeyPair(ikm)`
+    pub fn derive_keypair(&self, ikm: Uint8Array) -> Result<Array, JsValue> {
+        let ikm = secret_bytes(&ikm);
+
+        let keypair = hpke::derive_keypair(self.suite.kem, &ikm)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(keypair_array(keypair))
+    }
+
+    /// `auth_key` is the sender's private key and selects the auth modes.
+    pub fn setup_sender(
+        &self,
+        recipient_public_key: Uint8Array,
+        info: Option<Uint8Array>,
+        psk: Option<Uint8Array>,
+        psk_id: Option<Uint8Array>,
+        auth_key: Option<Uint8Array>,
+    ) -> Result<HpkeSenderContext, JsValue> {
+        let pk = input_bytes(&recipient_public_key);
+        let info = info.as_ref().map(input_bytes);
+        let psk = psk.as_ref().map(secret_bytes);
+        let psk_id = psk_id.as_ref().map(input_bytes);
+        let auth_key = auth_key.as_ref().map(secret_bytes);
+
+        let opts = HpkeOptions {
+            info: info.as_deref().unwrap_or_default(),
+            psk: psk.as_deref().map(Vec::as_slice),
+            psk_id: psk_id.as_deref(),
+            auth_key: auth_key.as_deref().map(Vec::as_slice),
+        };
+
+        let (enc, inner) = hpke::setup_sender(self.suite, &pk, &opts)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(HpkeSenderContext { enc, inner })
+    }
+
+    /// `auth_key` is the sender's public key and selects the auth modes.
+    pub fn setup_recipient(
+        &self,
+        enc: Uint8Array,
+        recipient_private_key: Uint8Array,
+        info: Option<Uint8Array>,
+        psk: Option<Uint8Array>,
+        psk_id: Option<Uint8Array>,
+        auth_key: Option<Uint8Array>,
+    ) -> Result<HpkeRecipientContext, JsValue> {
+        let enc = input_bytes(&enc);
+        let sk = secret_bytes(&recipient_private_key);
+        let info = info.as_ref().map(input_bytes);
+        let psk = psk.as_ref().map(secret_bytes);
+        let psk_id = psk_id.as_ref().map(input_bytes);
+        let auth_key = auth_key.as_ref().map(input_bytes);
+
+        let opts = HpkeOptions {
+            info: info.as_deref().unwrap_or_default(),
+            psk: psk.as_deref().map(Vec::as_slice),
+            psk_id: psk_id.as_deref(),
+            auth_key: auth_key.as_deref(),
+        };
+
+        let inner = hpke::setup_recipient(self.suite, &enc, &sk, &opts)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(HpkeRecipientContext { inner })
+    }
+}
+
+#[wasm_bindgen]
+pub struct HpkeSenderContext {
+    enc: Vec<u8>,
+    inner: hpke::SenderContext,
+}
+
+#[wasm_bindgen]
+impl HpkeSenderContext {
+    /// Encapsulated key for the recipient's `setup_recipient`
+    #[wasm_bindgen(getter)]
+    pub fn enc(&self) -> Uint8Array {
+        Uint8Array::from(self.enc.as_slice())
+    }
+
+    pub fn seal(
+        &mut self,
+        plaintext: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&plaintext);
+        let aad = aad.as_ref().map(input_bytes);
+
+        let ct = self
+            .inner
+            .seal(aad.as_deref().unwrap_or_default(), &data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(ct.as_slice()))
+    }
+
+    pub fn export(
+        &self,
+        exporter_context: Uint8Array,
+        length: usize,
+    ) -> Result<Uint8Array, JsValue> {
+        let context = input_bytes(&exporter_context);
+
+        let secret = self
+            .inner
+            .export(&context, length)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(secret.as_slice()))
+    }
+}
+
+#[wasm_bindgen]
+pub struct HpkeRecipientContext {
+    inner: hpke::RecipientContext,
+}
+
+#[wasm_bindgen]
+impl HpkeRecipientContext {
+    pub fn open(
+        &mut self,
+        ciphertext: Uint8Array,
+        aad: Option<Uint8Array>,
+    ) -> Result<Uint8Array, JsValue> {
+        let data = input_bytes(&ciphertext);
+        let aad = aad.as_ref().map(input_bytes);
+
+        let pt = self
+            .inner
+            .open(aad.as_deref().unwrap_or_default(), &data)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(pt.as_slice()))
+    }
+
+    pub fn export(
+        &self,
+        exporter_context: Uint8Array,
+        length: usize,
+    ) -> Result<Uint8Array, JsValue> {
+        let context = input_bytes(&exporter_context);
+
+        let secret = self
+            .inner
+            .export(&context, length)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Uint8Array::from(secret.as_slice()))
+    }
+}
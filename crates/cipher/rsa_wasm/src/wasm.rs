@@ -0,0 +1,85 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::rsa::{self, HashAlg};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn rsa_oaep_encrypt(
+    plaintext: Uint8Array,
+    public_key_der: Uint8Array,
+    hash: JsValue,
+    label: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let pk_der = input_bytes(&public_key_der);
+
+    let hash_alg: HashAlg = serde_wasm_bindgen::from_value(hash)
+        .map_err(|_| JsValue::from_str("Invalid hash algorithm"))?;
+
+    let label = label.as_ref().map(input_bytes);
+
+    let ct = rsa::oaep_encrypt(&data, &pk_der, hash_alg, label.as_deref())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(ct.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn rsa_oaep_decrypt(
+    ciphertext: Uint8Array,
+    private_key_der: Uint8Array,
+    hash: JsValue,
+    label: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let ct = input_bytes(&ciphertext);
+    let sk_der = secret_bytes(&private_key_der);
+
+    let hash_alg: HashAlg = serde_wasm_bindgen::from_value(hash)
+        .map_err(|_| JsValue::from_str("Invalid hash algorithm"))?;
+
+    let label = label.as_ref().map(input_bytes);
+
+    let pt = rsa::oaep_decrypt(&ct, &sk_der, hash_alg, label.as_deref())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(pt.as_slice()))
+}
+
+/// RSA-OAEP-wrapped AES-256-GCM envelope for payloads of any size; see
+/// [`rsa::envelope_encrypt`] for the container layout.
+#[wasm_bindgen]
+pub fn rsa_envelope_encrypt(
+    plaintext: Uint8Array,
+    public_key_der: Uint8Array,
+    hash: JsValue,
+    label: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let pk_der = input_bytes(&public_key_der);
+
+    let hash_alg: HashAlg = serde_wasm_bindgen::from_value(hash)
+        .map_err(|_| JsValue::from_str("Invalid hash algorithm"))?;
+
+    let label = label.as_ref().map(input_bytes);
+
+    let envelope = rsa::envelope_encrypt(&data, &pk_der, hash_alg, label.as_deref())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(envelope.as_slice()))
+}
+
+/// The OAEP hash is read from the envelope header.
+#[wasm_bindgen]
+pub fn rsa_envelope_decrypt(
+    envelope: Uint8Array,
+    private_key_der: Uint8Array,
+    label: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&envelope);
+    let sk_der = secret_bytes(&private_key_der);
+
+    let label = label.as_ref().map(input_bytes);
+
+    let pt = rsa::envelope_decrypt(&data, &sk_der, label.as_deref())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(pt.as_slice()))
+}
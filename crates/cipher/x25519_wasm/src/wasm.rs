@@ -0,0 +1,101 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::cipher::x25519;
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn x25519_generate_keypair() -> js_sys::Array {
+    let (sk, pk) = x25519::generate_keypair();
+    let arr = js_sys::Array::new();
+    arr.push(&Uint8Array::from(sk.as_slice()));
+    arr.push(&Uint8Array::from(pk.as_slice()));
+    arr
+}
+
+#[wasm_bindgen]
+pub fn x25519_derive_shared_secret(private_key: Uint8Array, peer_public_key: Uint8Array) -> Result<Uint8Array, JsValue> {
+    let sk_vec = secret_bytes(&private_key);
+    let pk_vec = input_bytes(&peer_public_key);
+    let shared = x25519::derive_shared_secret(&sk_vec, &pk_vec)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(shared.as_slice()))
+}
+
+/// libsodium `crypto_box_beforenm`: a key for `secretbox_seal`/`secretbox_open`.
+#[wasm_bindgen]
+pub fn box_beforenm(
+    peer_public_key: Uint8Array,
+    private_key: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let pk = input_bytes(&peer_public_key);
+    let sk = secret_bytes(&private_key);
+
+    let key = x25519::box_beforenm(&pk, &sk).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(key.as_slice()))
+}
+
+/// libsodium `crypto_box_easy`: tag followed by ciphertext.
+#[wasm_bindgen]
+pub fn box_seal(
+    plaintext: Uint8Array,
+    nonce: Uint8Array,
+    peer_public_key: Uint8Array,
+    private_key: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let nonce = input_bytes(&nonce);
+    let pk = input_bytes(&peer_public_key);
+    let sk = secret_bytes(&private_key);
+
+    let boxed = x25519::box_seal(&data, &nonce, &pk, &sk)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(boxed.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn box_open(
+    boxed: Uint8Array,
+    nonce: Uint8Array,
+    peer_public_key: Uint8Array,
+    private_key: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&boxed);
+    let nonce = input_bytes(&nonce);
+    let pk = input_bytes(&peer_public_key);
+    let sk = secret_bytes(&private_key);
+
+    let plaintext = x25519::box_open(&data, &nonce, &pk, &sk)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(plaintext.as_slice()))
+}
+
+/// libsodium `crypto_box_seal`: ephemeral public key followed by a box.
+#[wasm_bindgen]
+pub fn sealed_box_seal(
+    plaintext: Uint8Array,
+    recipient_public_key: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&plaintext);
+    let pk = input_bytes(&recipient_public_key);
+
+    let sealed =
+        x25519::sealed_box_seal(&data, &pk).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(sealed.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn sealed_box_open(
+    sealed: Uint8Array,
+    recipient_public_key: Uint8Array,
+    recipient_private_key: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    let data = input_bytes(&sealed);
+    let pk = input_bytes(&recipient_public_key);
+    let sk = secret_bytes(&recipient_private_key);
+
+    let plaintext = x25519::sealed_box_open(&data, &pk, &sk)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(plaintext.as_slice()))
+}
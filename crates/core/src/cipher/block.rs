@@ -0,0 +1,290 @@
+//! Camellia (RFC 3713), ARIA (RFC 5794) and SM4 (GB/T 32907) in GCM, CBC
+//! and CTR mode.
+//!
+//! All three are 128-bit block ciphers, so they share the AES mode
+//! implementations: `AesGcm<C, U12>` for GCM, PKCS#7-padded CBC, and CTR
+//! with the whole block as a big-endian counter. CBC and CTR outputs match
+//! OpenSSL's `camellia-*`, `aria-*` and `sm4-*` ciphers; GCM matches
+//! OpenSSL for ARIA and SM4 (and RFC 8998's SM4-GCM). OpenSSL has no
+//! Camellia-GCM, so that one is the generic construction with no interop
+//! reference.
+
+use aes_gcm::aead::{Aead, KeyInit, Nonce, Payload};
+use aes_gcm::AesGcm;
+use aria::{Aria128, Aria192, Aria256};
+use camellia::{Camellia128, Camellia192, Camellia256};
+use cbc::cipher::block_padding::NoPadding;
+use cbc::cipher::{
+    BlockCipher, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, BlockSizeUser,
+};
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use sm4::Sm4;
+use typenum::{U12, U16};
+
+use super::padding::{pad, unpad, Padding};
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum BlockAlgorithm {
+    Camellia128Gcm,
+    Camellia192Gcm,
+    Camellia256Gcm,
+    Camellia128Cbc,
+    Camellia192Cbc,
+    Camellia256Cbc,
+    Camellia128Ctr,
+    Camellia192Ctr,
+    Camellia256Ctr,
+    Aria128Gcm,
+    Aria192Gcm,
+    Aria256Gcm,
+    Aria128Cbc,
+    Aria192Cbc,
+    Aria256Cbc,
+    Aria128Ctr,
+    Aria192Ctr,
+    Aria256Ctr,
+    Sm4Gcm,
+    Sm4Cbc,
+    Sm4Ctr,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cipher {
+    Camellia128,
+    Camellia192,
+    Camellia256,
+    Aria128,
+    Aria192,
+    Aria256,
+    Sm4,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Gcm,
+    Cbc,
+    Ctr,
+}
+
+impl BlockAlgorithm {
+    /// Human-readable name used in error messages, e.g. `ARIA-128-GCM`.
+    pub fn name(self) -> &'static str {
+        match self {
+            BlockAlgorithm::Camellia128Gcm => "CAMELLIA-128-GCM",
+            BlockAlgorithm::Camellia192Gcm => "CAMELLIA-192-GCM",
+            BlockAlgorithm::Camellia256Gcm => "CAMELLIA-256-GCM",
+            BlockAlgorithm::Camellia128Cbc => "CAMELLIA-128-CBC",
+            BlockAlgorithm::Camellia192Cbc => "CAMELLIA-192-CBC",
+            BlockAlgorithm::Camellia256Cbc => "CAMELLIA-256-CBC",
+            BlockAlgorithm::Camellia128Ctr => "CAMELLIA-128-CTR",
+            BlockAlgorithm::Camellia192Ctr => "CAMELLIA-192-CTR",
+            BlockAlgorithm::Camellia256Ctr => "CAMELLIA-256-CTR",
+            BlockAlgorithm::Aria128Gcm => "ARIA-128-GCM",
+            BlockAlgorithm::Aria192Gcm => "ARIA-192-GCM",
+            BlockAlgorithm::Aria256Gcm => "ARIA-256-GCM",
+            BlockAlgorithm::Aria128Cbc => "ARIA-128-CBC",
+            BlockAlgorithm::Aria192Cbc => "ARIA-192-CBC",
+            BlockAlgorithm::Aria256Cbc => "ARIA-256-CBC",
+            BlockAlgorithm::Aria128Ctr => "ARIA-128-CTR",
+            BlockAlgorithm::Aria192Ctr => "ARIA-192-CTR",
+            BlockAlgorithm::Aria256Ctr => "ARIA-256-CTR",
+            BlockAlgorithm::Sm4Gcm => "SM4-GCM",
+            BlockAlgorithm::Sm4Cbc => "SM4-CBC",
+            BlockAlgorithm::Sm4Ctr => "SM4-CTR",
+        }
+    }
+
+    fn parts(self) -> (Cipher, Mode) {
+        match self {
+            BlockAlgorithm::Camellia128Gcm => (Cipher::Camellia128, Mode::Gcm),
+            BlockAlgorithm::Camellia192Gcm => (Cipher::Camellia192, Mode::Gcm),
+            BlockAlgorithm::Camellia256Gcm => (Cipher::Camellia256, Mode::Gcm),
+            BlockAlgorithm::Camellia128Cbc => (Cipher::Camellia128, Mode::Cbc),
+            BlockAlgorithm::Camellia192Cbc => (Cipher::Camellia192, Mode::Cbc),
+            BlockAlgorithm::Camellia256Cbc => (Cipher::Camellia256, Mode::Cbc),
+            BlockAlgorithm::Camellia128Ctr => (Cipher::Camellia128, Mode::Ctr),
+            BlockAlgorithm::Camellia192Ctr => (Cipher::Camellia192, Mode::Ctr),
+            BlockAlgorithm::Camellia256Ctr => (Cipher::Camellia256, Mode::Ctr),
+            BlockAlgorithm::Aria128Gcm => (Cipher::Aria128, Mode::Gcm),
+            BlockAlgorithm::Aria192Gcm => (Cipher::Aria192, Mode::Gcm),
+            BlockAlgorithm::Aria256Gcm => (Cipher::Aria256, Mode::Gcm),
+            BlockAlgorithm::Aria128Cbc => (Cipher::Aria128, Mode::Cbc),
+            BlockAlgorithm::Aria192Cbc => (Cipher::Aria192, Mode::Cbc),
+            BlockAlgorithm::Aria256Cbc => (Cipher::Aria256, Mode::Cbc),
+            BlockAlgorithm::Aria128Ctr => (Cipher::Aria128, Mode::Ctr),
+            BlockAlgorithm::Aria192Ctr => (Cipher::Aria192, Mode::Ctr),
+            BlockAlgorithm::Aria256Ctr => (Cipher::Aria256, Mode::Ctr),
+            BlockAlgorithm::Sm4Gcm => (Cipher::Sm4, Mode::Gcm),
+            BlockAlgorithm::Sm4Cbc => (Cipher::Sm4, Mode::Cbc),
+            BlockAlgorithm::Sm4Ctr => (Cipher::Sm4, Mode::Ctr),
+        }
+    }
+
+    /// Whether the mode authenticates, i.e. is GCM.
+    pub fn is_aead(self) -> bool {
+        self.parts().1 == Mode::Gcm
+    }
+
+    /// Required key length in bytes.
+    pub fn key_len(self) -> usize {
+        match self.parts().0 {
+            Cipher::Camellia192 | Cipher::Aria192 => 24,
+            Cipher::Camellia256 | Cipher::Aria256 => 32,
+            _ => 16,
+        }
+    }
+
+    /// Required nonce (GCM) or IV (CBC, CTR) length in bytes.
+    pub fn nonce_len(self) -> usize {
+        if self.is_aead() {
+            12
+        } else {
+            16
+        }
+    }
+}
+
+fn check_lengths(algo: BlockAlgorithm, key: &[u8], nonce: &[u8], aad: &[u8]) -> Result<()> {
+    if key.len() != algo.key_len() || nonce.len() != algo.nonce_len() {
+        return Err(Error::InvalidLength(format!(
+            "{}: key must be {} bytes, {} must be {} bytes",
+            algo.name(),
+            algo.key_len(),
+            if algo.is_aead() { "nonce" } else { "IV" },
+            algo.nonce_len()
+        )));
+    }
+    if !aad.is_empty() && !algo.is_aead() {
+        return Err(Error::InvalidParameter(format!(
+            "{} does not authenticate associated data",
+            algo.name()
+        )));
+    }
+
+    Ok(())
+}
+
+/// GCM, CBC or CTR over the block cipher `C`, in the direction given by
+/// `encrypt`.
+fn apply<C>(
+    algo: BlockAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    data: &[u8],
+    aad: &[u8],
+    encrypt: bool,
+) -> Result<Vec<u8>>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + BlockDecrypt + KeyInit,
+{
+    let invalid_key = || Error::InvalidKey(format!("Invalid {} key/IV", algo.name()));
+    let failed = || Error::Decryption(format!("{} decryption failed", algo.name()));
+
+    match algo.parts().1 {
+        Mode::Gcm => {
+            let cipher = AesGcm::<C, U12>::new_from_slice(key).map_err(|_| invalid_key())?;
+            let nonce = Nonce::<AesGcm<C, U12>>::from_slice(nonce);
+            let payload = Payload { msg: data, aad };
+            if encrypt {
+                cipher
+                    .encrypt(nonce, payload)
+                    .map_err(|_| Error::Operation(format!("{} encryption failed", algo.name())))
+            } else {
+                cipher.decrypt(nonce, payload).map_err(|_| failed())
+            }
+        }
+        Mode::Cbc if encrypt => {
+            let padded = pad(data, 16, Padding::Pkcs7)
+                .ok_or_else(|| Error::Operation(format!("{} padding failed", algo.name())))?;
+            Ok(cbc::Encryptor::<C>::new_from_slices(key, nonce)
+                .map_err(|_| invalid_key())?
+                .encrypt_padded_vec_mut::<NoPadding>(&padded))
+        }
+        Mode::Cbc => {
+            let mut plaintext = cbc::Decryptor::<C>::new_from_slices(key, nonce)
+                .map_err(|_| invalid_key())?
+                .decrypt_padded_vec_mut::<NoPadding>(data)
+                .map_err(|_| failed())?;
+            if !unpad(&mut plaintext, 16, Padding::Pkcs7) {
+                return Err(failed());
+            }
+            Ok(plaintext)
+        }
+        Mode::Ctr => {
+            let mut cipher =
+                ctr::Ctr128BE::<C>::new_from_slices(key, nonce).map_err(|_| invalid_key())?;
+            let mut data = data.to_vec();
+            cipher.apply_keystream(&mut data);
+            Ok(data)
+        }
+    }
+}
+
+fn dispatch(
+    algo: BlockAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    data: &[u8],
+    aad: &[u8],
+    encrypt: bool,
+) -> Result<Vec<u8>> {
+    check_lengths(algo, key, nonce, aad)?;
+
+    match algo.parts().0 {
+        Cipher::Camellia128 => apply::<Camellia128>(algo, key, nonce, data, aad, encrypt),
+        Cipher::Camellia192 => apply::<Camellia192>(algo, key, nonce, data, aad, encrypt),
+        Cipher::Camellia256 => apply::<Camellia256>(algo, key, nonce, data, aad, encrypt),
+        Cipher::Aria128 => apply::<Aria128>(algo, key, nonce, data, aad, encrypt),
+        Cipher::Aria192 => apply::<Aria192>(algo, key, nonce, data, aad, encrypt),
+        Cipher::Aria256 => apply::<Aria256>(algo, key, nonce, data, aad, encrypt),
+        Cipher::Sm4 => apply::<Sm4>(algo, key, nonce, data, aad, encrypt),
+    }
+}
+
+/// Encrypts `plaintext`. GCM appends the 16-byte tag, CBC pads with PKCS#7
+/// and CTR returns ciphertext of the same length.
+pub fn encrypt(
+    plaintext: &[u8],
+    key: &[u8],
+    nonce_or_iv: &[u8],
+    algo: BlockAlgorithm,
+) -> Result<Vec<u8>> {
+    dispatch(algo, key, nonce_or_iv, plaintext, &[], true)
+}
+
+/// Decrypts and, for GCM, authenticates `ciphertext`.
+pub fn decrypt(
+    ciphertext: &[u8],
+    key: &[u8],
+    nonce_or_iv: &[u8],
+    algo: BlockAlgorithm,
+) -> Result<Vec<u8>> {
+    dispatch(algo, key, nonce_or_iv, ciphertext, &[], false)
+}
+
+/// [`encrypt`] with associated data bound into the GCM tag; CBC and CTR
+/// reject non-empty AAD.
+pub fn encrypt_with_aad(
+    plaintext: &[u8],
+    key: &[u8],
+    nonce_or_iv: &[u8],
+    aad: &[u8],
+    algo: BlockAlgorithm,
+) -> Result<Vec<u8>> {
+    dispatch(algo, key, nonce_or_iv, plaintext, aad, true)
+}
+
+/// [`decrypt`] with associated data; must match what was passed to
+/// [`encrypt_with_aad`].
+pub fn decrypt_with_aad(
+    ciphertext: &[u8],
+    key: &[u8],
+    nonce_or_iv: &[u8],
+    aad: &[u8],
+    algo: BlockAlgorithm,
+) -> Result<Vec<u8>> {
+    dispatch(algo, key, nonce_or_iv, ciphertext, aad, false)
+}
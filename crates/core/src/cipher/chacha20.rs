@@ -0,0 +1,309 @@
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use chacha20::{hchacha, ChaCha20, XChaCha20};
+use chacha20poly1305::aead::{Aead, AeadInPlace, KeyInit, Nonce, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce as AeadNonce, Tag, XChaCha20Poly1305};
+use typenum::U10;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ChaCha20Algorithm {
+    /// Raw ChaCha20 stream cipher (no authentication)
+    Chacha20,
+    /// ChaCha20-Poly1305 AEAD (authenticated encryption)
+    Chacha20Poly1305,
+    /// XChaCha20 with a 24-byte nonce (no authentication)
+    XChacha20,
+    /// XChaCha20-Poly1305 AEAD with a 24-byte nonce, safe to pick at random
+    XChacha20Poly1305,
+}
+
+impl ChaCha20Algorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            ChaCha20Algorithm::Chacha20 => "ChaCha20",
+            ChaCha20Algorithm::Chacha20Poly1305 => "ChaCha20-Poly1305",
+            ChaCha20Algorithm::XChacha20 => "XChaCha20",
+            ChaCha20Algorithm::XChacha20Poly1305 => "XChaCha20-Poly1305",
+        }
+    }
+
+    /// Required nonce length in bytes: 12, or 24 for the extended variants.
+    pub fn nonce_len(self) -> usize {
+        match self {
+            ChaCha20Algorithm::Chacha20 | ChaCha20Algorithm::Chacha20Poly1305 => 12,
+            ChaCha20Algorithm::XChacha20 | ChaCha20Algorithm::XChacha20Poly1305 => 24,
+        }
+    }
+
+    fn is_aead(self) -> bool {
+        matches!(
+            self,
+            ChaCha20Algorithm::Chacha20Poly1305 | ChaCha20Algorithm::XChacha20Poly1305
+        )
+    }
+}
+
+fn check_lengths(algo: ChaCha20Algorithm, key: &[u8], nonce: &[u8]) -> Result<()> {
+    check_key(algo, key)?;
+    check_nonce(algo, nonce)
+}
+
+fn check_key(algo: ChaCha20Algorithm, key: &[u8]) -> Result<()> {
+    if key.len() != 32 {
+        return Err(Error::InvalidLength(format!(
+            "{}: key must be 32 bytes",
+            algo.name()
+        )));
+    }
+    Ok(())
+}
+
+fn check_nonce(algo: ChaCha20Algorithm, nonce: &[u8]) -> Result<()> {
+    if nonce.len() != algo.nonce_len() {
+        return Err(Error::InvalidLength(format!(
+            "{}: nonce must be {} bytes",
+            algo.name(),
+            algo.nonce_len()
+        )));
+    }
+    Ok(())
+}
+
+fn apply_keystream<C: KeyIvInit + StreamCipher>(
+    algo: ChaCha20Algorithm,
+    key: &[u8],
+    nonce: &[u8],
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    let mut cipher = C::new_from_slices(key, nonce)
+        .map_err(|_| Error::InvalidKey(format!("Invalid {} key/nonce", algo.name())))?;
+    let mut data = data.to_vec();
+    cipher.apply_keystream(&mut data);
+    Ok(data)
+}
+
+fn aead(key: &[u8]) -> Result<ChaCha20Poly1305> {
+    ChaCha20Poly1305::new_from_slice(key)
+        .map_err(|_| Error::InvalidKey("Invalid ChaCha20-Poly1305 key".into()))
+}
+
+fn aead_new<C: KeyInit>(algo: ChaCha20Algorithm, key: &[u8]) -> Result<C> {
+    C::new_from_slice(key).map_err(|_| Error::InvalidKey(format!("Invalid {} key", algo.name())))
+}
+
+fn aead_seal<C: Aead>(cipher: &C, nonce: &[u8], msg: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    cipher
+        .encrypt(Nonce::<C>::from_slice(nonce), Payload { msg, aad })
+        .map_err(|_| Error::Operation("AEAD encryption failed".into()))
+}
+
+fn aead_open<C: Aead>(cipher: &C, nonce: &[u8], msg: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    cipher
+        .decrypt(Nonce::<C>::from_slice(nonce), Payload { msg, aad })
+        .map_err(|_| Error::Decryption("AEAD decryption failed or tag mismatch".into()))
+}
+
+fn aead_encrypt<C: KeyInit + Aead>(
+    algo: ChaCha20Algorithm,
+    key: &[u8],
+    nonce: &[u8],
+    msg: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    aead_seal(&aead_new::<C>(algo, key)?, nonce, msg, aad)
+}
+
+fn aead_decrypt<C: KeyInit + Aead>(
+    algo: ChaCha20Algorithm,
+    key: &[u8],
+    nonce: &[u8],
+    msg: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    aead_open(&aead_new::<C>(algo, key)?, nonce, msg, aad)
+}
+
+/// HChaCha20 (draft-irtf-cfrg-xchacha section 2.2): derives a 32-byte
+/// subkey from `key` and the first 16 bytes of an XChaCha20 nonce.
+pub fn hchacha20(key: &[u8], input: &[u8]) -> Result<Vec<u8>> {
+    if key.len() != 32 || input.len() != 16 {
+        return Err(Error::InvalidLength(
+            "HChaCha20: key must be 32 bytes, input must be 16 bytes".into(),
+        ));
+    }
+
+    Ok(hchacha::<U10>(key.into(), input.into()).to_vec())
+}
+
+pub fn encrypt(
+    plaintext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    algo: ChaCha20Algorithm,
+) -> Result<Vec<u8>> {
+    encrypt_with_aad(plaintext, key, nonce, &[], algo)
+}
+
+pub fn decrypt(
+    ciphertext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    algo: ChaCha20Algorithm,
+) -> Result<Vec<u8>> {
+    decrypt_with_aad(ciphertext, key, nonce, &[], algo)
+}
+
+fn check_aad(algo: ChaCha20Algorithm, aad: &[u8]) -> Result<()> {
+    if !aad.is_empty() && !algo.is_aead() {
+        return Err(Error::InvalidParameter(format!(
+            "{} does not authenticate associated data",
+            algo.name()
+        )));
+    }
+    Ok(())
+}
+
+/// [`encrypt`] with associated data authenticated by Poly1305 (RFC 8439
+/// section 2.8). Raw ChaCha20 and XChaCha20 reject non-empty AAD.
+pub fn encrypt_with_aad(
+    plaintext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    algo: ChaCha20Algorithm,
+) -> Result<Vec<u8>> {
+    check_lengths(algo, key, nonce)?;
+    check_aad(algo, aad)?;
+
+    match algo {
+        ChaCha20Algorithm::Chacha20 => apply_keystream::<ChaCha20>(algo, key, nonce, plaintext),
+        ChaCha20Algorithm::XChacha20 => apply_keystream::<XChaCha20>(algo, key, nonce, plaintext),
+        ChaCha20Algorithm::Chacha20Poly1305 => {
+            aead_encrypt::<ChaCha20Poly1305>(algo, key, nonce, plaintext, aad)
+        }
+        ChaCha20Algorithm::XChacha20Poly1305 => {
+            aead_encrypt::<XChaCha20Poly1305>(algo, key, nonce, plaintext, aad)
+        }
+    }
+}
+
+pub fn decrypt_with_aad(
+    ciphertext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    algo: ChaCha20Algorithm,
+) -> Result<Vec<u8>> {
+    check_lengths(algo, key, nonce)?;
+    check_aad(algo, aad)?;
+
+    match algo {
+        ChaCha20Algorithm::Chacha20 => apply_keystream::<ChaCha20>(algo, key, nonce, ciphertext),
+        ChaCha20Algorithm::XChacha20 => apply_keystream::<XChaCha20>(algo, key, nonce, ciphertext),
+        ChaCha20Algorithm::Chacha20Poly1305 => {
+            aead_decrypt::<ChaCha20Poly1305>(algo, key, nonce, ciphertext, aad)
+        }
+        ChaCha20Algorithm::XChacha20Poly1305 => {
+            aead_decrypt::<XChaCha20Poly1305>(algo, key, nonce, ciphertext, aad)
+        }
+    }
+}
+
+/// ChaCha20-Poly1305 returning `(ciphertext, tag)` with the 16-byte tag kept
+/// separate, as JWE and COSE carry it.
+pub fn encrypt_detached(
+    plaintext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    check_lengths(ChaCha20Algorithm::Chacha20Poly1305, key, nonce)?;
+
+    let mut buffer = plaintext.to_vec();
+    let tag = aead(key)?
+        .encrypt_in_place_detached(AeadNonce::from_slice(nonce), aad, &mut buffer)
+        .map_err(|_| Error::Operation("AEAD encryption failed".into()))?;
+
+    Ok((buffer, tag.to_vec()))
+}
+
+pub fn decrypt_detached(
+    ciphertext: &[u8],
+    tag: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    check_lengths(ChaCha20Algorithm::Chacha20Poly1305, key, nonce)?;
+    if tag.len() != 16 {
+        return Err(Error::InvalidLength(
+            "ChaCha20-Poly1305: tag must be 16 bytes".into(),
+        ));
+    }
+
+    let mut buffer = ciphertext.to_vec();
+    aead(key)?
+        .decrypt_in_place_detached(
+            AeadNonce::from_slice(nonce),
+            aad,
+            &mut buffer,
+            Tag::from_slice(tag),
+        )
+        .map_err(|_| Error::Decryption("AEAD decryption failed or tag mismatch".into()))?;
+
+    Ok(buffer)
+}
+
+enum AeadInner {
+    ChaCha(ChaCha20Poly1305),
+    XChaCha(XChaCha20Poly1305),
+}
+
+/// ChaCha20-Poly1305 or XChaCha20-Poly1305 bound to one key, so the key is
+/// copied in once rather than on every call. It is wiped when the value is
+/// dropped.
+pub struct ChaCha20Poly1305Cipher {
+    inner: AeadInner,
+    algo: ChaCha20Algorithm,
+}
+
+impl ChaCha20Poly1305Cipher {
+    pub fn new(key: &[u8], algo: ChaCha20Algorithm) -> Result<Self> {
+        if !algo.is_aead() {
+            return Err(Error::InvalidParameter(format!(
+                "{} is not an AEAD, use ChaCha20-Poly1305 or XChaCha20-Poly1305",
+                algo.name()
+            )));
+        }
+        check_key(algo, key)?;
+
+        let inner = match algo {
+            ChaCha20Algorithm::Chacha20Poly1305 => AeadInner::ChaCha(aead_new(algo, key)?),
+            _ => AeadInner::XChaCha(aead_new(algo, key)?),
+        };
+
+        Ok(Self { inner, algo })
+    }
+
+    /// Same output as [`encrypt_with_aad`] under this key.
+    pub fn encrypt(&self, plaintext: &[u8], nonce: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        check_nonce(self.algo, nonce)?;
+
+        match &self.inner {
+            AeadInner::ChaCha(c) => aead_seal(c, nonce, plaintext, aad),
+            AeadInner::XChaCha(c) => aead_seal(c, nonce, plaintext, aad),
+        }
+    }
+
+    pub fn decrypt(&self, ciphertext: &[u8], nonce: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        check_nonce(self.algo, nonce)?;
+
+        match &self.inner {
+            AeadInner::ChaCha(c) => aead_open(c, nonce, ciphertext, aad),
+            AeadInner::XChaCha(c) => aead_open(c, nonce, ciphertext, aad),
+        }
+    }
+}
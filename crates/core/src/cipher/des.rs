@@ -0,0 +1,546 @@
+use cbc::cipher::block_padding::NoPadding;
+use cbc::cipher::generic_array::GenericArray;
+use cbc::cipher::{
+    BlockCipher, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, BlockSizeUser,
+    KeyInit,
+};
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use ctr::Ctr64BE;
+use des::{Des, TdesEde2, TdesEde3};
+use subtle::ConstantTimeEq;
+use typenum::U8;
+
+pub use super::padding::Padding;
+use super::padding::{pad, unpad};
+use crate::{Error, Result};
+
+// DES and 3DES both use an 8-byte block and IV; EDE2 takes a 16-byte key
+// (K1 K2, with K3 = K1) and EDE3 a 24-byte key. ECB takes no IV.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum DesAlgorithm {
+    /// DES in CBC mode with PKCS7 padding
+    DesCbc,
+    /// 3DES (EDE3) in CBC mode with PKCS7 padding
+    TdesCbc,
+    /// DES in CTR mode (no padding)
+    DesCtr,
+    /// 3DES (EDE3) in CTR mode (no padding)
+    TdesCtr,
+    /// DES in ECB mode with PKCS7 padding
+    DesEcb,
+    /// 3DES (EDE3) in ECB mode with PKCS7 padding
+    TdesEcb,
+    /// Two-key 3DES (EDE2) in CBC mode with PKCS7 padding
+    TdesEde2Cbc,
+    /// Two-key 3DES (EDE2) in CTR mode (no padding)
+    TdesEde2Ctr,
+    /// Two-key 3DES (EDE2) in ECB mode with PKCS7 padding
+    TdesEde2Ecb,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cipher {
+    Des,
+    TdesEde2,
+    TdesEde3,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Cbc,
+    Ctr,
+    Ecb,
+}
+
+impl DesAlgorithm {
+    /// Human-readable name used in error messages, e.g. `3DES-EDE2-CBC`.
+    pub fn name(self) -> &'static str {
+        match self {
+            DesAlgorithm::DesCbc => "DES-CBC",
+            DesAlgorithm::TdesCbc => "3DES-CBC",
+            DesAlgorithm::DesCtr => "DES-CTR",
+            DesAlgorithm::TdesCtr => "3DES-CTR",
+            DesAlgorithm::DesEcb => "DES-ECB",
+            DesAlgorithm::TdesEcb => "3DES-ECB",
+            DesAlgorithm::TdesEde2Cbc => "3DES-EDE2-CBC",
+            DesAlgorithm::TdesEde2Ctr => "3DES-EDE2-CTR",
+            DesAlgorithm::TdesEde2Ecb => "3DES-EDE2-ECB",
+        }
+    }
+
+    fn parts(self) -> (Cipher, Mode) {
+        match self {
+            DesAlgorithm::DesCbc => (Cipher::Des, Mode::Cbc),
+            DesAlgorithm::TdesCbc => (Cipher::TdesEde3, Mode::Cbc),
+            DesAlgorithm::DesCtr => (Cipher::Des, Mode::Ctr),
+            DesAlgorithm::TdesCtr => (Cipher::TdesEde3, Mode::Ctr),
+            DesAlgorithm::DesEcb => (Cipher::Des, Mode::Ecb),
+            DesAlgorithm::TdesEcb => (Cipher::TdesEde3, Mode::Ecb),
+            DesAlgorithm::TdesEde2Cbc => (Cipher::TdesEde2, Mode::Cbc),
+            DesAlgorithm::TdesEde2Ctr => (Cipher::TdesEde2, Mode::Ctr),
+            DesAlgorithm::TdesEde2Ecb => (Cipher::TdesEde2, Mode::Ecb),
+        }
+    }
+
+    pub fn key_len(self) -> usize {
+        match self.parts().0 {
+            Cipher::Des => 8,
+            Cipher::TdesEde2 => 16,
+            Cipher::TdesEde3 => 24,
+        }
+    }
+
+    /// 8, or 0 for ECB.
+    pub fn iv_len(self) -> usize {
+        match self.parts().1 {
+            Mode::Ecb => 0,
+            _ => 8,
+        }
+    }
+}
+
+#[inline]
+fn require_len(actual: usize, expected: usize, what: &str) -> Result<()> {
+    if actual != expected {
+        Err(Error::InvalidLength(format!(
+            "{} must be {} bytes (got {})",
+            what, expected, actual
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_lengths(algo: DesAlgorithm, key: &[u8], iv: &[u8]) -> Result<()> {
+    let (cipher, mode) = algo.parts();
+    let key_what = match cipher {
+        Cipher::Des => "DES key",
+        Cipher::TdesEde2 => "3DES key (EDE2)",
+        Cipher::TdesEde3 => "3DES key (EDE3)",
+    };
+    require_len(key.len(), algo.key_len(), key_what)?;
+    match mode {
+        Mode::Cbc => require_len(iv.len(), 8, &format!("{} IV", prefix(cipher))),
+        Mode::Ctr => require_len(iv.len(), 8, &format!("{} CTR IV/nonce", prefix(cipher))),
+        Mode::Ecb if !iv.is_empty() => {
+            Err(Error::InvalidLength(format!("{} takes no IV", algo.name())))
+        }
+        Mode::Ecb => Ok(()),
+    }
+}
+
+fn check_padding(algo: DesAlgorithm, padding: Padding) -> Result<()> {
+    if padding != Padding::NoPadding && algo.parts().1 == Mode::Ctr {
+        return Err(Error::InvalidParameter(format!(
+            "{} is a stream mode and takes no padding",
+            algo.name()
+        )));
+    }
+    Ok(())
+}
+
+fn prefix(cipher: Cipher) -> &'static str {
+    match cipher {
+        Cipher::Des => "DES",
+        _ => "3DES",
+    }
+}
+
+fn ctr_apply<C: KeyIvInit + StreamCipher>(
+    key: &[u8],
+    iv: &[u8],
+    data: &[u8],
+    what: &str,
+) -> Result<Vec<u8>> {
+    let mut cipher = C::new_from_slices(key, iv)
+        .map_err(|_| Error::InvalidKey(format!("Invalid {} CTR key/IV", what)))?;
+    let mut data = data.to_vec();
+    cipher.apply_keystream(&mut data);
+    Ok(data)
+}
+
+/// CBC, ECB or CTR over the DES variant `C`. `padding` only applies to CBC
+/// and ECB.
+fn apply_encrypt<C>(
+    algo: DesAlgorithm,
+    key: &[u8],
+    iv: &[u8],
+    data: &[u8],
+    padding: Padding,
+) -> Result<Vec<u8>>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U8> + BlockEncrypt + BlockDecrypt + KeyInit,
+{
+    let (cipher, mode) = algo.parts();
+    let invalid_key = || Error::InvalidKey(format!("Invalid {} key/IV", prefix(cipher)));
+
+    if mode == Mode::Ctr {
+        return ctr_apply::<Ctr64BE<C>>(key, iv, data, prefix(cipher));
+    }
+
+    let padded = pad(data, 8, padding).ok_or_else(|| {
+        Error::InvalidLength(format!(
+            "{} without padding needs a multiple of 8 bytes",
+            algo.name()
+        ))
+    })?;
+    Ok(if mode == Mode::Cbc {
+        cbc::Encryptor::<C>::new_from_slices(key, iv)
+            .map_err(|_| invalid_key())?
+            .encrypt_padded_vec_mut::<NoPadding>(&padded)
+    } else {
+        ecb::Encryptor::<C>::new_from_slice(key)
+            .map_err(|_| invalid_key())?
+            .encrypt_padded_vec_mut::<NoPadding>(&padded)
+    })
+}
+
+/// Inverse of [`apply_encrypt`]. A wrong length and bad padding produce the
+/// same error, and the padding itself is checked in constant time.
+fn apply_decrypt<C>(
+    algo: DesAlgorithm,
+    key: &[u8],
+    iv: &[u8],
+    data: &[u8],
+    padding: Padding,
+) -> Result<Vec<u8>>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U8> + BlockEncrypt + BlockDecrypt + KeyInit,
+{
+    let (cipher, mode) = algo.parts();
+    let invalid_key = || Error::InvalidKey(format!("Invalid {} key/IV", prefix(cipher)));
+    let failed = || {
+        Error::Decryption(format!(
+            "{} {} padding error or invalid data",
+            prefix(cipher),
+            if mode == Mode::Cbc { "CBC" } else { "ECB" }
+        ))
+    };
+
+    if mode == Mode::Ctr {
+        return ctr_apply::<Ctr64BE<C>>(key, iv, data, prefix(cipher));
+    }
+
+    let decrypted = if mode == Mode::Cbc {
+        cbc::Decryptor::<C>::new_from_slices(key, iv)
+            .map_err(|_| invalid_key())?
+            .decrypt_padded_vec_mut::<NoPadding>(data)
+    } else {
+        ecb::Decryptor::<C>::new_from_slice(key)
+            .map_err(|_| invalid_key())?
+            .decrypt_padded_vec_mut::<NoPadding>(data)
+    };
+    let mut plaintext = decrypted.map_err(|_| failed())?;
+    if !unpad(&mut plaintext, 8, padding) {
+        return Err(failed());
+    }
+    Ok(plaintext)
+}
+
+fn default_padding(algo: DesAlgorithm) -> Padding {
+    match algo.parts().1 {
+        Mode::Ctr => Padding::NoPadding,
+        _ => Padding::Pkcs7,
+    }
+}
+
+pub fn encrypt(plaintext: &[u8], key: &[u8], iv: &[u8], algo: DesAlgorithm) -> Result<Vec<u8>> {
+    encrypt_padded(plaintext, key, iv, default_padding(algo), algo)
+}
+
+pub fn decrypt(ciphertext: &[u8], key: &[u8], iv: &[u8], algo: DesAlgorithm) -> Result<Vec<u8>> {
+    decrypt_padded(ciphertext, key, iv, default_padding(algo), algo)
+}
+
+/// Encrypts with CBC or ECB under the given `padding`; CTR accepts only
+/// [`Padding::NoPadding`]. ECB takes an empty `iv`.
+pub fn encrypt_padded(
+    plaintext: &[u8],
+    key: &[u8],
+    iv: &[u8],
+    padding: Padding,
+    algo: DesAlgorithm,
+) -> Result<Vec<u8>> {
+    check_lengths(algo, key, iv)?;
+    check_padding(algo, padding)?;
+
+    match algo.parts().0 {
+        Cipher::Des => apply_encrypt::<Des>(algo, key, iv, plaintext, padding),
+        Cipher::TdesEde2 => apply_encrypt::<TdesEde2>(algo, key, iv, plaintext, padding),
+        Cipher::TdesEde3 => apply_encrypt::<TdesEde3>(algo, key, iv, plaintext, padding),
+    }
+}
+
+/// Inverse of [`encrypt_padded`]. Every padding failure is reported as the
+/// same [`Error::Decryption`] after constant-time checking.
+pub fn decrypt_padded(
+    ciphertext: &[u8],
+    key: &[u8],
+    iv: &[u8],
+    padding: Padding,
+    algo: DesAlgorithm,
+) -> Result<Vec<u8>> {
+    check_lengths(algo, key, iv)?;
+    check_padding(algo, padding)?;
+
+    match algo.parts().0 {
+        Cipher::Des => apply_decrypt::<Des>(algo, key, iv, ciphertext, padding),
+        Cipher::TdesEde2 => apply_decrypt::<TdesEde2>(algo, key, iv, ciphertext, padding),
+        Cipher::TdesEde3 => apply_decrypt::<TdesEde3>(algo, key, iv, ciphertext, padding),
+    }
+}
+
+/// The four weak keys (FIPS 74 3.6), with odd parity.
+const WEAK_KEYS: [[u8; 8]; 4] = [
+    [0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01],
+    [0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe],
+    [0xe0, 0xe0, 0xe0, 0xe0, 0xf1, 0xf1, 0xf1, 0xf1],
+    [0x1f, 0x1f, 0x1f, 0x1f, 0x0e, 0x0e, 0x0e, 0x0e],
+];
+
+/// The six pairs of semi-weak keys (FIPS 74 3.6), with odd parity.
+const SEMI_WEAK_KEYS: [[u8; 8]; 12] = [
+    [0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe],
+    [0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01],
+    [0x1f, 0xe0, 0x1f, 0xe0, 0x0e, 0xf1, 0x0e, 0xf1],
+    [0xe0, 0x1f, 0xe0, 0x1f, 0xf1, 0x0e, 0xf1, 0x0e],
+    [0x01, 0xe0, 0x01, 0xe0, 0x01, 0xf1, 0x01, 0xf1],
+    [0xe0, 0x01, 0xe0, 0x01, 0xf1, 0x01, 0xf1, 0x01],
+    [0x1f, 0xfe, 0x1f, 0xfe, 0x0e, 0xfe, 0x0e, 0xfe],
+    [0xfe, 0x1f, 0xfe, 0x1f, 0xfe, 0x0e, 0xfe, 0x0e],
+    [0x01, 0x1f, 0x01, 0x1f, 0x01, 0x0e, 0x01, 0x0e],
+    [0x1f, 0x01, 0x1f, 0x01, 0x0e, 0x01, 0x0e, 0x01],
+    [0xe0, 0xfe, 0xe0, 0xfe, 0xf1, 0xfe, 0xf1, 0xfe],
+    [0xfe, 0xe0, 0xfe, 0xe0, 0xfe, 0xf1, 0xfe, 0xf1],
+];
+
+/// Result of [`check_key`]. Weak, semi-weak and degenerate keys still
+/// encrypt; rejecting them is up to the caller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DesKeyCheck {
+    /// Every byte has odd parity, as HSMs and FIPS 46-3 expect.
+    pub odd_parity: bool,
+    /// A single-DES component is one of the four weak keys.
+    pub weak: bool,
+    /// A single-DES component is one of the twelve semi-weak keys.
+    pub semi_weak: bool,
+    /// 3DES with K1 = K2 or K2 = K3, which reduces to single DES.
+    pub degenerate: bool,
+}
+
+/// Inspects a DES (8-byte), EDE2 (16-byte) or EDE3 (24-byte) key. Parity
+/// bits are ignored when comparing against the weak and semi-weak keys.
+pub fn check_key(key: &[u8]) -> Result<DesKeyCheck> {
+    if !matches!(key.len(), 8 | 16 | 24) {
+        return Err(Error::InvalidLength(format!(
+            "DES key must be 8, 16 or 24 bytes (got {})",
+            key.len()
+        )));
+    }
+
+    let strip = |k: &[u8]| -> [u8; 8] { std::array::from_fn(|i| k[i] & 0xfe) };
+    let listed = |list: &[[u8; 8]], k: &[u8]| list.iter().any(|w| strip(w) == strip(k));
+    let parts: Vec<&[u8]> = key.chunks(8).collect();
+
+    Ok(DesKeyCheck {
+        odd_parity: key.iter().all(|b| b.count_ones() % 2 == 1),
+        weak: parts.iter().any(|k| listed(&WEAK_KEYS, k)),
+        semi_weak: parts.iter().any(|k| listed(&SEMI_WEAK_KEYS, k)),
+        degenerate: parts.windows(2).any(|w| strip(w[0]) == strip(w[1])),
+    })
+}
+
+/// Sets the low bit of every byte so that each has odd parity.
+pub fn set_odd_parity(key: &mut [u8]) {
+    for b in key.iter_mut() {
+        *b = (*b & 0xfe) | u8::from((*b >> 1).count_ones() % 2 == 0);
+    }
+}
+
+/// ISO/IEC 9797-1 MAC algorithm over DES/3DES (8-byte MAC).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum MacAlgorithm {
+    /// Algorithm 1: CBC-MAC with a zero IV under a DES (8-byte), EDE2
+    /// (16-byte) or EDE3 (24-byte) key. With DES this is FIPS 113 / ANSI X9.9.
+    Algorithm1,
+    /// Algorithm 3, the retail MAC of ANSI X9.19: single-DES CBC-MAC under K,
+    /// then the last block is decrypted under K' and encrypted under K again.
+    /// Takes the 16-byte key K || K'.
+    Algorithm3,
+}
+
+/// ISO/IEC 9797-1 padding method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum MacPadding {
+    /// Method 1: zeros up to the block boundary, none if already aligned.
+    /// Empty input becomes one zero block.
+    Method1,
+    /// Method 2: `0x80` followed by zeros, always at least one byte.
+    Method2,
+}
+
+impl MacAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            MacAlgorithm::Algorithm1 => "ISO 9797-1 MAC algorithm 1",
+            MacAlgorithm::Algorithm3 => "ISO 9797-1 MAC algorithm 3 (retail MAC)",
+        }
+    }
+}
+
+#[derive(Clone)]
+enum MacCipher {
+    Des(Des),
+    TdesEde2(TdesEde2),
+    TdesEde3(TdesEde3),
+    Retail(Des, Des),
+}
+
+/// Incremental ISO/IEC 9797-1 MAC. Whole blocks are chained as they arrive,
+/// so memory stays constant however long the message is.
+#[derive(Clone)]
+pub struct DesMac {
+    cipher: MacCipher,
+    padding: MacPadding,
+    state: [u8; 8],
+    buffer: Vec<u8>,
+    empty: bool,
+}
+
+impl DesMac {
+    pub fn new(key: &[u8], algo: MacAlgorithm, padding: MacPadding) -> Result<Self> {
+        let invalid_key = |_| Error::InvalidKey(format!("Invalid {} key", algo.name()));
+        let cipher = match (algo, key.len()) {
+            (MacAlgorithm::Algorithm1, 8) => {
+                MacCipher::Des(Des::new_from_slice(key).map_err(invalid_key)?)
+            }
+            (MacAlgorithm::Algorithm1, 16) => {
+                MacCipher::TdesEde2(TdesEde2::new_from_slice(key).map_err(invalid_key)?)
+            }
+            (MacAlgorithm::Algorithm1, 24) => {
+                MacCipher::TdesEde3(TdesEde3::new_from_slice(key).map_err(invalid_key)?)
+            }
+            (MacAlgorithm::Algorithm3, 16) => MacCipher::Retail(
+                Des::new_from_slice(&key[..8]).map_err(invalid_key)?,
+                Des::new_from_slice(&key[8..]).map_err(invalid_key)?,
+            ),
+            (_, n) => {
+                let expected = match algo {
+                    MacAlgorithm::Algorithm1 => "8, 16 or 24",
+                    MacAlgorithm::Algorithm3 => "16",
+                };
+                return Err(Error::InvalidLength(format!(
+                    "{} key must be {} bytes (got {})",
+                    algo.name(),
+                    expected,
+                    n
+                )));
+            }
+        };
+
+        Ok(Self {
+            cipher,
+            padding,
+            state: [0; 8],
+            buffer: Vec::with_capacity(8),
+            empty: true,
+        })
+    }
+
+    fn chain(&mut self, block: &[u8]) {
+        for (s, b) in self.state.iter_mut().zip(block) {
+            *s ^= b;
+        }
+        let block = GenericArray::from_mut_slice(&mut self.state);
+        match &self.cipher {
+            MacCipher::Des(c) => c.encrypt_block(block),
+            MacCipher::TdesEde2(c) => c.encrypt_block(block),
+            MacCipher::TdesEde3(c) => c.encrypt_block(block),
+            MacCipher::Retail(k, _) => k.encrypt_block(block),
+        }
+    }
+
+    pub fn update(&mut self, mut input: &[u8]) {
+        self.empty &= input.is_empty();
+        if !self.buffer.is_empty() {
+            let take = input.len().min(8 - self.buffer.len());
+            self.buffer.extend_from_slice(&input[..take]);
+            input = &input[take..];
+            if self.buffer.len() < 8 {
+                return;
+            }
+            let block = std::mem::take(&mut self.buffer);
+            self.chain(&block);
+        }
+
+        let mut blocks = input.chunks_exact(8);
+        for block in &mut blocks {
+            self.chain(block);
+        }
+        self.buffer.extend_from_slice(blocks.remainder());
+    }
+
+    /// The full 8-byte MAC; callers that send a truncated MAC (commonly the
+    /// leftmost 4 bytes) cut it themselves.
+    pub fn finalize(mut self) -> Vec<u8> {
+        let mut last = std::mem::take(&mut self.buffer);
+        match self.padding {
+            MacPadding::Method1 if last.is_empty() && !self.empty => {}
+            MacPadding::Method1 => last.resize(8, 0),
+            MacPadding::Method2 => {
+                last.push(0x80);
+                last.resize(8, 0);
+            }
+        }
+        if !last.is_empty() {
+            self.chain(&last);
+        }
+
+        if let MacCipher::Retail(k, k2) = &self.cipher {
+            let block = GenericArray::from_mut_slice(&mut self.state);
+            k2.decrypt_block(block);
+            k.encrypt_block(block);
+        }
+        self.state.to_vec()
+    }
+
+    /// Compares in constant time against a full or truncated (4 to 8 byte)
+    /// MAC.
+    pub fn verify(self, tag: &[u8]) -> Result<bool> {
+        if !(4..=8).contains(&tag.len()) {
+            return Err(Error::InvalidLength(format!(
+                "DES MAC to verify must be 4 to 8 bytes (got {})",
+                tag.len()
+            )));
+        }
+        let mac = self.finalize();
+        Ok(bool::from(mac[..tag.len()].ct_eq(tag)))
+    }
+}
+
+/// One-shot [`DesMac`].
+pub fn mac(data: &[u8], key: &[u8], algo: MacAlgorithm, padding: MacPadding) -> Result<Vec<u8>> {
+    let mut mac = DesMac::new(key, algo, padding)?;
+    mac.update(data);
+    Ok(mac.finalize())
+}
+
+/// One-shot [`DesMac::verify`].
+pub fn mac_verify(
+    data: &[u8],
+    key: &[u8],
+    tag: &[u8],
+    algo: MacAlgorithm,
+    padding: MacPadding,
+) -> Result<bool> {
+    let mut mac = DesMac::new(key, algo, padding)?;
+    mac.update(data);
+    mac.verify(tag)
+}
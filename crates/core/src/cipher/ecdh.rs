@@ -0,0 +1,66 @@
+use rand_core::OsRng;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum EcdhCurve {
+    P256,
+    P384,
+}
+
+/// Returns `(private_key, uncompressed_public_key)`.
+pub fn generate_keypair(curve: EcdhCurve) -> (Vec<u8>, Vec<u8>) {
+    match curve {
+        EcdhCurve::P256 => {
+            use p256::elliptic_curve::sec1::ToEncodedPoint;
+            let sk = p256::SecretKey::random(&mut OsRng);
+            let pk = sk.public_key().to_encoded_point(false);
+            (sk.to_bytes().to_vec(), pk.as_bytes().to_vec())
+        }
+        EcdhCurve::P384 => {
+            use p384::elliptic_curve::sec1::ToEncodedPoint;
+            let sk = p384::SecretKey::random(&mut OsRng);
+            let pk = sk.public_key().to_encoded_point(false);
+            (sk.to_bytes().to_vec(), pk.as_bytes().to_vec())
+        }
+    }
+}
+
+/// Raw ECDH shared secret (the x-coordinate) between `private_key` and a
+/// SEC1-encoded `peer_public_key`.
+pub fn derive_shared_secret(
+    curve: EcdhCurve,
+    private_key: &[u8],
+    peer_public_key: &[u8],
+) -> Result<Vec<u8>> {
+    match curve {
+        EcdhCurve::P256 => {
+            if private_key.len() != 32 {
+                return Err(Error::InvalidLength(
+                    "P-256 private key must be 32 bytes".into(),
+                ));
+            }
+            let sk = p256::SecretKey::from_slice(private_key)
+                .map_err(|_| Error::InvalidKey("Invalid P-256 private key".into()))?;
+            let pk = p256::PublicKey::from_sec1_bytes(peer_public_key)
+                .map_err(|_| Error::InvalidKey("Invalid P-256 public key".into()))?;
+            let secret = p256::ecdh::diffie_hellman(sk.to_nonzero_scalar(), pk.as_affine());
+            Ok(secret.raw_secret_bytes().to_vec())
+        }
+        EcdhCurve::P384 => {
+            if private_key.len() != 48 {
+                return Err(Error::InvalidLength(
+                    "P-384 private key must be 48 bytes".into(),
+                ));
+            }
+            let sk = p384::SecretKey::from_slice(private_key)
+                .map_err(|_| Error::InvalidKey("Invalid P-384 private key".into()))?;
+            let pk = p384::PublicKey::from_sec1_bytes(peer_public_key)
+                .map_err(|_| Error::InvalidKey("Invalid P-384 public key".into()))?;
+            let secret = p384::ecdh::diffie_hellman(sk.to_nonzero_scalar(), pk.as_affine());
+            Ok(secret.raw_secret_bytes().to_vec())
+        }
+    }
+}
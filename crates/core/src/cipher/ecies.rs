@@ -0,0 +1,292 @@
+//! ECIES over secp256k1 and P-256, wire-compatible with eciesjs, eciespy
+//! and the `ecies` crate.
+//!
+//! A fresh ephemeral key pair is agreed with the recipient's public key; the
+//! AEAD key is HKDF-SHA256 (no salt, no info) of the ephemeral public key
+//! followed by the full shared point. The output is the ephemeral public key,
+//! the nonce, the 16-byte tag and the ciphertext, in that order.
+
+use aes_gcm::aead::AeadInPlace;
+use aes_gcm::aes::Aes256;
+use aes_gcm::{AesGcm, KeyInit, Nonce, Tag};
+use chacha20poly1305::XChaCha20Poly1305;
+use hkdf::Hkdf;
+use p256::elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint};
+use p256::elliptic_curve::{AffinePoint, CurveArithmetic, FieldBytesSize, PublicKey, SecretKey};
+use rand_core::{OsRng, RngCore};
+use sha2::Sha256;
+use typenum::{U12, U16};
+use zeroize::Zeroizing;
+
+use crate::{Error, Result};
+
+const TAG_LEN: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum EciesCurve {
+    Secp256k1,
+    P256,
+}
+
+impl EciesCurve {
+    pub fn name(self) -> &'static str {
+        match self {
+            EciesCurve::Secp256k1 => "secp256k1",
+            EciesCurve::P256 => "P-256",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum EciesAead {
+    /// AES-256-GCM with a 16-byte nonce, or 12 with
+    /// [`EciesOptions::aes_nonce_len`]
+    Aes256Gcm,
+    /// XChaCha20-Poly1305 with a 24-byte nonce
+    XChaCha20Poly1305,
+}
+
+/// Must match between sender and recipient, except
+/// `compressed_ephemeral_key`, which the recipient reads from the prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EciesOptions {
+    pub aead: EciesAead,
+    /// 16 (the eciesjs default) or 12; ignored for XChaCha20-Poly1305.
+    pub aes_nonce_len: usize,
+    /// Send the ephemeral key as a 33-byte compressed point.
+    pub compressed_ephemeral_key: bool,
+    /// Feed compressed points to HKDF (eciesjs `isHkdfKeyCompressed`).
+    pub compressed_hkdf_key: bool,
+}
+
+impl Default for EciesOptions {
+    fn default() -> Self {
+        EciesOptions {
+            aead: EciesAead::Aes256Gcm,
+            aes_nonce_len: 16,
+            compressed_ephemeral_key: false,
+            compressed_hkdf_key: false,
+        }
+    }
+}
+
+impl EciesOptions {
+    fn nonce_len(&self) -> Result<usize> {
+        match (self.aead, self.aes_nonce_len) {
+            (EciesAead::XChaCha20Poly1305, _) => Ok(24),
+            (EciesAead::Aes256Gcm, n @ (12 | 16)) => Ok(n),
+            (EciesAead::Aes256Gcm, _) => Err(Error::InvalidParameter(
+                "ECIES AES-GCM nonce must be 12 or 16 bytes".into(),
+            )),
+        }
+    }
+}
+
+/// Returns `(private_key, uncompressed_public_key)`.
+pub fn generate_keypair(curve: EciesCurve) -> (Vec<u8>, Vec<u8>) {
+    match curve {
+        EciesCurve::Secp256k1 => keypair::<k256::Secp256k1>(),
+        EciesCurve::P256 => keypair::<p256::NistP256>(),
+    }
+}
+
+/// Encrypts `plaintext` to `receiver_public_key`, a SEC1 point or, as
+/// Ethereum tooling passes them, 64 raw coordinate bytes.
+pub fn encrypt(
+    curve: EciesCurve,
+    receiver_public_key: &[u8],
+    plaintext: &[u8],
+    opts: &EciesOptions,
+) -> Result<Vec<u8>> {
+    let nonce_len = opts.nonce_len()?;
+    let (mut out, key) = match curve {
+        EciesCurve::Secp256k1 => encapsulate::<k256::Secp256k1>(curve, receiver_public_key, opts)?,
+        EciesCurve::P256 => encapsulate::<p256::NistP256>(curve, receiver_public_key, opts)?,
+    };
+
+    let header = out.len();
+    out.resize(header + nonce_len + TAG_LEN, 0);
+    OsRng.fill_bytes(&mut out[header..header + nonce_len]);
+    out.extend_from_slice(plaintext);
+
+    let (nonce, rest) = out[header..].split_at_mut(nonce_len);
+    let (tag, body) = rest.split_at_mut(TAG_LEN);
+    tag.copy_from_slice(&seal(opts.aead, &key, nonce, body)?);
+    Ok(out)
+}
+
+/// Decrypts an [`encrypt`] ciphertext with the recipient's private key.
+pub fn decrypt(
+    curve: EciesCurve,
+    receiver_private_key: &[u8],
+    ciphertext: &[u8],
+    opts: &EciesOptions,
+) -> Result<Vec<u8>> {
+    let nonce_len = opts.nonce_len()?;
+    let key_len = match ciphertext.first() {
+        Some(0x02 | 0x03) => 33,
+        Some(0x04) => 65,
+        _ => {
+            return Err(Error::Decryption(
+                "ECIES ciphertext does not start with an ephemeral key".into(),
+            ))
+        }
+    };
+    if ciphertext.len() < key_len + nonce_len + TAG_LEN {
+        return Err(Error::Decryption("ECIES ciphertext is too short".into()));
+    }
+
+    let (ephemeral, rest) = ciphertext.split_at(key_len);
+    let key = match curve {
+        EciesCurve::Secp256k1 => {
+            decapsulate::<k256::Secp256k1>(curve, ephemeral, receiver_private_key, opts)?
+        }
+        EciesCurve::P256 => {
+            decapsulate::<p256::NistP256>(curve, ephemeral, receiver_private_key, opts)?
+        }
+    };
+
+    let (nonce, rest) = rest.split_at(nonce_len);
+    let (tag, body) = rest.split_at(TAG_LEN);
+    let mut buffer = body.to_vec();
+    open(opts.aead, &key, nonce, tag, &mut buffer)?;
+    Ok(buffer)
+}
+
+fn keypair<C>() -> (Vec<u8>, Vec<u8>)
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let sk = SecretKey::<C>::random(&mut OsRng);
+    let pk = sk.public_key().to_encoded_point(false);
+    (sk.to_bytes().to_vec(), pk.as_bytes().to_vec())
+}
+
+fn parse_public_key<C>(curve: EciesCurve, bytes: &[u8]) -> Result<PublicKey<C>>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let parsed = if bytes.len() == 64 {
+        PublicKey::<C>::from_sec1_bytes(&[&[0x04], bytes].concat())
+    } else {
+        PublicKey::<C>::from_sec1_bytes(bytes)
+    };
+    parsed.map_err(|_| Error::InvalidKey(format!("Invalid {} public key", curve.name())))
+}
+
+/// HKDF-SHA256 of the sender's point and the shared point.
+fn shared_key<C>(
+    sender: &PublicKey<C>,
+    shared: &PublicKey<C>,
+    compressed: bool,
+) -> Zeroizing<[u8; 32]>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let mut ikm = Zeroizing::new(sender.to_encoded_point(compressed).as_bytes().to_vec());
+    ikm.extend_from_slice(shared.to_encoded_point(compressed).as_bytes());
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    Hkdf::<Sha256>::new(None, &ikm)
+        .expand(&[], key.as_mut())
+        .expect("32 bytes is a valid HKDF-SHA256 length");
+    key
+}
+
+fn multiply<C>(curve: EciesCurve, point: &PublicKey<C>, sk: &SecretKey<C>) -> Result<PublicKey<C>>
+where
+    C: CurveArithmetic,
+{
+    let product = point.to_projective() * *sk.to_nonzero_scalar();
+    PublicKey::<C>::from_affine(product.into())
+        .map_err(|_| Error::InvalidKey(format!("Invalid {} public key", curve.name())))
+}
+
+/// Returns the encoded ephemeral public key and the AEAD key.
+fn encapsulate<C>(
+    curve: EciesCurve,
+    receiver_public_key: &[u8],
+    opts: &EciesOptions,
+) -> Result<(Vec<u8>, Zeroizing<[u8; 32]>)>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let receiver = parse_public_key::<C>(curve, receiver_public_key)?;
+    let esk = SecretKey::<C>::random(&mut OsRng);
+    let epk = esk.public_key();
+
+    let shared = multiply(curve, &receiver, &esk)?;
+    let key = shared_key(&epk, &shared, opts.compressed_hkdf_key);
+    let encoded = epk.to_encoded_point(opts.compressed_ephemeral_key);
+    Ok((encoded.as_bytes().to_vec(), key))
+}
+
+fn decapsulate<C>(
+    curve: EciesCurve,
+    ephemeral_public_key: &[u8],
+    receiver_private_key: &[u8],
+    opts: &EciesOptions,
+) -> Result<Zeroizing<[u8; 32]>>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    if receiver_private_key.len() != 32 {
+        return Err(Error::InvalidLength(format!(
+            "{} private key must be 32 bytes",
+            curve.name()
+        )));
+    }
+    let sk = SecretKey::<C>::from_slice(receiver_private_key)
+        .map_err(|_| Error::InvalidKey(format!("Invalid {} private key", curve.name())))?;
+    let epk = parse_public_key::<C>(curve, ephemeral_public_key)?;
+
+    let shared = multiply(curve, &epk, &sk)?;
+    Ok(shared_key(&epk, &shared, opts.compressed_hkdf_key))
+}
+
+fn seal(aead: EciesAead, key: &[u8; 32], nonce: &[u8], buffer: &mut [u8]) -> Result<[u8; TAG_LEN]> {
+    let tag = match (aead, nonce.len()) {
+        (EciesAead::XChaCha20Poly1305, _) => {
+            XChaCha20Poly1305::new(key.into()).encrypt_in_place_detached(nonce.into(), &[], buffer)
+        }
+        (EciesAead::Aes256Gcm, 12) => AesGcm::<Aes256, U12>::new(key.into())
+            .encrypt_in_place_detached(Nonce::from_slice(nonce), &[], buffer),
+        (EciesAead::Aes256Gcm, _) => AesGcm::<Aes256, U16>::new(key.into())
+            .encrypt_in_place_detached(Nonce::from_slice(nonce), &[], buffer),
+    };
+    tag.map(Into::into)
+        .map_err(|_| Error::Operation("ECIES encryption failed".into()))
+}
+
+fn open(
+    aead: EciesAead,
+    key: &[u8; 32],
+    nonce: &[u8],
+    tag: &[u8],
+    buffer: &mut [u8],
+) -> Result<()> {
+    let tag = Tag::<U16>::from_slice(tag);
+    match (aead, nonce.len()) {
+        (EciesAead::XChaCha20Poly1305, _) => XChaCha20Poly1305::new(key.into())
+            .decrypt_in_place_detached(nonce.into(), &[], buffer, tag),
+        (EciesAead::Aes256Gcm, 12) => AesGcm::<Aes256, U12>::new(key.into())
+            .decrypt_in_place_detached(Nonce::from_slice(nonce), &[], buffer, tag),
+        (EciesAead::Aes256Gcm, _) => AesGcm::<Aes256, U16>::new(key.into())
+            .decrypt_in_place_detached(Nonce::from_slice(nonce), &[], buffer, tag),
+    }
+    .map_err(|_| Error::Decryption("ECIES decryption failed".into()))
+}
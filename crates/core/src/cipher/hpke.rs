@@ -0,0 +1,696 @@
+//! Hybrid Public Key Encryption (RFC 9180).
+//!
+//! DHKEM over X25519, P-256 or P-384, HKDF-SHA256/384/512 and AES-GCM or
+//! ChaCha20-Poly1305, in all four modes. The mode follows from
+//! [`HpkeOptions`]: a PSK selects the PSK modes and a sender key the auth
+//! modes. Public keys are raw X25519 keys or uncompressed SEC1 points and
+//! private keys are raw scalars, as produced by the RFC's
+//! `SerializePublicKey` and `SerializePrivateKey`.
+
+use digest::core_api::BlockSizeUser;
+use digest::Digest;
+use hkdf::{SimpleHkdf, SimpleHkdfExtract};
+use sha2::{Sha256, Sha384, Sha512};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use super::aes::{AesAlgorithm, AesGcmCipher};
+use super::chacha20::{ChaCha20Algorithm, ChaCha20Poly1305Cipher};
+use super::ecdh::{self, EcdhCurve};
+use super::x25519;
+use crate::{Error, Result};
+
+const HPKE_V1: &[u8] = b"HPKE-v1";
+
+const MODE_BASE: u8 = 0x00;
+const MODE_PSK: u8 = 0x01;
+const MODE_AUTH: u8 = 0x02;
+const MODE_AUTH_PSK: u8 = 0x03;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HpkeKem {
+    /// DHKEM(X25519, HKDF-SHA256)
+    X25519HkdfSha256,
+    /// DHKEM(P-256, HKDF-SHA256)
+    P256HkdfSha256,
+    /// DHKEM(P-384, HKDF-SHA384)
+    P384HkdfSha384,
+}
+
+impl HpkeKem {
+    pub fn name(self) -> &'static str {
+        match self {
+            HpkeKem::X25519HkdfSha256 => "DHKEM(X25519, HKDF-SHA256)",
+            HpkeKem::P256HkdfSha256 => "DHKEM(P-256, HKDF-SHA256)",
+            HpkeKem::P384HkdfSha384 => "DHKEM(P-384, HKDF-SHA384)",
+        }
+    }
+
+    /// IANA `kem_id`.
+    pub fn id(self) -> u16 {
+        match self {
+            HpkeKem::X25519HkdfSha256 => 0x0020,
+            HpkeKem::P256HkdfSha256 => 0x0010,
+            HpkeKem::P384HkdfSha384 => 0x0011,
+        }
+    }
+
+    /// `Npk`, which is also the length of `enc`.
+    pub fn public_key_len(self) -> usize {
+        match self {
+            HpkeKem::X25519HkdfSha256 => 32,
+            HpkeKem::P256HkdfSha256 => 65,
+            HpkeKem::P384HkdfSha384 => 97,
+        }
+    }
+
+    /// `Nsk`.
+    pub fn private_key_len(self) -> usize {
+        match self {
+            HpkeKem::X25519HkdfSha256 | HpkeKem::P256HkdfSha256 => 32,
+            HpkeKem::P384HkdfSha384 => 48,
+        }
+    }
+
+    fn kdf(self) -> HpkeKdf {
+        match self {
+            HpkeKem::X25519HkdfSha256 | HpkeKem::P256HkdfSha256 => HpkeKdf::HkdfSha256,
+            HpkeKem::P384HkdfSha384 => HpkeKdf::HkdfSha384,
+        }
+    }
+
+    fn suite_id(self) -> [u8; 5] {
+        let id = self.id().to_be_bytes();
+        [b'K', b'E', b'M', id[0], id[1]]
+    }
+
+    fn check_key(self, key: &[u8], expected: usize, kind: &str) -> Result<()> {
+        if key.len() != expected {
+            return Err(Error::InvalidLength(format!(
+                "{}: {} key must be {} bytes",
+                self.name(),
+                kind,
+                expected
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Public key of `private_key`; fails if it is not a valid scalar.
+    fn public_key(self, private_key: &[u8]) -> Result<Vec<u8>> {
+        self.check_key(private_key, self.private_key_len(), "private")?;
+
+        match self {
+            HpkeKem::X25519HkdfSha256 => {
+                let mut sk = Zeroizing::new([0u8; 32]);
+                sk.copy_from_slice(private_key);
+                let sk = x25519_dalek::StaticSecret::from(*sk);
+                Ok(x25519_dalek::PublicKey::from(&sk).as_bytes().to_vec())
+            }
+            HpkeKem::P256HkdfSha256 => {
+                use p256::elliptic_curve::sec1::ToEncodedPoint;
+                let sk = p256::SecretKey::from_slice(private_key)
+                    .map_err(|_| Error::InvalidKey("Invalid P-256 private key".into()))?;
+                Ok(sk.public_key().to_encoded_point(false).as_bytes().to_vec())
+            }
+            HpkeKem::P384HkdfSha384 => {
+                use p384::elliptic_curve::sec1::ToEncodedPoint;
+                let sk = p384::SecretKey::from_slice(private_key)
+                    .map_err(|_| Error::InvalidKey("Invalid P-384 private key".into()))?;
+                Ok(sk.public_key().to_encoded_point(false).as_bytes().to_vec())
+            }
+        }
+    }
+
+    fn dh(self, private_key: &[u8], public_key: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        self.check_key(private_key, self.private_key_len(), "private")?;
+        self.check_key(public_key, self.public_key_len(), "public")?;
+
+        match self {
+            HpkeKem::X25519HkdfSha256 => {
+                let dh = Zeroizing::new(x25519::derive_shared_secret(private_key, public_key)?);
+                if bool::from(dh.ct_eq(&[0u8; 32])) {
+                    return Err(Error::InvalidKey(
+                        "X25519 public key has small order".into(),
+                    ));
+                }
+                Ok(Zeroizing::new(dh.to_vec()))
+            }
+            HpkeKem::P256HkdfSha256 => Ok(Zeroizing::new(ecdh::derive_shared_secret(
+                EcdhCurve::P256,
+                private_key,
+                public_key,
+            )?)),
+            HpkeKem::P384HkdfSha384 => Ok(Zeroizing::new(ecdh::derive_shared_secret(
+                EcdhCurve::P384,
+                private_key,
+                public_key,
+            )?)),
+        }
+    }
+
+    fn extract_and_expand(self, dh: &[u8], kem_context: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        let suite_id = self.suite_id();
+        let kdf = self.kdf();
+        let eae_prk = kdf.labeled_extract(&suite_id, b"", b"eae_prk", dh);
+
+        kdf.labeled_expand(
+            &suite_id,
+            &eae_prk,
+            b"shared_secret",
+            kem_context,
+            kdf.hash_len(),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HpkeKdf {
+    HkdfSha256,
+    HkdfSha384,
+    HkdfSha512,
+}
+
+impl HpkeKdf {
+    /// IANA `kdf_id`.
+    pub fn id(self) -> u16 {
+        match self {
+            HpkeKdf::HkdfSha256 => 0x0001,
+            HpkeKdf::HkdfSha384 => 0x0002,
+            HpkeKdf::HkdfSha512 => 0x0003,
+        }
+    }
+
+    /// `Nh`, the length of the exporter secret.
+    pub fn hash_len(self) -> usize {
+        match self {
+            HpkeKdf::HkdfSha256 => 32,
+            HpkeKdf::HkdfSha384 => 48,
+            HpkeKdf::HkdfSha512 => 64,
+        }
+    }
+
+    fn labeled_extract(
+        self,
+        suite_id: &[u8],
+        salt: &[u8],
+        label: &[u8],
+        ikm: &[u8],
+    ) -> Zeroizing<Vec<u8>> {
+        match self {
+            HpkeKdf::HkdfSha256 => labeled_extract::<Sha256>(suite_id, salt, label, ikm),
+            HpkeKdf::HkdfSha384 => labeled_extract::<Sha384>(suite_id, salt, label, ikm),
+            HpkeKdf::HkdfSha512 => labeled_extract::<Sha512>(suite_id, salt, label, ikm),
+        }
+    }
+
+    fn labeled_expand(
+        self,
+        suite_id: &[u8],
+        prk: &[u8],
+        label: &[u8],
+        info: &[u8],
+        len: usize,
+    ) -> Result<Zeroizing<Vec<u8>>> {
+        match self {
+            HpkeKdf::HkdfSha256 => labeled_expand::<Sha256>(suite_id, prk, label, info, len),
+            HpkeKdf::HkdfSha384 => labeled_expand::<Sha384>(suite_id, prk, label, info, len),
+            HpkeKdf::HkdfSha512 => labeled_expand::<Sha512>(suite_id, prk, label, info, len),
+        }
+    }
+}
+
+fn labeled_extract<D>(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Zeroizing<Vec<u8>>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    let mut extract = SimpleHkdfExtract::<D>::new(Some(salt));
+    for part in [HPKE_V1, suite_id, label, ikm] {
+        extract.input_ikm(part);
+    }
+
+    Zeroizing::new(extract.finalize().0.to_vec())
+}
+
+fn labeled_expand<D>(
+    suite_id: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Zeroizing<Vec<u8>>>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    let too_long = || {
+        Error::InvalidLength(format!(
+            "HPKE: at most {} bytes can be derived",
+            255 * <D as Digest>::output_size()
+        ))
+    };
+
+    let length = u16::try_from(len).map_err(|_| too_long())?.to_be_bytes();
+    let hkdf =
+        SimpleHkdf::<D>::from_prk(prk).map_err(|_| Error::Operation("HPKE: invalid PRK".into()))?;
+    let mut okm = Zeroizing::new(vec![0u8; len]);
+
+    hkdf.expand_multi_info(&[&length, HPKE_V1, suite_id, label, info], &mut okm)
+        .map_err(|_| too_long())?;
+
+    Ok(okm)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HpkeAead {
+    Aes128Gcm,
+    Aes256Gcm,
+    ChaCha20Poly1305,
+    /// No AEAD: the context can only [`export`](SenderContext::export).
+    ExportOnly,
+}
+
+impl HpkeAead {
+    /// IANA `aead_id`.
+    pub fn id(self) -> u16 {
+        match self {
+            HpkeAead::Aes128Gcm => 0x0001,
+            HpkeAead::Aes256Gcm => 0x0002,
+            HpkeAead::ChaCha20Poly1305 => 0x0003,
+            HpkeAead::ExportOnly => 0xffff,
+        }
+    }
+
+    /// `Nk`.
+    pub fn key_len(self) -> usize {
+        match self {
+            HpkeAead::Aes128Gcm => 16,
+            HpkeAead::Aes256Gcm | HpkeAead::ChaCha20Poly1305 => 32,
+            HpkeAead::ExportOnly => 0,
+        }
+    }
+
+    /// `Nn`.
+    pub fn nonce_len(self) -> usize {
+        match self {
+            HpkeAead::ExportOnly => 0,
+            _ => 12,
+        }
+    }
+}
+
+/// A KEM, KDF and AEAD combination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HpkeSuite {
+    pub kem: HpkeKem,
+    pub kdf: HpkeKdf,
+    pub aead: HpkeAead,
+}
+
+impl HpkeSuite {
+    pub const fn new(kem: HpkeKem, kdf: HpkeKdf, aead: HpkeAead) -> Self {
+        Self { kem, kdf, aead }
+    }
+
+    fn id(self) -> [u8; 10] {
+        let mut id = *b"HPKE\0\0\0\0\0\0";
+        id[4..6].copy_from_slice(&self.kem.id().to_be_bytes());
+        id[6..8].copy_from_slice(&self.kdf.id().to_be_bytes());
+        id[8..10].copy_from_slice(&self.aead.id().to_be_bytes());
+        id
+    }
+}
+
+/// Inputs to the key schedule besides the KEM shared secret. Both sides
+/// must pass the same `info`, `psk` and `psk_id`.
+#[derive(Clone, Copy, Default)]
+pub struct HpkeOptions<'a> {
+    /// Application-supplied context bound into every key.
+    pub info: &'a [u8],
+    /// Pre-shared key, given together with `psk_id`; selects the PSK or
+    /// auth-PSK mode.
+    pub psk: Option<&'a [u8]>,
+    pub psk_id: Option<&'a [u8]>,
+    /// The sender's static key for the auth modes: its private key when
+    /// setting up a sender, its public key when setting up a recipient.
+    pub auth_key: Option<&'a [u8]>,
+}
+
+impl HpkeOptions<'_> {
+    fn mode(&self) -> Result<u8> {
+        let psk = self.psk.is_some_and(|p| !p.is_empty());
+        let psk_id = self.psk_id.is_some_and(|p| !p.is_empty());
+
+        if psk != psk_id {
+            return Err(Error::InvalidParameter(
+                "HPKE: psk and psk_id must be given together".into(),
+            ));
+        }
+
+        Ok(match (psk, self.auth_key.is_some()) {
+            (false, false) => MODE_BASE,
+            (true, false) => MODE_PSK,
+            (false, true) => MODE_AUTH,
+            (true, true) => MODE_AUTH_PSK,
+        })
+    }
+}
+
+/// Returns `(private_key, public_key)`.
+pub fn generate_keypair(kem: HpkeKem) -> (Vec<u8>, Vec<u8>) {
+    match kem {
+        HpkeKem::X25519HkdfSha256 => {
+            let (sk, pk) = x25519::generate_keypair();
+            (sk.to_vec(), pk.to_vec())
+        }
+        HpkeKem::P256HkdfSha256 => ecdh::generate_keypair(EcdhCurve::P256),
+        HpkeKem::P384HkdfSha384 => ecdh::generate_keypair(EcdhCurve::P384),
+    }
+}
+
+/// `DeriveKeyPair`: the key pair determined by `ikm`, which must be at
+/// least [`HpkeKem::private_key_len`] bytes of uniformly random input.
+pub fn derive_keypair(kem: HpkeKem, ikm: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    if ikm.len() < kem.private_key_len() {
+        return Err(Error::InvalidLength(format!(
+            "{}: ikm must be at least {} bytes",
+            kem.name(),
+            kem.private_key_len()
+        )));
+    }
+
+    let suite_id = kem.suite_id();
+    let kdf = kem.kdf();
+    let dkp_prk = kdf.labeled_extract(&suite_id, b"", b"dkp_prk", ikm);
+
+    if kem == HpkeKem::X25519HkdfSha256 {
+        let sk = kdf.labeled_expand(&suite_id, &dkp_prk, b"sk", b"", 32)?;
+        let pk = kem.public_key(&sk)?;
+        return Ok((sk.to_vec(), pk));
+    }
+
+    // Rejection sampling; the bitmask is 0xff for both NIST curves.
+    for counter in 0..=255u8 {
+        let sk = kdf.labeled_expand(
+            &suite_id,
+            &dkp_prk,
+            b"candidate",
+            &[counter],
+            kem.private_key_len(),
+        )?;
+        if let Ok(pk) = kem.public_key(&sk) {
+            return Ok((sk.to_vec(), pk));
+        }
+    }
+
+    Err(Error::Operation(format!(
+        "{}: DeriveKeyPair found no valid key",
+        kem.name()
+    )))
+}
+
+enum ContextAead {
+    Gcm(Box<AesGcmCipher>),
+    ChaCha(ChaCha20Poly1305Cipher),
+    ExportOnly,
+}
+
+/// State shared by both ends of a context.
+struct Context {
+    aead: ContextAead,
+    base_nonce: Vec<u8>,
+    seq: u64,
+    kdf: HpkeKdf,
+    suite_id: [u8; 10],
+    exporter_secret: Zeroizing<Vec<u8>>,
+}
+
+impl Context {
+    fn nonce(&self) -> Result<Vec<u8>> {
+        if matches!(self.aead, ContextAead::ExportOnly) {
+            return Err(Error::InvalidParameter(
+                "HPKE: an export-only context cannot seal or open".into(),
+            ));
+        }
+        if self.seq == u64::MAX {
+            return Err(Error::Operation("HPKE: message limit reached".into()));
+        }
+
+        let mut nonce = self.base_nonce.clone();
+        for (n, s) in nonce
+            .iter_mut()
+            .rev()
+            .zip(self.seq.to_be_bytes().iter().rev())
+        {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    fn seal(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let nonce = self.nonce()?;
+        let ciphertext = match &self.aead {
+            ContextAead::Gcm(c) => c.encrypt(plaintext, &nonce, aad)?,
+            ContextAead::ChaCha(c) => c.encrypt(plaintext, &nonce, aad)?,
+            ContextAead::ExportOnly => unreachable!("rejected by nonce()"),
+        };
+
+        self.seq += 1;
+        Ok(ciphertext)
+    }
+
+    fn open(&mut self, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let nonce = self.nonce()?;
+        let plaintext = match &self.aead {
+            ContextAead::Gcm(c) => c.decrypt(ciphertext, &nonce, aad)?,
+            ContextAead::ChaCha(c) => c.decrypt(ciphertext, &nonce, aad)?,
+            ContextAead::ExportOnly => unreachable!("rejected by nonce()"),
+        };
+
+        self.seq += 1;
+        Ok(plaintext)
+    }
+
+    fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>> {
+        let secret = self.kdf.labeled_expand(
+            &self.suite_id,
+            &self.exporter_secret,
+            b"sec",
+            exporter_context,
+            len,
+        )?;
+
+        Ok(secret.to_vec())
+    }
+}
+
+/// The sender's end of an HPKE context, from [`setup_sender`].
+pub struct SenderContext {
+    inner: Context,
+}
+
+impl SenderContext {
+    /// Encrypts the next message; each call uses the next nonce, so the
+    /// recipient must open messages in the same order.
+    pub fn seal(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        self.inner.seal(aad, plaintext)
+    }
+
+    /// `Export`: `len` bytes of secret bound to `exporter_context`.
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>> {
+        self.inner.export(exporter_context, len)
+    }
+
+    /// Number of messages sealed so far.
+    pub fn sequence_number(&self) -> u64 {
+        self.inner.seq
+    }
+}
+
+/// The recipient's end of an HPKE context, from [`setup_recipient`].
+pub struct RecipientContext {
+    inner: Context,
+}
+
+impl RecipientContext {
+    /// Decrypts the next message. A failed open does not advance the
+    /// sequence number.
+    pub fn open(&mut self, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        self.inner.open(aad, ciphertext)
+    }
+
+    /// `Export`: `len` bytes of secret bound to `exporter_context`.
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>> {
+        self.inner.export(exporter_context, len)
+    }
+
+    /// Number of messages opened so far.
+    pub fn sequence_number(&self) -> u64 {
+        self.inner.seq
+    }
+}
+
+fn key_schedule(
+    suite: HpkeSuite,
+    mode: u8,
+    shared_secret: &[u8],
+    opts: &HpkeOptions,
+) -> Result<Context> {
+    let suite_id = suite.id();
+    let kdf = suite.kdf;
+
+    let psk_id_hash = kdf.labeled_extract(
+        &suite_id,
+        b"",
+        b"psk_id_hash",
+        opts.psk_id.unwrap_or_default(),
+    );
+    let info_hash = kdf.labeled_extract(&suite_id, b"", b"info_hash", opts.info);
+    let context = [&[mode][..], &psk_id_hash, &info_hash].concat();
+
+    let secret = kdf.labeled_extract(
+        &suite_id,
+        shared_secret,
+        b"secret",
+        opts.psk.unwrap_or_default(),
+    );
+    let expand = |label: &[u8], len| kdf.labeled_expand(&suite_id, &secret, label, &context, len);
+
+    let key = expand(b"key", suite.aead.key_len())?;
+    let aead = match suite.aead {
+        HpkeAead::Aes128Gcm => {
+            ContextAead::Gcm(Box::new(AesGcmCipher::new(&key, AesAlgorithm::Aes128Gcm)?))
+        }
+        HpkeAead::Aes256Gcm => {
+            ContextAead::Gcm(Box::new(AesGcmCipher::new(&key, AesAlgorithm::Aes256Gcm)?))
+        }
+        HpkeAead::ChaCha20Poly1305 => ContextAead::ChaCha(ChaCha20Poly1305Cipher::new(
+            &key,
+            ChaCha20Algorithm::Chacha20Poly1305,
+        )?),
+        HpkeAead::ExportOnly => ContextAead::ExportOnly,
+    };
+
+    Ok(Context {
+        aead,
+        base_nonce: expand(b"base_nonce", suite.aead.nonce_len())?.to_vec(),
+        seq: 0,
+        kdf,
+        suite_id,
+        exporter_secret: expand(b"exp", kdf.hash_len())?,
+    })
+}
+
+fn setup_sender_with_ephemeral(
+    suite: HpkeSuite,
+    recipient_public_key: &[u8],
+    opts: &HpkeOptions,
+    ephemeral_private_key: &[u8],
+) -> Result<(Vec<u8>, SenderContext)> {
+    let mode = opts.mode()?;
+    let kem = suite.kem;
+
+    let enc = kem.public_key(ephemeral_private_key)?;
+    let mut dh = Zeroizing::new(Vec::with_capacity(2 * kem.private_key_len()));
+    dh.extend_from_slice(&kem.dh(ephemeral_private_key, recipient_public_key)?);
+    let mut kem_context = [&enc[..], recipient_public_key].concat();
+
+    if let Some(sender_private_key) = opts.auth_key {
+        dh.extend_from_slice(&kem.dh(sender_private_key, recipient_public_key)?);
+        kem_context.extend_from_slice(&kem.public_key(sender_private_key)?);
+    }
+
+    let shared_secret = kem.extract_and_expand(&dh, &kem_context)?;
+    let inner = key_schedule(suite, mode, &shared_secret, opts)?;
+
+    Ok((enc, SenderContext { inner }))
+}
+
+/// `SetupBaseS`, `SetupPSKS`, `SetupAuthS` or `SetupAuthPSKS`, depending on
+/// `opts`. Returns the encapsulated key `enc`, which the recipient needs,
+/// and the sender's context.
+pub fn setup_sender(
+    suite: HpkeSuite,
+    recipient_public_key: &[u8],
+    opts: &HpkeOptions,
+) -> Result<(Vec<u8>, SenderContext)> {
+    let (ephemeral_private_key, _) = generate_keypair(suite.kem);
+    let ephemeral_private_key = Zeroizing::new(ephemeral_private_key);
+
+    setup_sender_with_ephemeral(suite, recipient_public_key, opts, &ephemeral_private_key)
+}
+
+/// [`setup_sender`] with the ephemeral key pair derived from `ikm_e`
+/// through [`derive_keypair`], as in the RFC's test vectors. Reusing
+/// `ikm_e` reuses the ephemeral key, so this is only for known-answer
+/// tests.
+pub fn setup_sender_derand(
+    suite: HpkeSuite,
+    recipient_public_key: &[u8],
+    opts: &HpkeOptions,
+    ikm_e: &[u8],
+) -> Result<(Vec<u8>, SenderContext)> {
+    let (ephemeral_private_key, _) = derive_keypair(suite.kem, ikm_e)?;
+    let ephemeral_private_key = Zeroizing::new(ephemeral_private_key);
+
+    setup_sender_with_ephemeral(suite, recipient_public_key, opts, &ephemeral_private_key)
+}
+
+/// `SetupBaseR`, `SetupPSKR`, `SetupAuthR` or `SetupAuthPSKR`, depending on
+/// `opts`.
+pub fn setup_recipient(
+    suite: HpkeSuite,
+    enc: &[u8],
+    recipient_private_key: &[u8],
+    opts: &HpkeOptions,
+) -> Result<RecipientContext> {
+    let mode = opts.mode()?;
+    let kem = suite.kem;
+
+    let recipient_public_key = kem.public_key(recipient_private_key)?;
+    let mut dh = Zeroizing::new(Vec::with_capacity(2 * kem.private_key_len()));
+    dh.extend_from_slice(&kem.dh(recipient_private_key, enc)?);
+    let mut kem_context = [enc, &recipient_public_key[..]].concat();
+
+    if let Some(sender_public_key) = opts.auth_key {
+        dh.extend_from_slice(&kem.dh(recipient_private_key, sender_public_key)?);
+        kem_context.extend_from_slice(sender_public_key);
+    }
+
+    let shared_secret = kem.extract_and_expand(&dh, &kem_context)?;
+    let inner = key_schedule(suite, mode, &shared_secret, opts)?;
+
+    Ok(RecipientContext { inner })
+}
+
+/// Single-shot encryption of one message; returns `(enc, ciphertext)`.
+pub fn seal(
+    suite: HpkeSuite,
+    recipient_public_key: &[u8],
+    opts: &HpkeOptions,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    let (enc, mut ctx) = setup_sender(suite, recipient_public_key, opts)?;
+    let ciphertext = ctx.seal(aad, plaintext)?;
+
+    Ok((enc, ciphertext))
+}
+
+/// Single-shot decryption of a message from [`seal`].
+pub fn open(
+    suite: HpkeSuite,
+    enc: &[u8],
+    recipient_private_key: &[u8],
+    opts: &HpkeOptions,
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    setup_recipient(suite, enc, recipient_private_key, opts)?.open(aad, ciphertext)
+}
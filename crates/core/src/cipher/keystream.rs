@@ -0,0 +1,318 @@
+//! Seekable keystreams for AES-CTR, DES/3DES-CTR and ChaCha20.
+//!
+//! The one-shot `encrypt`/`decrypt` functions always start at block 0. A
+//! [`Keystream`] keeps its position between calls and can jump to any byte
+//! offset, so a range of a large ciphertext (an HTTP range request, a chunk
+//! of a media file) can be decrypted without touching what precedes it.
+//! Encryption and decryption are the same XOR.
+//!
+//! The counter occupies part of the IV block, chosen by [`CounterLayout`].
+//! It starts from the value in the IV and wraps modulo its width; the
+//! keystream ends before the counter would return to its starting value.
+
+use aes::{Aes128, Aes192, Aes256};
+use chacha20::{ChaCha20, XChaCha20};
+use ctr::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use des::{Des, TdesEde2, TdesEde3};
+
+use super::aes::AesAlgorithm;
+use super::chacha20::ChaCha20Algorithm;
+use super::des::DesAlgorithm;
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum CounterLayout {
+    /// Big-endian counter in the last 4 bytes of the block (SP 800-38A with
+    /// a 96-bit nonce, as inside GCM).
+    Ctr32BE,
+    /// Big-endian counter in the last 8 bytes; the DES-CTR default.
+    Ctr64BE,
+    /// The whole 16-byte block is a big-endian counter; the AES-CTR default,
+    /// as in OpenSSL and WebCrypto. AES only.
+    Ctr128BE,
+    /// Little-endian counter in the first 4 bytes. ChaCha20 (RFC 8439)
+    /// supports only this layout.
+    Ctr32LE,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KeystreamAlgorithm {
+    Aes128Ctr,
+    Aes192Ctr,
+    Aes256Ctr,
+    DesCtr,
+    TdesCtr,
+    TdesEde2Ctr,
+    Chacha20,
+    XChacha20,
+}
+
+impl KeystreamAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            KeystreamAlgorithm::Aes128Ctr => "AES-128-CTR",
+            KeystreamAlgorithm::Aes192Ctr => "AES-192-CTR",
+            KeystreamAlgorithm::Aes256Ctr => "AES-256-CTR",
+            KeystreamAlgorithm::DesCtr => "DES-CTR",
+            KeystreamAlgorithm::TdesCtr => "3DES-CTR",
+            KeystreamAlgorithm::TdesEde2Ctr => "3DES-EDE2-CTR",
+            KeystreamAlgorithm::Chacha20 => "ChaCha20",
+            KeystreamAlgorithm::XChacha20 => "XChaCha20",
+        }
+    }
+
+    pub fn key_len(self) -> usize {
+        match self {
+            KeystreamAlgorithm::Aes128Ctr | KeystreamAlgorithm::TdesEde2Ctr => 16,
+            KeystreamAlgorithm::Aes192Ctr | KeystreamAlgorithm::TdesCtr => 24,
+            KeystreamAlgorithm::DesCtr => 8,
+            _ => 32,
+        }
+    }
+
+    /// IV (initial counter block) or nonce length in bytes.
+    pub fn iv_len(self) -> usize {
+        match self {
+            KeystreamAlgorithm::DesCtr
+            | KeystreamAlgorithm::TdesCtr
+            | KeystreamAlgorithm::TdesEde2Ctr => 8,
+            KeystreamAlgorithm::Chacha20 => 12,
+            KeystreamAlgorithm::XChacha20 => 24,
+            _ => 16,
+        }
+    }
+
+    /// The layout used by the one-shot CTR and ChaCha20 functions.
+    pub fn default_layout(self) -> CounterLayout {
+        match self {
+            KeystreamAlgorithm::DesCtr
+            | KeystreamAlgorithm::TdesCtr
+            | KeystreamAlgorithm::TdesEde2Ctr => CounterLayout::Ctr64BE,
+            KeystreamAlgorithm::Chacha20 | KeystreamAlgorithm::XChacha20 => CounterLayout::Ctr32LE,
+            _ => CounterLayout::Ctr128BE,
+        }
+    }
+}
+
+impl TryFrom<AesAlgorithm> for KeystreamAlgorithm {
+    type Error = Error;
+
+    fn try_from(algo: AesAlgorithm) -> Result<Self> {
+        match algo {
+            AesAlgorithm::Aes128Ctr => Ok(KeystreamAlgorithm::Aes128Ctr),
+            AesAlgorithm::Aes192Ctr => Ok(KeystreamAlgorithm::Aes192Ctr),
+            AesAlgorithm::Aes256Ctr => Ok(KeystreamAlgorithm::Aes256Ctr),
+            _ => Err(not_seekable(algo.name())),
+        }
+    }
+}
+
+impl TryFrom<DesAlgorithm> for KeystreamAlgorithm {
+    type Error = Error;
+
+    fn try_from(algo: DesAlgorithm) -> Result<Self> {
+        match algo {
+            DesAlgorithm::DesCtr => Ok(KeystreamAlgorithm::DesCtr),
+            DesAlgorithm::TdesCtr => Ok(KeystreamAlgorithm::TdesCtr),
+            DesAlgorithm::TdesEde2Ctr => Ok(KeystreamAlgorithm::TdesEde2Ctr),
+            DesAlgorithm::DesCbc
+            | DesAlgorithm::TdesCbc
+            | DesAlgorithm::DesEcb
+            | DesAlgorithm::TdesEcb
+            | DesAlgorithm::TdesEde2Cbc
+            | DesAlgorithm::TdesEde2Ecb => Err(not_seekable(algo.name())),
+        }
+    }
+}
+
+impl TryFrom<ChaCha20Algorithm> for KeystreamAlgorithm {
+    type Error = Error;
+
+    fn try_from(algo: ChaCha20Algorithm) -> Result<Self> {
+        match algo {
+            ChaCha20Algorithm::Chacha20 => Ok(KeystreamAlgorithm::Chacha20),
+            ChaCha20Algorithm::XChacha20 => Ok(KeystreamAlgorithm::XChacha20),
+            _ => Err(not_seekable(algo.name())),
+        }
+    }
+}
+
+fn not_seekable(name: &str) -> Error {
+    Error::InvalidParameter(format!(
+        "{} has no seekable keystream, use CTR or raw ChaCha20",
+        name
+    ))
+}
+
+/// Object-safe view of `StreamCipher + StreamCipherSeek`, whose seek methods
+/// are generic.
+trait Seekable {
+    fn apply(&mut self, data: &mut [u8]) -> bool;
+    fn seek(&mut self, pos: u64) -> bool;
+    fn position(&self) -> u64;
+}
+
+impl<C: StreamCipher + StreamCipherSeek> Seekable for C {
+    fn apply(&mut self, data: &mut [u8]) -> bool {
+        self.try_apply_keystream(data).is_ok()
+    }
+
+    fn seek(&mut self, pos: u64) -> bool {
+        self.try_seek(pos).is_ok()
+    }
+
+    fn position(&self) -> u64 {
+        // Only fails past 2^64 bytes of keystream.
+        self.try_current_pos().unwrap_or(u64::MAX)
+    }
+}
+
+fn seekable<C>(algo: KeystreamAlgorithm, key: &[u8], iv: &[u8]) -> Result<Box<dyn Seekable>>
+where
+    C: KeyIvInit + StreamCipher + StreamCipherSeek + 'static,
+{
+    let cipher = C::new_from_slices(key, iv)
+        .map_err(|_| Error::InvalidKey(format!("Invalid {} key/IV", algo.name())))?;
+    Ok(Box::new(cipher))
+}
+
+macro_rules! ctr_seekable {
+    ($cipher:ty, $algo:expr, $layout:expr, $key:expr, $iv:expr; $($flavor:ident),+) => {
+        match $layout {
+            $(CounterLayout::$flavor => seekable::<ctr::$flavor<$cipher>>($algo, $key, $iv),)+
+            #[allow(unreachable_patterns)]
+            layout => Err(unsupported_layout($algo, layout)),
+        }
+    };
+}
+
+/// Keystream length in bytes, if it fits in a `u64`: one block short of a
+/// full counter cycle.
+fn keystream_len(algo: KeystreamAlgorithm, layout: CounterLayout) -> Option<u64> {
+    let block = match algo {
+        KeystreamAlgorithm::DesCtr
+        | KeystreamAlgorithm::TdesCtr
+        | KeystreamAlgorithm::TdesEde2Ctr => 8,
+        KeystreamAlgorithm::Chacha20 | KeystreamAlgorithm::XChacha20 => 64,
+        _ => 16,
+    };
+    match layout {
+        CounterLayout::Ctr32BE | CounterLayout::Ctr32LE => Some(u64::from(u32::MAX) * block),
+        CounterLayout::Ctr64BE | CounterLayout::Ctr128BE => None,
+    }
+}
+
+fn unsupported_layout(algo: KeystreamAlgorithm, layout: CounterLayout) -> Error {
+    Error::InvalidParameter(format!(
+        "{} does not support the {:?} counter layout",
+        algo.name(),
+        layout
+    ))
+}
+
+/// A CTR or ChaCha20 keystream with a movable position.
+///
+/// Key material is wiped when the value is dropped.
+pub struct Keystream {
+    inner: Box<dyn Seekable>,
+    algo: KeystreamAlgorithm,
+    len: Option<u64>,
+}
+
+impl Keystream {
+    /// `iv` is the initial counter block (AES, DES) or nonce (ChaCha20); the
+    /// stream starts at byte 0.
+    pub fn new(
+        key: &[u8],
+        iv: &[u8],
+        algo: KeystreamAlgorithm,
+        layout: CounterLayout,
+    ) -> Result<Self> {
+        if key.len() != algo.key_len() || iv.len() != algo.iv_len() {
+            return Err(Error::InvalidLength(format!(
+                "{}: key must be {} bytes, IV must be {} bytes",
+                algo.name(),
+                algo.key_len(),
+                algo.iv_len()
+            )));
+        }
+
+        let inner = match algo {
+            KeystreamAlgorithm::Aes128Ctr => ctr_seekable!(
+                Aes128, algo, layout, key, iv; Ctr32BE, Ctr64BE, Ctr128BE, Ctr32LE
+            ),
+            KeystreamAlgorithm::Aes192Ctr => ctr_seekable!(
+                Aes192, algo, layout, key, iv; Ctr32BE, Ctr64BE, Ctr128BE, Ctr32LE
+            ),
+            KeystreamAlgorithm::Aes256Ctr => ctr_seekable!(
+                Aes256, algo, layout, key, iv; Ctr32BE, Ctr64BE, Ctr128BE, Ctr32LE
+            ),
+            KeystreamAlgorithm::DesCtr => {
+                ctr_seekable!(Des, algo, layout, key, iv; Ctr32BE, Ctr64BE, Ctr32LE)
+            }
+            KeystreamAlgorithm::TdesCtr => {
+                ctr_seekable!(TdesEde3, algo, layout, key, iv; Ctr32BE, Ctr64BE, Ctr32LE)
+            }
+            KeystreamAlgorithm::TdesEde2Ctr => {
+                ctr_seekable!(TdesEde2, algo, layout, key, iv; Ctr32BE, Ctr64BE, Ctr32LE)
+            }
+            KeystreamAlgorithm::Chacha20 if layout == CounterLayout::Ctr32LE => {
+                seekable::<ChaCha20>(algo, key, iv)
+            }
+            KeystreamAlgorithm::XChacha20 if layout == CounterLayout::Ctr32LE => {
+                seekable::<XChaCha20>(algo, key, iv)
+            }
+            _ => Err(unsupported_layout(algo, layout)),
+        }?;
+
+        Ok(Self {
+            inner,
+            algo,
+            len: keystream_len(algo, layout),
+        })
+    }
+
+    /// XORs the keystream into `data` from the current position and moves
+    /// past it.
+    pub fn apply(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut data = data.to_vec();
+        self.apply_in_place(&mut data)?;
+        Ok(data)
+    }
+
+    /// In-place [`apply`](Self::apply). Fails without touching `data` if the
+    /// counter would run out partway through.
+    pub fn apply_in_place(&mut self, data: &mut [u8]) -> Result<()> {
+        // Checked here as well: after a seek into the final block the
+        // underlying cipher would wrap instead of failing.
+        let end = self.position().checked_add(data.len() as u64);
+        if end.zip(self.len).is_some_and(|(end, len)| end > len) || !self.inner.apply(data) {
+            return Err(Error::Operation(format!(
+                "{} keystream exhausted: the counter would wrap",
+                self.algo.name()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Moves to byte `offset` of the keystream, forwards or backwards.
+    pub fn seek(&mut self, offset: u64) -> Result<()> {
+        if self.len.is_some_and(|len| offset > len) || !self.inner.seek(offset) {
+            return Err(Error::InvalidParameter(format!(
+                "{}: offset {} is past the end of the keystream",
+                self.algo.name(),
+                offset
+            )));
+        }
+        Ok(())
+    }
+
+    /// Current byte offset in the keystream.
+    pub fn position(&self) -> u64 {
+        self.inner.position()
+    }
+}
@@ -0,0 +1,16 @@
+//! Symmetric ciphers, key agreement and public-key encryption.
+
+pub mod aes;
+pub mod ascon;
+pub mod block;
+pub mod chacha20;
+pub mod des;
+pub mod ecdh;
+pub mod ecies;
+pub mod hpke;
+pub mod keystream;
+pub mod padding;
+pub mod rsa;
+pub mod salsa20;
+pub mod stream;
+pub mod x25519;
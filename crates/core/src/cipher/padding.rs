@@ -0,0 +1,134 @@
+//! Block padding for the unauthenticated block modes (CBC and ECB).
+//!
+//! Unpadding inspects the whole final block with constant-time operations
+//! and reports every failure the same way, so a decryption oracle learns
+//! nothing from either the error or its timing about *why* padding was
+//! rejected.
+
+use rand_core::{OsRng, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Padding {
+    /// `n` bytes of value `n` (RFC 5652), the OpenSSL and WebCrypto default.
+    Pkcs7,
+    /// `0x80` followed by zeros (ISO/IEC 7816-4).
+    Iso7816,
+    /// Zeros followed by a final length byte (ANSI X9.23).
+    AnsiX923,
+    /// Random bytes followed by a final length byte (ISO 10126).
+    Iso10126,
+    /// Zeros up to the block boundary. Ambiguous: trailing zero bytes of the
+    /// plaintext are stripped too, and aligned input gets no extra block.
+    ZeroPadding,
+    /// No padding; input must already be a multiple of the block size.
+    NoPadding,
+}
+
+/// Pads `data` to a multiple of `block_size`, or returns `None` when
+/// [`Padding::NoPadding`] is given unaligned input.
+pub(crate) fn pad(data: &[u8], block_size: usize, padding: Padding) -> Option<Vec<u8>> {
+    let rem = data.len() % block_size;
+    let n = match padding {
+        Padding::NoPadding if rem != 0 => return None,
+        Padding::NoPadding => 0,
+        Padding::ZeroPadding => (block_size - rem) % block_size,
+        _ => block_size - rem,
+    };
+
+    let mut out = Vec::with_capacity(data.len() + n);
+    out.extend_from_slice(data);
+    match padding {
+        Padding::Pkcs7 => out.resize(data.len() + n, n as u8),
+        Padding::Iso7816 => {
+            out.push(0x80);
+            out.resize(data.len() + n, 0);
+        }
+        Padding::AnsiX923 => {
+            out.resize(data.len() + n - 1, 0);
+            out.push(n as u8);
+        }
+        Padding::Iso10126 => {
+            out.resize(data.len() + n - 1, 0);
+            OsRng.fill_bytes(&mut out[data.len()..]);
+            out.push(n as u8);
+        }
+        Padding::ZeroPadding => out.resize(data.len() + n, 0),
+        Padding::NoPadding => {}
+    }
+
+    Some(out)
+}
+
+/// Strips padding from decrypted `data` in place. Returns `false`, leaving
+/// `data` untouched, if the padding is malformed.
+///
+/// Only the length of `data` affects timing: the final block is always read
+/// in full and validity is accumulated as a [`Choice`].
+pub(crate) fn unpad(data: &mut Vec<u8>, block_size: usize, padding: Padding) -> bool {
+    if !data.len().is_multiple_of(block_size) {
+        return false;
+    }
+    if matches!(padding, Padding::NoPadding) {
+        return true;
+    }
+    if data.is_empty() {
+        // Zero padding adds nothing to empty input; every other scheme
+        // always adds at least one byte.
+        return matches!(padding, Padding::ZeroPadding);
+    }
+
+    let block = &data[data.len() - block_size..];
+    let bs = block_size as u8;
+    let last = block[block_size - 1];
+
+    let (valid, n) = match padding {
+        Padding::Pkcs7 | Padding::AnsiX923 | Padding::Iso10126 => {
+            let mut valid = last.ct_gt(&0) & !last.ct_gt(&bs);
+            // Byte `i` from the end (0 = last) belongs to the padding iff
+            // `i < last`.
+            for i in 1..bs {
+                let in_pad = last.ct_gt(&i);
+                let b = block[block_size - 1 - i as usize];
+                let ok = match padding {
+                    Padding::Pkcs7 => b.ct_eq(&last),
+                    Padding::AnsiX923 => b.ct_eq(&0),
+                    _ => Choice::from(1),
+                };
+                valid &= !in_pad | ok;
+            }
+            (valid, last)
+        }
+        Padding::Iso7816 | Padding::ZeroPadding => {
+            let iso = matches!(padding, Padding::Iso7816);
+            let mut found = Choice::from(0);
+            let mut bad = Choice::from(0);
+            let mut n = 0u8;
+            for i in 0..bs {
+                let b = block[block_size - 1 - i as usize];
+                let searching = !found;
+                let is_zero = b.ct_eq(&0);
+                if iso {
+                    let is_marker = b.ct_eq(&0x80);
+                    n.conditional_assign(&(i + 1), searching & is_marker);
+                    bad |= searching & !is_marker & !is_zero;
+                    found |= is_marker;
+                } else {
+                    n.conditional_assign(&(i + 1), searching & is_zero);
+                    found |= !is_zero;
+                }
+            }
+            (if iso { found & !bad } else { Choice::from(1) }, n)
+        }
+        Padding::NoPadding => unreachable!(),
+    };
+
+    if bool::from(valid) {
+        data.truncate(data.len() - n as usize);
+        true
+    } else {
+        false
+    }
+}
@@ -0,0 +1,241 @@
+use rand_core::{OsRng, RngCore};
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::traits::PublicKeyParts;
+use rsa::{Oaep, RsaPrivateKey, RsaPublicKey};
+use sha1::Sha1;
+use sha2::{Sha256, Sha384, Sha512};
+use zeroize::Zeroizing;
+
+use super::aes::{AesAlgorithm, AesGcmCipher};
+use crate::{Error, Result};
+
+/// Version byte of the [`envelope_encrypt`] container.
+pub const ENVELOPE_VERSION: u8 = 1;
+
+const ENVELOPE_HEADER_LEN: usize = 4;
+const ENVELOPE_NONCE_LEN: usize = 12;
+const ENVELOPE_TAG_LEN: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HashAlg {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlg {
+    fn name(self) -> &'static str {
+        match self {
+            HashAlg::Sha1 => "SHA1",
+            HashAlg::Sha256 => "SHA256",
+            HashAlg::Sha384 => "SHA384",
+            HashAlg::Sha512 => "SHA512",
+        }
+    }
+
+    fn output_len(self) -> usize {
+        match self {
+            HashAlg::Sha1 => 20,
+            HashAlg::Sha256 => 32,
+            HashAlg::Sha384 => 48,
+            HashAlg::Sha512 => 64,
+        }
+    }
+
+    /// Identifier in the envelope header.
+    fn id(self) -> u8 {
+        match self {
+            HashAlg::Sha1 => 1,
+            HashAlg::Sha256 => 2,
+            HashAlg::Sha384 => 3,
+            HashAlg::Sha512 => 4,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(HashAlg::Sha1),
+            2 => Some(HashAlg::Sha256),
+            3 => Some(HashAlg::Sha384),
+            4 => Some(HashAlg::Sha512),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an SPKI or PKCS#1 DER public key.
+pub fn parse_public_key_der(der: &[u8]) -> Result<RsaPublicKey> {
+    if let Ok(pk) = RsaPublicKey::from_public_key_der(der) {
+        return Ok(pk);
+    }
+    if let Ok(pk) = RsaPublicKey::from_pkcs1_der(der) {
+        return Ok(pk);
+    }
+    Err(Error::InvalidKey(
+        "Failed to parse RSA public key (SPKI or PKCS#1 DER)".into(),
+    ))
+}
+
+/// Parses a PKCS#8 or PKCS#1 DER private key.
+pub fn parse_private_key_der(der: &[u8]) -> Result<RsaPrivateKey> {
+    if let Ok(sk) = RsaPrivateKey::from_pkcs8_der(der) {
+        return Ok(sk);
+    }
+    if let Ok(sk) = RsaPrivateKey::from_pkcs1_der(der) {
+        return Ok(sk);
+    }
+    Err(Error::InvalidKey(
+        "Failed to parse RSA private key (PKCS#8 or PKCS#1 DER)".into(),
+    ))
+}
+
+/// Largest OAEP plaintext for a `bits`-bit modulus: `k - 2*hLen - 2`.
+pub fn max_oaep_len(bits: usize, hash_len: usize) -> usize {
+    let k = bits.div_ceil(8);
+    k.saturating_sub(2 * hash_len + 2)
+}
+
+// Labels are taken as UTF-8 to match the JS API; invalid UTF-8 becomes "".
+fn padding(hash: HashAlg, label: Option<&[u8]>) -> Oaep {
+    let label = label.map(|l| std::str::from_utf8(l).unwrap_or(""));
+    match (hash, label) {
+        (HashAlg::Sha1, Some(l)) => Oaep::new_with_label::<Sha1, _>(l),
+        (HashAlg::Sha1, None) => Oaep::new::<Sha1>(),
+        (HashAlg::Sha256, Some(l)) => Oaep::new_with_label::<Sha256, _>(l),
+        (HashAlg::Sha256, None) => Oaep::new::<Sha256>(),
+        (HashAlg::Sha384, Some(l)) => Oaep::new_with_label::<Sha384, _>(l),
+        (HashAlg::Sha384, None) => Oaep::new::<Sha384>(),
+        (HashAlg::Sha512, Some(l)) => Oaep::new_with_label::<Sha512, _>(l),
+        (HashAlg::Sha512, None) => Oaep::new::<Sha512>(),
+    }
+}
+
+pub fn oaep_encrypt(
+    plaintext: &[u8],
+    public_key_der: &[u8],
+    hash: HashAlg,
+    label: Option<&[u8]>,
+) -> Result<Vec<u8>> {
+    let pk = parse_public_key_der(public_key_der)?;
+
+    if plaintext.len() > max_oaep_len(pk.size() * 8, hash.output_len()) {
+        return Err(Error::InvalidLength(format!(
+            "Plaintext too long for RSA-OAEP-{}",
+            hash.name()
+        )));
+    }
+
+    pk.encrypt(&mut OsRng, padding(hash, label), plaintext)
+        .map_err(|_| Error::Operation("RSA-OAEP encryption failed".into()))
+}
+
+pub fn oaep_decrypt(
+    ciphertext: &[u8],
+    private_key_der: &[u8],
+    hash: HashAlg,
+    label: Option<&[u8]>,
+) -> Result<Vec<u8>> {
+    let sk = parse_private_key_der(private_key_der)?;
+
+    sk.decrypt(padding(hash, label), ciphertext)
+        .map_err(|_| Error::Decryption("RSA-OAEP decryption failed".into()))
+}
+
+/// Hybrid encryption of a payload of any size: a fresh AES-256-GCM key is
+/// wrapped with RSA-OAEP and the payload sealed under it. The container is
+///
+/// ```text
+/// version (1) | OAEP hash id (1) | k (2, big-endian) | OAEP-wrapped key (k)
+///   | GCM nonce (12) | ciphertext | GCM tag (16)
+/// ```
+///
+/// where `version` is [`ENVELOPE_VERSION`], the hash ids are 1 = SHA-1,
+/// 2 = SHA-256, 3 = SHA-384 and 4 = SHA-512, and `k` is the modulus size in
+/// bytes. Everything before the nonce is the GCM associated data.
+pub fn envelope_encrypt(
+    plaintext: &[u8],
+    public_key_der: &[u8],
+    hash: HashAlg,
+    label: Option<&[u8]>,
+) -> Result<Vec<u8>> {
+    let pk = parse_public_key_der(public_key_der)?;
+    if max_oaep_len(pk.size() * 8, hash.output_len()) < 32 {
+        return Err(Error::InvalidKey(format!(
+            "RSA key too small to wrap an AES-256 key with OAEP-{}",
+            hash.name()
+        )));
+    }
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    OsRng.fill_bytes(key.as_mut());
+    let wrapped = pk
+        .encrypt(&mut OsRng, padding(hash, label), key.as_ref())
+        .map_err(|_| Error::Operation("RSA-OAEP encryption failed".into()))?;
+
+    let mut out = Vec::with_capacity(
+        ENVELOPE_HEADER_LEN
+            + wrapped.len()
+            + ENVELOPE_NONCE_LEN
+            + plaintext.len()
+            + ENVELOPE_TAG_LEN,
+    );
+    out.push(ENVELOPE_VERSION);
+    out.push(hash.id());
+    out.extend_from_slice(&(wrapped.len() as u16).to_be_bytes());
+    out.extend_from_slice(&wrapped);
+
+    let mut nonce = [0u8; ENVELOPE_NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    let sealed = AesGcmCipher::new(key.as_ref(), AesAlgorithm::Aes256Gcm)?
+        .encrypt(plaintext, &nonce, &out)?;
+
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&sealed);
+    Ok(out)
+}
+
+/// Opens an [`envelope_encrypt`] container; the OAEP hash is read from its
+/// header.
+pub fn envelope_decrypt(
+    envelope: &[u8],
+    private_key_der: &[u8],
+    label: Option<&[u8]>,
+) -> Result<Vec<u8>> {
+    if envelope.len() < ENVELOPE_HEADER_LEN {
+        return Err(Error::Decryption("RSA envelope is too short".into()));
+    }
+    if envelope[0] != ENVELOPE_VERSION {
+        return Err(Error::Decryption(format!(
+            "Unsupported RSA envelope version {}",
+            envelope[0]
+        )));
+    }
+    let hash = HashAlg::from_id(envelope[1]).ok_or_else(|| {
+        Error::Decryption(format!("Unknown RSA envelope hash id {}", envelope[1]))
+    })?;
+    let wrapped_len = u16::from_be_bytes([envelope[2], envelope[3]]) as usize;
+    let aad_len = ENVELOPE_HEADER_LEN + wrapped_len;
+    if envelope.len() < aad_len + ENVELOPE_NONCE_LEN + ENVELOPE_TAG_LEN {
+        return Err(Error::Decryption("RSA envelope is too short".into()));
+    }
+
+    let sk = parse_private_key_der(private_key_der)?;
+    let (aad, rest) = envelope.split_at(aad_len);
+    let (nonce, sealed) = rest.split_at(ENVELOPE_NONCE_LEN);
+
+    let key = Zeroizing::new(
+        sk.decrypt(padding(hash, label), &aad[ENVELOPE_HEADER_LEN..])
+            .map_err(|_| Error::Decryption("RSA-OAEP decryption failed".into()))?,
+    );
+    if key.len() != 32 {
+        return Err(Error::Decryption(
+            "RSA envelope key must be 32 bytes".into(),
+        ));
+    }
+
+    AesGcmCipher::new(&key, AesAlgorithm::Aes256Gcm)?.decrypt(sealed, nonce, aad)
+}
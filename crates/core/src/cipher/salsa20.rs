@@ -0,0 +1,128 @@
+//! Salsa20, XSalsa20 and NaCl/libsodium `crypto_secretbox`
+//! (XSalsa20-Poly1305).
+//!
+//! [`secretbox_seal`] produces libsodium's combined `crypto_secretbox_easy`
+//! format, the 16-byte Poly1305 tag followed by the ciphertext, which is also
+//! what TweetNaCl's `nacl.secretbox` returns.
+
+use crypto_secretbox::aead::{Aead, KeyInit};
+use crypto_secretbox::{Nonce, XSalsa20Poly1305};
+use salsa20::cipher::{KeyIvInit, StreamCipher};
+use salsa20::{Salsa20, XSalsa20};
+
+use crate::{Error, Result};
+
+/// `crypto_secretbox_KEYBYTES`.
+pub const SECRETBOX_KEY_LEN: usize = 32;
+
+/// `crypto_secretbox_NONCEBYTES`; large enough to pick at random.
+pub const SECRETBOX_NONCE_LEN: usize = 24;
+
+/// `crypto_secretbox_MACBYTES`, prepended to every sealed box.
+pub const SECRETBOX_MAC_LEN: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Salsa20Algorithm {
+    /// Salsa20/20 with an 8-byte nonce (no authentication)
+    Salsa20,
+    /// XSalsa20 with a 24-byte nonce (no authentication)
+    XSalsa20,
+}
+
+impl Salsa20Algorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            Salsa20Algorithm::Salsa20 => "Salsa20",
+            Salsa20Algorithm::XSalsa20 => "XSalsa20",
+        }
+    }
+
+    /// Required nonce length in bytes: 8, or 24 for XSalsa20.
+    pub fn nonce_len(self) -> usize {
+        match self {
+            Salsa20Algorithm::Salsa20 => 8,
+            Salsa20Algorithm::XSalsa20 => 24,
+        }
+    }
+}
+
+fn apply_keystream<C: KeyIvInit + StreamCipher>(
+    algo: Salsa20Algorithm,
+    key: &[u8],
+    nonce: &[u8],
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    if key.len() != 32 || nonce.len() != algo.nonce_len() {
+        return Err(Error::InvalidLength(format!(
+            "{}: key must be 32 bytes, nonce must be {} bytes",
+            algo.name(),
+            algo.nonce_len()
+        )));
+    }
+
+    let mut cipher = C::new_from_slices(key, nonce)
+        .map_err(|_| Error::InvalidKey(format!("Invalid {} key/nonce", algo.name())))?;
+    let mut data = data.to_vec();
+    cipher.apply_keystream(&mut data);
+    Ok(data)
+}
+
+/// XORs the keystream, starting at block 0, into `plaintext`; libsodium's
+/// `crypto_stream_salsa20_xor` / `crypto_stream_xsalsa20_xor`.
+pub fn encrypt(
+    plaintext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    algo: Salsa20Algorithm,
+) -> Result<Vec<u8>> {
+    match algo {
+        Salsa20Algorithm::Salsa20 => apply_keystream::<Salsa20>(algo, key, nonce, plaintext),
+        Salsa20Algorithm::XSalsa20 => apply_keystream::<XSalsa20>(algo, key, nonce, plaintext),
+    }
+}
+
+/// Same XOR as [`encrypt`].
+pub fn decrypt(
+    ciphertext: &[u8],
+    key: &[u8],
+    nonce: &[u8],
+    algo: Salsa20Algorithm,
+) -> Result<Vec<u8>> {
+    encrypt(ciphertext, key, nonce, algo)
+}
+
+fn secretbox(key: &[u8], nonce: &[u8]) -> Result<(XSalsa20Poly1305, Nonce)> {
+    if key.len() != SECRETBOX_KEY_LEN || nonce.len() != SECRETBOX_NONCE_LEN {
+        return Err(Error::InvalidLength(format!(
+            "secretbox: key must be {} bytes, nonce must be {} bytes",
+            SECRETBOX_KEY_LEN, SECRETBOX_NONCE_LEN
+        )));
+    }
+
+    let cipher = XSalsa20Poly1305::new_from_slice(key)
+        .map_err(|_| Error::InvalidKey("Invalid secretbox key".into()))?;
+
+    Ok((cipher, *Nonce::from_slice(nonce)))
+}
+
+/// `crypto_secretbox_easy`: encrypts and authenticates `plaintext`,
+/// returning the tag followed by the ciphertext.
+pub fn secretbox_seal(plaintext: &[u8], key: &[u8], nonce: &[u8]) -> Result<Vec<u8>> {
+    let (cipher, nonce) = secretbox(key, nonce)?;
+
+    cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| Error::Operation("secretbox encryption failed".into()))
+}
+
+/// `crypto_secretbox_open_easy`: verifies and decrypts output of
+/// [`secretbox_seal`].
+pub fn secretbox_open(boxed: &[u8], key: &[u8], nonce: &[u8]) -> Result<Vec<u8>> {
+    let (cipher, nonce) = secretbox(key, nonce)?;
+
+    cipher
+        .decrypt(&nonce, boxed)
+        .map_err(|_| Error::Decryption("secretbox verification failed".into()))
+}
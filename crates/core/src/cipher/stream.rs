@@ -0,0 +1,280 @@
+//! STREAM online authenticated encryption (Hoang, Reyhanitabar, Rogaway and
+//! Vizár, 2015) over AES-GCM and ChaCha20-Poly1305.
+//!
+//! Input is cut into fixed-size segments. Each segment is sealed under the
+//! 12-byte nonce `prefix (7) || counter (4, big-endian) || last flag (1)`,
+//! the `aead::stream::EncryptorBE32` layout, and carries its own 16-byte
+//! tag. Reordered segments fail under the wrong counter, and a stream cut
+//! at a segment boundary fails because its final segment lacks the last
+//! flag.
+
+use aes::Aes192;
+use aes_gcm::aead::stream::{DecryptorBE32, EncryptorBE32};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm};
+use chacha20poly1305::ChaCha20Poly1305;
+use typenum::U12;
+
+use super::aes::AesAlgorithm;
+use crate::{Error, Result};
+
+type Aes192Gcm = AesGcm<Aes192, U12>;
+
+/// Length of the per-segment authentication tag.
+pub const TAG_LEN: usize = 16;
+
+/// Length of the nonce prefix passed to [`StreamingEncryptor::new`].
+pub const NONCE_PREFIX_LEN: usize = 7;
+
+/// Plaintext segment size used when none is given.
+pub const DEFAULT_SEGMENT_SIZE: usize = 64 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum StreamAlgorithm {
+    Aes128Gcm,
+    Aes192Gcm,
+    Aes256Gcm,
+    Chacha20Poly1305,
+}
+
+impl StreamAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            StreamAlgorithm::Aes128Gcm => "AES-128-GCM",
+            StreamAlgorithm::Aes192Gcm => "AES-192-GCM",
+            StreamAlgorithm::Aes256Gcm => "AES-256-GCM",
+            StreamAlgorithm::Chacha20Poly1305 => "ChaCha20-Poly1305",
+        }
+    }
+
+    pub fn key_len(self) -> usize {
+        match self {
+            StreamAlgorithm::Aes128Gcm => 16,
+            StreamAlgorithm::Aes192Gcm => 24,
+            StreamAlgorithm::Aes256Gcm | StreamAlgorithm::Chacha20Poly1305 => 32,
+        }
+    }
+}
+
+impl TryFrom<AesAlgorithm> for StreamAlgorithm {
+    type Error = Error;
+
+    fn try_from(algo: AesAlgorithm) -> Result<Self> {
+        match algo {
+            AesAlgorithm::Aes128Gcm => Ok(StreamAlgorithm::Aes128Gcm),
+            AesAlgorithm::Aes192Gcm => Ok(StreamAlgorithm::Aes192Gcm),
+            AesAlgorithm::Aes256Gcm => Ok(StreamAlgorithm::Aes256Gcm),
+            _ => Err(Error::InvalidParameter(format!(
+                "{} is not supported for STREAM encryption, use GCM",
+                algo.name()
+            ))),
+        }
+    }
+}
+
+fn check_params(
+    algo: StreamAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    segment_size: usize,
+) -> Result<()> {
+    if key.len() != algo.key_len() || nonce.len() != NONCE_PREFIX_LEN {
+        return Err(Error::InvalidLength(format!(
+            "{} STREAM: key must be {} bytes, nonce must be {} bytes",
+            algo.name(),
+            algo.key_len(),
+            NONCE_PREFIX_LEN
+        )));
+    }
+    if segment_size == 0 {
+        return Err(Error::InvalidParameter(
+            "STREAM segment size must be at least 1 byte".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+macro_rules! stream_inner {
+    ($name:ident, $stream:ident) => {
+        enum $name {
+            Aes128Gcm($stream<Aes128Gcm>),
+            Aes192Gcm($stream<Aes192Gcm>),
+            Aes256Gcm($stream<Aes256Gcm>),
+            Chacha20Poly1305($stream<ChaCha20Poly1305>),
+        }
+
+        impl $name {
+            fn new(algo: StreamAlgorithm, key: &[u8], nonce: &[u8]) -> Self {
+                let nonce = nonce.into();
+                match algo {
+                    StreamAlgorithm::Aes128Gcm => Self::Aes128Gcm($stream::new(key.into(), nonce)),
+                    StreamAlgorithm::Aes192Gcm => Self::Aes192Gcm($stream::new(key.into(), nonce)),
+                    StreamAlgorithm::Aes256Gcm => Self::Aes256Gcm($stream::new(key.into(), nonce)),
+                    StreamAlgorithm::Chacha20Poly1305 => {
+                        Self::Chacha20Poly1305($stream::new(key.into(), nonce))
+                    }
+                }
+            }
+        }
+    };
+}
+
+stream_inner!(EncryptorInner, EncryptorBE32);
+stream_inner!(DecryptorInner, DecryptorBE32);
+
+macro_rules! dispatch {
+    ($enum:ident, $inner:expr, $s:ident => $call:expr) => {
+        match $inner {
+            $enum::Aes128Gcm($s) => $call,
+            $enum::Aes192Gcm($s) => $call,
+            $enum::Aes256Gcm($s) => $call,
+            $enum::Chacha20Poly1305($s) => $call,
+        }
+    };
+}
+
+/// Incremental STREAM encryption.
+///
+/// [`update`](Self::update) buffers plaintext and returns every segment it
+/// can seal; [`finalize`](Self::finalize) seals the remainder as the last
+/// segment. Each full segment grows by [`TAG_LEN`] bytes.
+pub struct StreamingEncryptor {
+    inner: Option<EncryptorInner>,
+    algo: StreamAlgorithm,
+    segment_size: usize,
+    buffer: Vec<u8>,
+}
+
+impl StreamingEncryptor {
+    /// `nonce` is the 7-byte prefix; it must never repeat under one key.
+    pub fn new(
+        key: &[u8],
+        nonce: &[u8],
+        algo: StreamAlgorithm,
+        segment_size: usize,
+    ) -> Result<Self> {
+        check_params(algo, key, nonce, segment_size)?;
+
+        Ok(Self {
+            inner: Some(EncryptorInner::new(algo, key, nonce)),
+            algo,
+            segment_size,
+            buffer: Vec::new(),
+        })
+    }
+
+    pub fn update(&mut self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let inner = self
+            .inner
+            .as_mut()
+            .ok_or(Error::Finalized("StreamingEncryptor has been finalized"))?;
+        self.buffer.extend_from_slice(plaintext);
+
+        // Hold back at least one byte so `finalize` always has a segment to
+        // mark as last, even when the input is a multiple of the segment size.
+        let mut out = Vec::new();
+        let mut offset = 0;
+        while self.buffer.len() - offset > self.segment_size {
+            let segment = &self.buffer[offset..offset + self.segment_size];
+            let sealed = dispatch!(EncryptorInner, &mut *inner, s => s.encrypt_next(segment))
+                .map_err(|_| {
+                    Error::Operation(format!("{} STREAM encryption failed", self.algo.name()))
+                })?;
+            out.extend_from_slice(&sealed);
+            offset += self.segment_size;
+        }
+        self.buffer.drain(..offset);
+
+        Ok(out)
+    }
+
+    pub fn finalize(&mut self) -> Result<Vec<u8>> {
+        let inner = self
+            .inner
+            .take()
+            .ok_or(Error::Finalized("StreamingEncryptor has been finalized"))?;
+        let last = std::mem::take(&mut self.buffer);
+
+        dispatch!(EncryptorInner, inner, s => s.encrypt_last(last.as_slice()))
+            .map_err(|_| Error::Operation(format!("{} STREAM encryption failed", self.algo.name())))
+    }
+}
+
+/// Incremental STREAM decryption, the inverse of [`StreamingEncryptor`].
+///
+/// Segments are released only once authenticated. A missing, reordered or
+/// altered segment is reported as [`Error::Decryption`], and
+/// [`finalize`](Self::finalize) must be called to detect truncation.
+pub struct StreamingDecryptor {
+    inner: Option<DecryptorInner>,
+    algo: StreamAlgorithm,
+    segment_size: usize,
+    buffer: Vec<u8>,
+}
+
+impl StreamingDecryptor {
+    pub fn new(
+        key: &[u8],
+        nonce: &[u8],
+        algo: StreamAlgorithm,
+        segment_size: usize,
+    ) -> Result<Self> {
+        check_params(algo, key, nonce, segment_size)?;
+
+        Ok(Self {
+            inner: Some(DecryptorInner::new(algo, key, nonce)),
+            algo,
+            segment_size,
+            buffer: Vec::new(),
+        })
+    }
+
+    fn failed(&self) -> Error {
+        Error::Decryption(format!(
+            "{} STREAM decryption failed: segment tampered, reordered or truncated",
+            self.algo.name()
+        ))
+    }
+
+    pub fn update(&mut self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let sealed_len = self.segment_size + TAG_LEN;
+        let inner = self
+            .inner
+            .as_mut()
+            .ok_or(Error::Finalized("StreamingDecryptor has been finalized"))?;
+        self.buffer.extend_from_slice(ciphertext);
+
+        // A full segment may still be the last one; keep it until more
+        // ciphertext proves otherwise.
+        let mut out = Vec::new();
+        let mut offset = 0;
+        while self.buffer.len() - offset > sealed_len {
+            let segment = &self.buffer[offset..offset + sealed_len];
+            let Ok(plain) = dispatch!(DecryptorInner, &mut *inner, s => s.decrypt_next(segment))
+            else {
+                // Nothing after a forged segment can be trusted.
+                self.inner = None;
+                self.buffer.clear();
+                return Err(self.failed());
+            };
+            out.extend_from_slice(&plain);
+            offset += sealed_len;
+        }
+        self.buffer.drain(..offset);
+
+        Ok(out)
+    }
+
+    pub fn finalize(&mut self) -> Result<Vec<u8>> {
+        let inner = self
+            .inner
+            .take()
+            .ok_or(Error::Finalized("StreamingDecryptor has been finalized"))?;
+        let last = std::mem::take(&mut self.buffer);
+
+        dispatch!(DecryptorInner, inner, s => s.decrypt_last(last.as_slice()))
+            .map_err(|_| self.failed())
+    }
+}
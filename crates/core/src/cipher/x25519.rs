@@ -0,0 +1,140 @@
+//! X25519 key agreement and NaCl/libsodium `crypto_box`.
+//!
+//! `crypto_box` is XSalsa20-Poly1305 ([`super::salsa20::secretbox_seal`])
+//! under the HSalsa20 hash of the X25519 shared secret. Boxes and sealed
+//! boxes are byte-compatible with libsodium and TweetNaCl.
+
+use blake2::digest::Digest;
+use blake2::Blake2b;
+use rand_core::OsRng;
+use salsa20::hsalsa;
+use subtle::ConstantTimeEq;
+use typenum::{U10, U24};
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+use super::salsa20::{secretbox_open, secretbox_seal, SECRETBOX_MAC_LEN};
+use crate::{Error, Result};
+
+/// `crypto_box_NONCEBYTES`.
+pub const BOX_NONCE_LEN: usize = 24;
+
+/// `crypto_box_MACBYTES`, prepended to every box.
+pub const BOX_MAC_LEN: usize = SECRETBOX_MAC_LEN;
+
+/// `crypto_box_SEALBYTES`: the ephemeral public key plus the tag.
+pub const SEALED_BOX_OVERHEAD: usize = 32 + BOX_MAC_LEN;
+
+/// Returns `(private_key, public_key)`.
+pub fn generate_keypair() -> ([u8; 32], [u8; 32]) {
+    let sk = StaticSecret::random_from_rng(OsRng);
+    let pk = PublicKey::from(&sk);
+    (sk.to_bytes(), pk.to_bytes())
+}
+
+/// Raw X25519 shared secret.
+pub fn derive_shared_secret(private_key: &[u8], peer_public_key: &[u8]) -> Result<[u8; 32]> {
+    let (Ok(sk_bytes), Ok(pk_bytes)) = (
+        <[u8; 32]>::try_from(private_key),
+        <[u8; 32]>::try_from(peer_public_key),
+    ) else {
+        return Err(Error::InvalidLength("X25519 keys must be 32 bytes".into()));
+    };
+    let sk = StaticSecret::from(sk_bytes);
+    let pk = PublicKey::from(pk_bytes);
+    Ok(sk.diffie_hellman(&pk).to_bytes())
+}
+
+/// `crypto_box_beforenm`: the 32-byte key shared by both ends of a box,
+/// for use with [`super::salsa20::secretbox_seal`] when many messages go
+/// to the same peer. Fails, as libsodium does, if the peer's public key has
+/// small order and the shared secret is all zeros.
+pub fn box_beforenm(peer_public_key: &[u8], private_key: &[u8]) -> Result<[u8; 32]> {
+    let shared = Zeroizing::new(derive_shared_secret(private_key, peer_public_key)?);
+
+    if bool::from(shared.ct_eq(&[0u8; 32])) {
+        return Err(Error::InvalidKey(
+            "X25519 public key has small order".into(),
+        ));
+    }
+
+    Ok(hsalsa::<U10>(shared.as_ref().into(), &Default::default()).into())
+}
+
+/// `crypto_box_easy`: encrypts `plaintext` from the holder of
+/// `private_key` to `peer_public_key`. The result is the 16-byte tag
+/// followed by the ciphertext.
+pub fn box_seal(
+    plaintext: &[u8],
+    nonce: &[u8],
+    peer_public_key: &[u8],
+    private_key: &[u8],
+) -> Result<Vec<u8>> {
+    let key = Zeroizing::new(box_beforenm(peer_public_key, private_key)?);
+
+    secretbox_seal(plaintext, key.as_ref(), nonce)
+}
+
+/// `crypto_box_open_easy`: verifies and decrypts a box from
+/// `peer_public_key`.
+pub fn box_open(
+    boxed: &[u8],
+    nonce: &[u8],
+    peer_public_key: &[u8],
+    private_key: &[u8],
+) -> Result<Vec<u8>> {
+    let key = Zeroizing::new(box_beforenm(peer_public_key, private_key)?);
+
+    secretbox_open(boxed, key.as_ref(), nonce)
+}
+
+/// BLAKE2b-192 of the ephemeral and recipient public keys.
+fn sealed_box_nonce(ephemeral_pk: &[u8], recipient_pk: &[u8]) -> [u8; BOX_NONCE_LEN] {
+    Blake2b::<U24>::new()
+        .chain_update(ephemeral_pk)
+        .chain_update(recipient_pk)
+        .finalize()
+        .into()
+}
+
+/// `crypto_box_seal`: anonymous encryption to `recipient_public_key` under a
+/// fresh ephemeral key pair. The result is the ephemeral public key
+/// followed by a box, [`SEALED_BOX_OVERHEAD`] bytes longer than the input.
+pub fn sealed_box_seal(plaintext: &[u8], recipient_public_key: &[u8]) -> Result<Vec<u8>> {
+    if recipient_public_key.len() != 32 {
+        return Err(Error::InvalidLength("X25519 keys must be 32 bytes".into()));
+    }
+
+    let esk = StaticSecret::random_from_rng(OsRng);
+    let epk = PublicKey::from(&esk);
+    let nonce = sealed_box_nonce(epk.as_bytes(), recipient_public_key);
+
+    let boxed = box_seal(
+        plaintext,
+        &nonce,
+        recipient_public_key,
+        Zeroizing::new(esk.to_bytes()).as_ref(),
+    )?;
+
+    let mut sealed = Vec::with_capacity(32 + boxed.len());
+    sealed.extend_from_slice(epk.as_bytes());
+    sealed.extend_from_slice(&boxed);
+    Ok(sealed)
+}
+
+/// `crypto_box_seal_open`: decrypts a sealed box with the recipient's key
+/// pair.
+pub fn sealed_box_open(
+    sealed: &[u8],
+    recipient_public_key: &[u8],
+    recipient_private_key: &[u8],
+) -> Result<Vec<u8>> {
+    if sealed.len() < SEALED_BOX_OVERHEAD {
+        return Err(Error::Decryption("sealed box is too short".into()));
+    }
+
+    let (epk, boxed) = sealed.split_at(32);
+    let nonce = sealed_box_nonce(epk, recipient_public_key);
+
+    box_open(boxed, &nonce, epk, recipient_private_key)
+}
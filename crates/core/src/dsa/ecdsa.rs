@@ -0,0 +1,97 @@
+use k256::ecdsa::signature::hazmat::{
+    PrehashSigner as K256PrehashSigner, PrehashVerifier as K256PrehashVerifier,
+};
+use p256::ecdsa::signature::hazmat::{
+    PrehashSigner as P256PrehashSigner, PrehashVerifier as P256PrehashVerifier,
+};
+use rand_core::OsRng;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Curve {
+    P256,
+    Secp256k1,
+}
+
+impl Curve {
+    fn name(self) -> &'static str {
+        match self {
+            Curve::P256 => "P-256",
+            Curve::Secp256k1 => "secp256k1",
+        }
+    }
+}
+
+/// Returns `(private_key, uncompressed_public_key)`.
+pub fn generate_keypair(curve: Curve) -> (Vec<u8>, Vec<u8>) {
+    match curve {
+        Curve::P256 => {
+            let sk = p256::ecdsa::SigningKey::random(&mut OsRng);
+            let vk = sk.verifying_key().to_encoded_point(false);
+            (sk.to_bytes().to_vec(), vk.as_bytes().to_vec())
+        }
+        Curve::Secp256k1 => {
+            let sk = k256::ecdsa::SigningKey::random(&mut OsRng);
+            let vk = sk.verifying_key().to_encoded_point(false);
+            (sk.to_bytes().to_vec(), vk.as_bytes().to_vec())
+        }
+    }
+}
+
+fn check_prehash(message_hash32: &[u8], op: &str) -> Result<()> {
+    if message_hash32.len() != 32 {
+        return Err(Error::InvalidLength(format!(
+            "ECDSA {} expects 32-byte message hash",
+            op
+        )));
+    }
+    Ok(())
+}
+
+/// Signs a 32-byte message hash and returns a DER signature.
+pub fn sign(curve: Curve, private_key: &[u8], message_hash32: &[u8]) -> Result<Vec<u8>> {
+    check_prehash(message_hash32, "sign")?;
+    let invalid_key = || Error::InvalidKey(format!("Invalid {} private key", curve.name()));
+    let sign_failed = |_| Error::Operation("ECDSA sign failed".into());
+    match curve {
+        Curve::P256 => {
+            let sk = p256::ecdsa::SigningKey::from_slice(private_key).map_err(|_| invalid_key())?;
+            let sig: p256::ecdsa::Signature =
+                P256PrehashSigner::sign_prehash(&sk, message_hash32).map_err(sign_failed)?;
+            Ok(sig.to_der().as_bytes().to_vec())
+        }
+        Curve::Secp256k1 => {
+            let sk = k256::ecdsa::SigningKey::from_slice(private_key).map_err(|_| invalid_key())?;
+            let sig: k256::ecdsa::Signature =
+                K256PrehashSigner::sign_prehash(&sk, message_hash32).map_err(sign_failed)?;
+            Ok(sig.to_der().as_bytes().to_vec())
+        }
+    }
+}
+
+/// Verifies a DER signature over a 32-byte message hash.
+pub fn verify(
+    curve: Curve,
+    public_key: &[u8],
+    message_hash32: &[u8],
+    signature_der: &[u8],
+) -> Result<bool> {
+    check_prehash(message_hash32, "verify")?;
+    let invalid_key = |_| Error::InvalidKey(format!("Invalid {} public key", curve.name()));
+    let invalid_sig = |_| Error::InvalidParameter("Invalid ECDSA signature".into());
+    match curve {
+        Curve::P256 => {
+            let vk = p256::ecdsa::VerifyingKey::from_sec1_bytes(public_key).map_err(invalid_key)?;
+            let sig = p256::ecdsa::Signature::from_der(signature_der).map_err(invalid_sig)?;
+            Ok(P256PrehashVerifier::verify_prehash(&vk, message_hash32, &sig).is_ok())
+        }
+        Curve::Secp256k1 => {
+            let vk = k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key).map_err(invalid_key)?;
+            let sig = k256::ecdsa::Signature::from_der(signature_der).map_err(invalid_sig)?;
+            Ok(K256PrehashVerifier::verify_prehash(&vk, message_hash32, &sig).is_ok())
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand_core::OsRng;
+
+use crate::{Error, Result};
+
+/// Returns `(private_key, public_key)`.
+pub fn generate_keypair() -> ([u8; 32], [u8; 32]) {
+    let signing_key = SigningKey::generate(&mut OsRng);
+    (
+        signing_key.to_bytes(),
+        signing_key.verifying_key().to_bytes(),
+    )
+}
+
+pub fn sign(private_key: &[u8], message: &[u8]) -> Result<[u8; 64]> {
+    let sk_bytes: [u8; 32] = private_key
+        .try_into()
+        .map_err(|_| Error::InvalidLength("Ed25519 private key must be 32 bytes".into()))?;
+    let sk = SigningKey::from_bytes(&sk_bytes);
+    Ok(sk.sign(message).to_bytes())
+}
+
+pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool> {
+    let pk_bytes: [u8; 32] = public_key
+        .try_into()
+        .map_err(|_| Error::InvalidLength("Ed25519 public key must be 32 bytes".into()))?;
+    let vk = VerifyingKey::from_bytes(&pk_bytes)
+        .map_err(|_| Error::InvalidKey("Invalid Ed25519 public key".into()))?;
+    let sig_bytes: [u8; 64] = signature
+        .try_into()
+        .map_err(|_| Error::InvalidLength("Ed25519 signature must be 64 bytes".into()))?;
+    let sig = Signature::from_bytes(&sig_bytes);
+    Ok(vk.verify(message, &sig).is_ok())
+}
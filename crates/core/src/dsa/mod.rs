@@ -0,0 +1,5 @@
+//! Digital signatures.
+
+pub mod ecdsa;
+pub mod ed25519;
+pub mod rsa_sign;
@@ -0,0 +1,77 @@
+use rand_core::OsRng;
+use rsa::pkcs1v15::{SigningKey as Pkcs1v15SigningKey, VerifyingKey as Pkcs1v15VerifyingKey};
+use rsa::pss::{BlindedSigningKey as PssSigningKey, VerifyingKey as PssVerifyingKey};
+use rsa::signature::{RandomizedSigner, SignatureEncoding, Verifier};
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::cipher::rsa::{parse_private_key_der, parse_public_key_der};
+use crate::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HashAlg {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+/// RSASSA-PSS signature (salt length = hash length).
+pub fn pss_sign(private_key_der: &[u8], message: &[u8], hash: HashAlg) -> Result<Vec<u8>> {
+    let sk = parse_private_key_der(private_key_der)?;
+    let sig = match hash {
+        HashAlg::Sha256 => PssSigningKey::<Sha256>::new(sk).sign_with_rng(&mut OsRng, message),
+        HashAlg::Sha384 => PssSigningKey::<Sha384>::new(sk).sign_with_rng(&mut OsRng, message),
+        HashAlg::Sha512 => PssSigningKey::<Sha512>::new(sk).sign_with_rng(&mut OsRng, message),
+    };
+    Ok(sig.to_vec())
+}
+
+/// Malformed signatures verify as `false` rather than erroring.
+pub fn pss_verify(
+    public_key_der: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    hash: HashAlg,
+) -> Result<bool> {
+    let pk = parse_public_key_der(public_key_der)?;
+    let Ok(sig) = rsa::pss::Signature::try_from(signature) else {
+        return Ok(false);
+    };
+    let ok = match hash {
+        HashAlg::Sha256 => PssVerifyingKey::<Sha256>::new(pk).verify(message, &sig),
+        HashAlg::Sha384 => PssVerifyingKey::<Sha384>::new(pk).verify(message, &sig),
+        HashAlg::Sha512 => PssVerifyingKey::<Sha512>::new(pk).verify(message, &sig),
+    };
+    Ok(ok.is_ok())
+}
+
+/// RSASSA-PKCS1-v1_5 signature.
+pub fn pkcs1v15_sign(private_key_der: &[u8], message: &[u8], hash: HashAlg) -> Result<Vec<u8>> {
+    let sk = parse_private_key_der(private_key_der)?;
+    let sig = match hash {
+        HashAlg::Sha256 => Pkcs1v15SigningKey::<Sha256>::new(sk).sign_with_rng(&mut OsRng, message),
+        HashAlg::Sha384 => Pkcs1v15SigningKey::<Sha384>::new(sk).sign_with_rng(&mut OsRng, message),
+        HashAlg::Sha512 => Pkcs1v15SigningKey::<Sha512>::new(sk).sign_with_rng(&mut OsRng, message),
+    };
+    Ok(sig.to_vec())
+}
+
+/// Malformed signatures verify as `false` rather than erroring.
+pub fn pkcs1v15_verify(
+    public_key_der: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    hash: HashAlg,
+) -> Result<bool> {
+    let pk = parse_public_key_der(public_key_der)?;
+    let Ok(sig) = rsa::pkcs1v15::Signature::try_from(signature) else {
+        return Ok(false);
+    };
+    let ok = match hash {
+        HashAlg::Sha256 => Pkcs1v15VerifyingKey::<Sha256>::new(pk).verify(message, &sig),
+        HashAlg::Sha384 => Pkcs1v15VerifyingKey::<Sha384>::new(pk).verify(message, &sig),
+        HashAlg::Sha512 => Pkcs1v15VerifyingKey::<Sha512>::new(pk).verify(message, &sig),
+    };
+    Ok(ok.is_ok())
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// Errors returned by the algorithms in this crate.
+///
+/// The message carried by each variant is the one surfaced to JavaScript by
+/// the wasm bindings, so it is kept stable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A key, nonce, IV or other fixed-size input has the wrong length.
+    InvalidLength(String),
+    /// Key material could not be parsed or was rejected by the algorithm.
+    InvalidKey(String),
+    /// An option is missing or out of range.
+    InvalidParameter(String),
+    /// Encryption, hashing or signing failed.
+    Operation(String),
+    /// Decryption failed: bad padding, truncated input or tag mismatch.
+    Decryption(String),
+    /// A streaming object was used after it was finalized.
+    Finalized(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength(msg)
+            | Error::InvalidKey(msg)
+            | Error::InvalidParameter(msg)
+            | Error::Operation(msg)
+            | Error::Decryption(msg) => f.write_str(msg),
+            Error::Finalized(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,169 @@
+//! HMAC over every hash exposed by the [`sha`](crate::sha) module.
+
+use ::hmac::{Hmac, Mac};
+use digest::KeyInit;
+
+use md4::Md4;
+use md5::Md5;
+use ripemd::{Ripemd160, Ripemd256, Ripemd320};
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use whirlpool::Whirlpool;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum HashAlgorithm {
+    Md4,
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Ripemd160,
+    Ripemd256,
+    Ripemd320,
+    Whirlpool,
+}
+
+fn new_mac<M: Mac + KeyInit>(key: &[u8]) -> Result<M> {
+    <M as Mac>::new_from_slice(key).map_err(|e| Error::InvalidKey(e.to_string()))
+}
+
+fn mac<M: Mac + KeyInit>(key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = new_mac::<M>(key)?;
+
+    mac.update(message);
+
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// HMAC of `message` under `key`.
+pub fn hmac(key: &[u8], message: &[u8], algo: HashAlgorithm) -> Result<Vec<u8>> {
+    match algo {
+        HashAlgorithm::Md4 => mac::<Hmac<Md4>>(key, message),
+        HashAlgorithm::Md5 => mac::<Hmac<Md5>>(key, message),
+        HashAlgorithm::Sha1 => mac::<Hmac<Sha1>>(key, message),
+        HashAlgorithm::Sha224 => mac::<Hmac<Sha224>>(key, message),
+        HashAlgorithm::Sha256 => mac::<Hmac<Sha256>>(key, message),
+        HashAlgorithm::Sha384 => mac::<Hmac<Sha384>>(key, message),
+        HashAlgorithm::Sha512 => mac::<Hmac<Sha512>>(key, message),
+        HashAlgorithm::Sha512_224 => mac::<Hmac<Sha512_224>>(key, message),
+        HashAlgorithm::Sha512_256 => mac::<Hmac<Sha512_256>>(key, message),
+        HashAlgorithm::Sha3_224 => mac::<Hmac<Sha3_224>>(key, message),
+        HashAlgorithm::Sha3_256 => mac::<Hmac<Sha3_256>>(key, message),
+        HashAlgorithm::Sha3_384 => mac::<Hmac<Sha3_384>>(key, message),
+        HashAlgorithm::Sha3_512 => mac::<Hmac<Sha3_512>>(key, message),
+        HashAlgorithm::Ripemd160 => mac::<Hmac<Ripemd160>>(key, message),
+        HashAlgorithm::Ripemd256 => mac::<Hmac<Ripemd256>>(key, message),
+        HashAlgorithm::Ripemd320 => mac::<Hmac<Ripemd320>>(key, message),
+        HashAlgorithm::Whirlpool => mac::<Hmac<Whirlpool>>(key, message),
+    }
+}
+
+#[derive(Clone)]
+enum HmacInner {
+    Md4(Hmac<Md4>),
+    Md5(Hmac<Md5>),
+    Sha1(Hmac<Sha1>),
+    Sha224(Hmac<Sha224>),
+    Sha256(Hmac<Sha256>),
+    Sha384(Hmac<Sha384>),
+    Sha512(Hmac<Sha512>),
+    Sha512_224(Hmac<Sha512_224>),
+    Sha512_256(Hmac<Sha512_256>),
+    Sha3_224(Hmac<Sha3_224>),
+    Sha3_256(Hmac<Sha3_256>),
+    Sha3_384(Hmac<Sha3_384>),
+    Sha3_512(Hmac<Sha3_512>),
+    Ripemd160(Hmac<Ripemd160>),
+    Ripemd256(Hmac<Ripemd256>),
+    Ripemd320(Hmac<Ripemd320>),
+    Whirlpool(Hmac<Whirlpool>),
+}
+
+/// Incremental HMAC.
+#[derive(Clone)]
+pub struct HmacHasher {
+    inner: HmacInner,
+}
+
+impl HmacHasher {
+    pub fn new(key: &[u8], algo: HashAlgorithm) -> Result<Self> {
+        let inner = match algo {
+            HashAlgorithm::Md4 => HmacInner::Md4(new_mac(key)?),
+            HashAlgorithm::Md5 => HmacInner::Md5(new_mac(key)?),
+            HashAlgorithm::Sha1 => HmacInner::Sha1(new_mac(key)?),
+            HashAlgorithm::Sha224 => HmacInner::Sha224(new_mac(key)?),
+            HashAlgorithm::Sha256 => HmacInner::Sha256(new_mac(key)?),
+            HashAlgorithm::Sha384 => HmacInner::Sha384(new_mac(key)?),
+            HashAlgorithm::Sha512 => HmacInner::Sha512(new_mac(key)?),
+            HashAlgorithm::Sha512_224 => HmacInner::Sha512_224(new_mac(key)?),
+            HashAlgorithm::Sha512_256 => HmacInner::Sha512_256(new_mac(key)?),
+            HashAlgorithm::Sha3_224 => HmacInner::Sha3_224(new_mac(key)?),
+            HashAlgorithm::Sha3_256 => HmacInner::Sha3_256(new_mac(key)?),
+            HashAlgorithm::Sha3_384 => HmacInner::Sha3_384(new_mac(key)?),
+            HashAlgorithm::Sha3_512 => HmacInner::Sha3_512(new_mac(key)?),
+            HashAlgorithm::Ripemd160 => HmacInner::Ripemd160(new_mac(key)?),
+            HashAlgorithm::Ripemd256 => HmacInner::Ripemd256(new_mac(key)?),
+            HashAlgorithm::Ripemd320 => HmacInner::Ripemd320(new_mac(key)?),
+            HashAlgorithm::Whirlpool => HmacInner::Whirlpool(new_mac(key)?),
+        };
+
+        Ok(Self { inner })
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        match &mut self.inner {
+            HmacInner::Md4(mac) => mac.update(input),
+            HmacInner::Md5(mac) => mac.update(input),
+            HmacInner::Sha1(mac) => mac.update(input),
+            HmacInner::Sha224(mac) => mac.update(input),
+            HmacInner::Sha256(mac) => mac.update(input),
+            HmacInner::Sha384(mac) => mac.update(input),
+            HmacInner::Sha512(mac) => mac.update(input),
+            HmacInner::Sha512_224(mac) => mac.update(input),
+            HmacInner::Sha512_256(mac) => mac.update(input),
+            HmacInner::Sha3_224(mac) => mac.update(input),
+            HmacInner::Sha3_256(mac) => mac.update(input),
+            HmacInner::Sha3_384(mac) => mac.update(input),
+            HmacInner::Sha3_512(mac) => mac.update(input),
+            HmacInner::Ripemd160(mac) => mac.update(input),
+            HmacInner::Ripemd256(mac) => mac.update(input),
+            HmacInner::Ripemd320(mac) => mac.update(input),
+            HmacInner::Whirlpool(mac) => mac.update(input),
+        }
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        match self.inner {
+            HmacInner::Md4(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Md5(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha1(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha224(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha256(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha384(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha512(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha512_224(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha512_256(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha3_224(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha3_256(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha3_384(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Sha3_512(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Ripemd160(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Ripemd256(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Ripemd320(mac) => mac.finalize().into_bytes().to_vec(),
+            HmacInner::Whirlpool(mac) => mac.finalize().into_bytes().to_vec(),
+        }
+    }
+}
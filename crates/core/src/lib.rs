@@ -0,0 +1,17 @@
+//! Pure-Rust implementations of every algorithm exposed by `cryptographer.js`.
+//!
+//! Each `*_wasm` crate under `crates/` is a thin `wasm-bindgen` wrapper around
+//! the matching module here, so native services produce exactly the same
+//! bytes as the Node package.
+
+pub mod cipher;
+pub mod dsa;
+mod error;
+pub mod hmac;
+pub mod payments;
+pub mod pha;
+pub mod sha;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use error::{Error, Result};
@@ -0,0 +1,41 @@
+//! Key check values: a few bytes that identify a key without revealing it,
+//! printed next to key components and compared after every key load.
+
+use super::{cmac, encrypt_blocks, KeyAlgorithm};
+use crate::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KcvMethod {
+    /// Leftmost 3 bytes of a zero block encrypted in ECB mode; the
+    /// traditional DES/3DES check value.
+    ZeroBlock,
+    /// Leftmost 5 bytes of the CMAC of a zero block (ANSI X9.24-1:2017), as
+    /// used for AES keys and TR-31 version D.
+    Cmac,
+}
+
+impl KcvMethod {
+    /// Number of bytes kept.
+    fn len(self) -> usize {
+        match self {
+            KcvMethod::ZeroBlock => 3,
+            KcvMethod::Cmac => 5,
+        }
+    }
+}
+
+/// Check value of `key`. CMAC needs a 3DES or AES key; single DES keys only
+/// support [`KcvMethod::ZeroBlock`].
+pub fn kcv(key: &[u8], algo: KeyAlgorithm, method: KcvMethod) -> Result<Vec<u8>> {
+    let zero = vec![0u8; algo.block_size()];
+
+    let mut out = match method {
+        KcvMethod::ZeroBlock => encrypt_blocks(algo, key, &[], &zero)?,
+        KcvMethod::Cmac => cmac(algo, key, &zero)?,
+    };
+
+    out.truncate(method.len());
+    Ok(out)
+}
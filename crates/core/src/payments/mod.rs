@@ -0,0 +1,108 @@
+//! Card-payment primitives: ISO 9564 PIN blocks, key check values and
+//! ANSI X9.143 (TR-31) key blocks, built on the [`des`] and [`aes`] ciphers.
+
+use ::des::{TdesEde2, TdesEde3};
+use cmac::{Cmac, Mac};
+
+use crate::cipher::aes::{self, AesAlgorithm};
+use crate::cipher::des::{self, DesAlgorithm, Padding};
+use crate::{Error, Result};
+
+pub mod kcv;
+pub mod pin_block;
+pub mod tr31;
+
+/// Block cipher family a payment key belongs to; the key length picks DES,
+/// EDE2 or EDE3, or the AES key size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KeyAlgorithm {
+    /// DES (8-byte key) or 3DES (16-byte EDE2 or 24-byte EDE3 key)
+    Tdes,
+    /// AES-128, AES-192 or AES-256
+    Aes,
+}
+
+impl KeyAlgorithm {
+    fn block_size(self) -> usize {
+        match self {
+            KeyAlgorithm::Tdes => 8,
+            KeyAlgorithm::Aes => 16,
+        }
+    }
+
+    fn check_key(self, key: &[u8]) -> Result<()> {
+        let ok = match self {
+            KeyAlgorithm::Tdes => matches!(key.len(), 8 | 16 | 24),
+            KeyAlgorithm::Aes => matches!(key.len(), 16 | 24 | 32),
+        };
+        if ok {
+            return Ok(());
+        }
+        Err(Error::InvalidLength(match self {
+            KeyAlgorithm::Tdes => format!("3DES key must be 8, 16 or 24 bytes (got {})", key.len()),
+            KeyAlgorithm::Aes => format!("AES key must be 16, 24 or 32 bytes (got {})", key.len()),
+        }))
+    }
+}
+
+enum Block {
+    Des(DesAlgorithm),
+    Aes(AesAlgorithm),
+}
+
+fn block_algorithm(algo: KeyAlgorithm, key: &[u8], cbc: bool) -> Result<Block> {
+    algo.check_key(key)?;
+    Ok(match (algo, key.len(), cbc) {
+        (KeyAlgorithm::Tdes, 8, false) => Block::Des(DesAlgorithm::DesEcb),
+        (KeyAlgorithm::Tdes, 16, false) => Block::Des(DesAlgorithm::TdesEde2Ecb),
+        (KeyAlgorithm::Tdes, _, false) => Block::Des(DesAlgorithm::TdesEcb),
+        (KeyAlgorithm::Tdes, 8, true) => Block::Des(DesAlgorithm::DesCbc),
+        (KeyAlgorithm::Tdes, 16, true) => Block::Des(DesAlgorithm::TdesEde2Cbc),
+        (KeyAlgorithm::Tdes, _, true) => Block::Des(DesAlgorithm::TdesCbc),
+        (KeyAlgorithm::Aes, 16, false) => Block::Aes(AesAlgorithm::Aes128Ecb),
+        (KeyAlgorithm::Aes, 24, false) => Block::Aes(AesAlgorithm::Aes192Ecb),
+        (KeyAlgorithm::Aes, _, false) => Block::Aes(AesAlgorithm::Aes256Ecb),
+        (KeyAlgorithm::Aes, 16, true) => Block::Aes(AesAlgorithm::Aes128Cbc),
+        (KeyAlgorithm::Aes, 24, true) => Block::Aes(AesAlgorithm::Aes192Cbc),
+        (KeyAlgorithm::Aes, _, true) => Block::Aes(AesAlgorithm::Aes256Cbc),
+    })
+}
+
+/// ECB (empty `iv`) or CBC over whole blocks, without padding.
+fn encrypt_blocks(algo: KeyAlgorithm, key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    match block_algorithm(algo, key, !iv.is_empty())? {
+        Block::Des(algo) => des::encrypt_padded(data, key, iv, Padding::NoPadding, algo),
+        Block::Aes(algo) => aes::encrypt_padded(data, key, iv, aes::Padding::NoPadding, algo),
+    }
+}
+
+fn decrypt_blocks(algo: KeyAlgorithm, key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    match block_algorithm(algo, key, !iv.is_empty())? {
+        Block::Des(algo) => des::decrypt_padded(data, key, iv, Padding::NoPadding, algo),
+        Block::Aes(algo) => aes::decrypt_padded(data, key, iv, aes::Padding::NoPadding, algo),
+    }
+}
+
+fn cmac_with<C: Mac + cmac::digest::KeyInit>(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = <C as Mac>::new_from_slice(key).map_err(|e| Error::InvalidKey(e.to_string()))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// NIST SP 800-38B CMAC. Single DES is not offered, as no payment scheme
+/// uses it.
+fn cmac(algo: KeyAlgorithm, key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    algo.check_key(key)?;
+    match (algo, key.len()) {
+        (KeyAlgorithm::Tdes, 8) => Err(Error::InvalidLength(
+            "3DES CMAC key must be 16 or 24 bytes (got 8)".into(),
+        )),
+        (KeyAlgorithm::Tdes, 16) => cmac_with::<Cmac<TdesEde2>>(key, data),
+        (KeyAlgorithm::Tdes, _) => cmac_with::<Cmac<TdesEde3>>(key, data),
+        (KeyAlgorithm::Aes, 16) => cmac_with::<Cmac<::aes::Aes128>>(key, data),
+        (KeyAlgorithm::Aes, 24) => cmac_with::<Cmac<::aes::Aes192>>(key, data),
+        (KeyAlgorithm::Aes, _) => cmac_with::<Cmac<::aes::Aes256>>(key, data),
+    }
+}
@@ -0,0 +1,225 @@
+//! ISO 9564-1 PIN blocks, formats 0, 1, 3 and 4.
+//!
+//! Formats 0, 1 and 3 are 8-byte blocks, enciphered with DES/3DES; format 0
+//! and 3 bind the PIN to the 12 rightmost PAN digits before the check digit.
+//! Format 4 is a 16-byte AES block that binds the whole PAN and is only
+//! defined in enciphered form.
+
+use rand_core::{OsRng, RngCore};
+
+use super::{decrypt_blocks, encrypt_blocks, KeyAlgorithm};
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PinBlockFormat {
+    /// ISO-0 (ANSI X9.8): PIN padded with `F`, XORed with the PAN
+    Iso0,
+    /// ISO-1: PIN padded with random digits, no PAN
+    Iso1,
+    /// ISO-3: PIN padded with random `A`-`F`, XORed with the PAN
+    Iso3,
+    /// ISO-4: 16-byte AES block, enciphered twice around the PAN
+    Iso4,
+}
+
+impl PinBlockFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            PinBlockFormat::Iso0 => "ISO format 0",
+            PinBlockFormat::Iso1 => "ISO format 1",
+            PinBlockFormat::Iso3 => "ISO format 3",
+            PinBlockFormat::Iso4 => "ISO format 4",
+        }
+    }
+
+    fn control(self) -> u8 {
+        match self {
+            PinBlockFormat::Iso0 => 0,
+            PinBlockFormat::Iso1 => 1,
+            PinBlockFormat::Iso3 => 3,
+            PinBlockFormat::Iso4 => 4,
+        }
+    }
+
+    /// Whether `nibble` may follow the PIN digits in the first 8 bytes.
+    fn valid_fill(self, nibble: u8) -> bool {
+        match self {
+            PinBlockFormat::Iso0 => nibble == 0xf,
+            PinBlockFormat::Iso1 => true,
+            PinBlockFormat::Iso3 => nibble >= 0xa,
+            PinBlockFormat::Iso4 => nibble == 0xa,
+        }
+    }
+}
+
+fn digits(value: &str, what: &str, lengths: std::ops::RangeInclusive<usize>) -> Result<Vec<u8>> {
+    if !lengths.contains(&value.len()) || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidParameter(format!(
+            "{} must be {} to {} decimal digits",
+            what,
+            lengths.start(),
+            lengths.end()
+        )));
+    }
+    Ok(value.bytes().map(|b| b - b'0').collect())
+}
+
+fn pack(nibbles: &[u8]) -> Vec<u8> {
+    nibbles.chunks(2).map(|p| p[0] << 4 | p[1]).collect()
+}
+
+fn unpack(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0xf]).collect()
+}
+
+/// First 8 bytes of the plaintext PIN field: control nibble, PIN length,
+/// PIN digits and the format's fill.
+fn pin_field(pin: &str, format: PinBlockFormat) -> Result<Vec<u8>> {
+    let pin = digits(pin, "PIN", 4..=12)?;
+
+    let mut random = [0u8; 16];
+    OsRng.fill_bytes(&mut random);
+
+    let mut nibbles = vec![format.control(), pin.len() as u8];
+    nibbles.extend_from_slice(&pin);
+    for r in &random[nibbles.len()..] {
+        nibbles.push(match format {
+            PinBlockFormat::Iso0 => 0xf,
+            PinBlockFormat::Iso1 => r & 0xf,
+            PinBlockFormat::Iso3 => 0xa + r % 6,
+            PinBlockFormat::Iso4 => 0xa,
+        });
+    }
+    Ok(pack(&nibbles))
+}
+
+fn parse_pin_field(field: &[u8], format: PinBlockFormat) -> Result<String> {
+    let invalid = || Error::InvalidParameter(format!("Not a valid {} PIN block", format.name()));
+    let nibbles = unpack(&field[..8]);
+
+    let len = nibbles[1] as usize;
+    if nibbles[0] != format.control() || !(4..=12).contains(&len) {
+        return Err(invalid());
+    }
+    let (pin, fill) = nibbles[2..].split_at(len);
+    if !pin.iter().all(|&n| n <= 9) || !fill.iter().all(|&n| format.valid_fill(n)) {
+        return Err(invalid());
+    }
+    Ok(pin.iter().map(|n| char::from(b'0' + n)).collect())
+}
+
+/// `0000` followed by the 12 rightmost PAN digits before the check digit,
+/// left-padded with zeros when the PAN is shorter.
+fn pan_field(pan: &str) -> Result<Vec<u8>> {
+    let pan = digits(pan, "PAN", 1..=19)?;
+    let account = &pan[..pan.len() - 1];
+
+    let mut nibbles = vec![0u8; 16];
+    let take = account.len().min(12);
+    nibbles[16 - take..].copy_from_slice(&account[account.len() - take..]);
+    Ok(pack(&nibbles))
+}
+
+/// Format 4 PAN field: the number of PAN digits beyond 12, then the whole
+/// PAN (left-padded with zeros to 12 digits), then zeros.
+fn pan_field_iso4(pan: &str) -> Result<Vec<u8>> {
+    let pan = digits(pan, "PAN", 1..=19)?;
+
+    let mut nibbles = vec![0u8; 32];
+    nibbles[0] = pan.len().saturating_sub(12) as u8;
+    let start = 1 + 12usize.saturating_sub(pan.len());
+    nibbles[start..start + pan.len()].copy_from_slice(&pan);
+    Ok(pack(&nibbles))
+}
+
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x ^= y;
+    }
+}
+
+/// Clear 8-byte PIN block in format 0, 1 or 3. `pan` is ignored by
+/// format 1.
+pub fn encode(pin: &str, pan: &str, format: PinBlockFormat) -> Result<Vec<u8>> {
+    if format == PinBlockFormat::Iso4 {
+        return Err(Error::InvalidParameter(
+            "ISO format 4 PIN blocks only exist enciphered; use encrypt".into(),
+        ));
+    }
+
+    let mut block = pin_field(pin, format)?;
+    if format != PinBlockFormat::Iso1 {
+        xor(&mut block, &pan_field(pan)?);
+    }
+    Ok(block)
+}
+
+/// Recovers the PIN from a clear format 0, 1 or 3 block, checking the
+/// control nibble, the PIN length and the fill.
+pub fn decode(block: &[u8], pan: &str, format: PinBlockFormat) -> Result<String> {
+    if format == PinBlockFormat::Iso4 {
+        return Err(Error::InvalidParameter(
+            "ISO format 4 PIN blocks only exist enciphered; use decrypt".into(),
+        ));
+    }
+    if block.len() != 8 {
+        return Err(Error::InvalidLength(format!(
+            "{} PIN block must be 8 bytes (got {})",
+            format.name(),
+            block.len()
+        )));
+    }
+
+    let mut field = block.to_vec();
+    if format != PinBlockFormat::Iso1 {
+        xor(&mut field, &pan_field(pan)?);
+    }
+    parse_pin_field(&field, format)
+}
+
+/// Enciphered PIN block: formats 0, 1 and 3 under a DES/3DES key (8, 16 or
+/// 24 bytes), format 4 under an AES key.
+pub fn encrypt(pin: &str, pan: &str, key: &[u8], format: PinBlockFormat) -> Result<Vec<u8>> {
+    if format != PinBlockFormat::Iso4 {
+        let block = encode(pin, pan, format)?;
+        return encrypt_blocks(KeyAlgorithm::Tdes, key, &[], &block);
+    }
+
+    KeyAlgorithm::Aes.check_key(key)?;
+    let mut field = pin_field(pin, format)?;
+    let mut random = [0u8; 8];
+    OsRng.fill_bytes(&mut random);
+    field.extend_from_slice(&random);
+
+    let mut intermediate = encrypt_blocks(KeyAlgorithm::Aes, key, &[], &field)?;
+    xor(&mut intermediate, &pan_field_iso4(pan)?);
+    encrypt_blocks(KeyAlgorithm::Aes, key, &[], &intermediate)
+}
+
+/// Inverse of [`encrypt`].
+pub fn decrypt(block: &[u8], pan: &str, key: &[u8], format: PinBlockFormat) -> Result<String> {
+    if format != PinBlockFormat::Iso4 {
+        if block.len() != 8 {
+            return Err(Error::InvalidLength(format!(
+                "{} PIN block must be 8 bytes (got {})",
+                format.name(),
+                block.len()
+            )));
+        }
+        let clear = decrypt_blocks(KeyAlgorithm::Tdes, key, &[], block)?;
+        return decode(&clear, pan, format);
+    }
+
+    if block.len() != 16 {
+        return Err(Error::InvalidLength(format!(
+            "ISO format 4 PIN block must be 16 bytes (got {})",
+            block.len()
+        )));
+    }
+    let mut intermediate = decrypt_blocks(KeyAlgorithm::Aes, key, &[], block)?;
+    xor(&mut intermediate, &pan_field_iso4(pan)?);
+    let field = decrypt_blocks(KeyAlgorithm::Aes, key, &[], &intermediate)?;
+    parse_pin_field(&field, format)
+}
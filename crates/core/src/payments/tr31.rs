@@ -0,0 +1,342 @@
+//! ANSI X9.143 (ASC X9 TR-31) key blocks, versions B and D.
+//!
+//! A key block is printable ASCII: a 16-character header, optional blocks,
+//! the hex-encoded encrypted key data and the hex-encoded MAC. Version B
+//! derives its encryption and MAC keys from a 3DES key block protection key
+//! (KBPK) with 3DES-CMAC, version D from an AES KBPK with AES-CMAC. The
+//! MAC covers the header and the clear key data and doubles as the CBC IV.
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand_core::{OsRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use super::{cmac, decrypt_blocks, encrypt_blocks, KeyAlgorithm};
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KeyBlockVersion {
+    /// `B`: 3DES key derivation binding method, 8-byte MAC
+    B,
+    /// `D`: AES key derivation binding method, 16-byte MAC
+    D,
+}
+
+impl KeyBlockVersion {
+    pub fn id(self) -> char {
+        match self {
+            KeyBlockVersion::B => 'B',
+            KeyBlockVersion::D => 'D',
+        }
+    }
+
+    fn algorithm(self) -> KeyAlgorithm {
+        match self {
+            KeyBlockVersion::B => KeyAlgorithm::Tdes,
+            KeyBlockVersion::D => KeyAlgorithm::Aes,
+        }
+    }
+
+    fn block_size(self) -> usize {
+        self.algorithm().block_size()
+    }
+
+    fn mac_len(self) -> usize {
+        self.block_size()
+    }
+}
+
+impl fmt::Display for KeyBlockVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// Optional header block, e.g. `KS` with a key set identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionalBlock {
+    /// Two-character identifier
+    pub id: String,
+    /// Printable ASCII contents
+    pub data: String,
+}
+
+/// Key block header. The key block length is computed when wrapping, and
+/// `PB` padding blocks are dropped when parsing and added back as needed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBlockHeader {
+    pub version: KeyBlockVersion,
+    /// Two characters, e.g. `P0` (PIN encryption) or `K0` (key encryption)
+    pub key_usage: String,
+    /// Algorithm of the wrapped key: `T` (3DES), `A` (AES), ...
+    pub algorithm: char,
+    /// `E` encrypt only, `D` decrypt only, `B` both, `N` no restrictions, ...
+    pub mode_of_use: char,
+    /// Two characters; `00` when versioning is not used
+    pub key_version: String,
+    /// `E` exportable, `N` not exportable, `S` sensitive
+    pub exportability: char,
+    pub optional_blocks: Vec<OptionalBlock>,
+}
+
+fn invalid(what: &str) -> Error {
+    Error::InvalidParameter(format!("Invalid TR-31 key block: {}", what))
+}
+
+fn printable(s: &str) -> bool {
+    s.bytes().all(|b| (0x20..0x7f).contains(&b))
+}
+
+/// Parses a fixed-width numeric field. `str::parse` alone would also take a
+/// leading `+`.
+fn number(field: &str, radix: u32) -> Option<usize> {
+    if !field.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    usize::from_str_radix(field, radix).ok()
+}
+
+impl KeyBlockHeader {
+    /// Parses a header, followed or not by the rest of the key block. The
+    /// key block length field is not checked here. Returns the header and
+    /// its length in characters, padding blocks included.
+    fn parse_prefix(s: &str) -> Result<(Self, usize)> {
+        if s.len() < 16 || !printable(s) {
+            return Err(invalid(
+                "header must be at least 16 printable ASCII characters",
+            ));
+        }
+        let version = match &s[0..1] {
+            "B" => KeyBlockVersion::B,
+            "D" => KeyBlockVersion::D,
+            v => return Err(invalid(&format!("unsupported version '{}'", v))),
+        };
+        let count = number(&s[12..14], 10)
+            .ok_or_else(|| invalid("number of optional blocks is not a number"))?;
+
+        let mut optional_blocks = Vec::with_capacity(count);
+        let mut pos = 16;
+        for _ in 0..count {
+            let len = s
+                .get(pos + 2..pos + 4)
+                .and_then(|l| number(l, 16))
+                .filter(|&l| l >= 4 && pos + l <= s.len())
+                .ok_or_else(|| invalid("malformed optional block"))?;
+            let (id, data) = (&s[pos..pos + 2], &s[pos + 4..pos + len]);
+            if id != "PB" {
+                optional_blocks.push(OptionalBlock {
+                    id: id.to_string(),
+                    data: data.to_string(),
+                });
+            }
+            pos += len;
+        }
+
+        let b = s.as_bytes();
+        let header = Self {
+            version,
+            key_usage: s[5..7].to_string(),
+            algorithm: char::from(b[7]),
+            mode_of_use: char::from(b[8]),
+            key_version: s[9..11].to_string(),
+            exportability: char::from(b[11]),
+            optional_blocks,
+        };
+        Ok((header, pos))
+    }
+
+    /// Fixed header and optional blocks, padded with a `PB` block to a
+    /// multiple of the cipher block size.
+    fn render(&self, body_len: usize) -> Result<String> {
+        let fields = [&self.key_usage, &self.key_version];
+        if fields.iter().any(|f| f.len() != 2 || !printable(f))
+            || ![self.algorithm, self.mode_of_use, self.exportability]
+                .iter()
+                .all(|c| (' '..='~').contains(c))
+        {
+            return Err(invalid(
+                "header fields must be printable ASCII of the right length",
+            ));
+        }
+
+        let mut blocks = String::new();
+        let mut count = 0;
+        for block in self.optional_blocks.iter().filter(|b| b.id != "PB") {
+            let len = 4 + block.data.len();
+            if block.id.len() != 2 || !printable(&block.id) || !printable(&block.data) || len > 0xff
+            {
+                return Err(invalid("malformed optional block"));
+            }
+            blocks.push_str(&format!("{}{:02X}{}", block.id, len, block.data));
+            count += 1;
+        }
+        let bs = self.version.block_size();
+        if count > 0 && !(16 + blocks.len()).is_multiple_of(bs) {
+            let pad = (bs - (16 + blocks.len() + 4) % bs) % bs;
+            blocks.push_str(&format!("PB{:02X}{}", 4 + pad, "0".repeat(pad)));
+            count += 1;
+        }
+        if count > 99 {
+            return Err(invalid("too many optional blocks"));
+        }
+
+        let total = 16 + blocks.len() + body_len;
+        if total > 9999 {
+            return Err(invalid("key block longer than 9999 characters"));
+        }
+        Ok(format!(
+            "{}{:04}{}{}{}{}{}{:02}00{}",
+            self.version.id(),
+            total,
+            self.key_usage,
+            self.algorithm,
+            self.mode_of_use,
+            self.key_version,
+            self.exportability,
+            count,
+            blocks
+        ))
+    }
+}
+
+impl FromStr for KeyBlockHeader {
+    type Err = Error;
+
+    /// Parses a header on its own or at the start of a key block; the key
+    /// block length field may hold any digits (e.g. `0000`).
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_prefix(s).map(|(header, _)| header)
+    }
+}
+
+/// Derives the key block encryption key (`usage` 0) or MAC key (`usage` 1)
+/// from the KBPK with CMAC in counter mode.
+fn derive(version: KeyBlockVersion, kbpk: &[u8], usage: u8) -> Result<Zeroizing<Vec<u8>>> {
+    let algorithm_id = match (version, kbpk.len()) {
+        (KeyBlockVersion::B, 16) => 0,
+        (KeyBlockVersion::B, _) => 1,
+        (KeyBlockVersion::D, 16) => 2,
+        (KeyBlockVersion::D, 24) => 3,
+        (KeyBlockVersion::D, _) => 4,
+    };
+    let bits = (kbpk.len() * 8) as u16;
+
+    // Sized up front so `extend_from_slice` never reallocates and leaves a
+    // stray copy of the key behind.
+    let mut key = Zeroizing::new(Vec::with_capacity(kbpk.len() + 16));
+    let mut counter = 1u8;
+    while key.len() < kbpk.len() {
+        let input = [
+            counter,
+            0,
+            usage,
+            0,
+            0,
+            algorithm_id,
+            (bits >> 8) as u8,
+            bits as u8,
+        ];
+        key.extend_from_slice(&Zeroizing::new(cmac(version.algorithm(), kbpk, &input)?));
+        counter += 1;
+    }
+    key.truncate(kbpk.len());
+    Ok(key)
+}
+
+fn check_kbpk(version: KeyBlockVersion, kbpk: &[u8]) -> Result<()> {
+    let ok = match version {
+        KeyBlockVersion::B => matches!(kbpk.len(), 16 | 24),
+        KeyBlockVersion::D => matches!(kbpk.len(), 16 | 24 | 32),
+    };
+    if ok {
+        return Ok(());
+    }
+    Err(Error::InvalidLength(format!(
+        "TR-31 version {} KBPK must be {} bytes (got {})",
+        version,
+        match version {
+            KeyBlockVersion::B => "16 or 24",
+            KeyBlockVersion::D => "16, 24 or 32",
+        },
+        kbpk.len()
+    )))
+}
+
+/// Wraps `key` under `kbpk`. The key data (a 2-byte bit length, the key and
+/// random padding to a whole block) is MACed together with the header, then
+/// encrypted in CBC mode with the MAC as IV.
+pub fn wrap(kbpk: &[u8], header: &KeyBlockHeader, key: &[u8]) -> Result<String> {
+    let version = header.version;
+    check_kbpk(version, kbpk)?;
+    if key.is_empty() || key.len() > 0x1fff {
+        return Err(Error::InvalidLength(format!(
+            "TR-31 wrapped key must be 1 to 8191 bytes (got {})",
+            key.len()
+        )));
+    }
+
+    let bs = version.block_size();
+    let data_len = (2 + key.len()).div_ceil(bs) * bs;
+    let mut key_data = Zeroizing::new(Vec::with_capacity(data_len));
+    key_data.extend_from_slice(&((key.len() * 8) as u16).to_be_bytes());
+    key_data.extend_from_slice(key);
+    key_data.resize(data_len, 0);
+    OsRng.fill_bytes(&mut key_data[2 + key.len()..]);
+
+    let header = header.render(2 * (data_len + version.mac_len()))?;
+    let kbek = derive(version, kbpk, 0)?;
+    let kbmk = derive(version, kbpk, 1)?;
+
+    let mac_input = Zeroizing::new([header.as_bytes(), &key_data].concat());
+    let mac = cmac(version.algorithm(), &kbmk, &mac_input)?;
+    let encrypted = encrypt_blocks(version.algorithm(), &kbek, &mac, &key_data)?;
+
+    Ok(format!(
+        "{}{}{}",
+        header,
+        hex::encode_upper(encrypted),
+        hex::encode_upper(mac)
+    ))
+}
+
+/// Verifies and unwraps a version B or D key block. A wrong KBPK and a
+/// tampered block both fail the MAC check with the same error.
+pub fn unwrap(kbpk: &[u8], key_block: &str) -> Result<(KeyBlockHeader, Vec<u8>)> {
+    let (header, header_len) = KeyBlockHeader::parse_prefix(key_block)?;
+    let version = header.version;
+    check_kbpk(version, kbpk)?;
+
+    if number(&key_block[1..5], 10) != Some(key_block.len()) {
+        return Err(invalid("key block length field does not match"));
+    }
+    let body = hex::decode(&key_block[header_len..])
+        .map_err(|_| invalid("key data and MAC must be hex"))?;
+    let mac_len = version.mac_len();
+    if body.len() < mac_len + version.block_size()
+        || (body.len() - mac_len) % version.block_size() != 0
+    {
+        return Err(invalid("key data is not a whole number of blocks"));
+    }
+    let (encrypted, mac) = body.split_at(body.len() - mac_len);
+
+    let kbek = derive(version, kbpk, 0)?;
+    let kbmk = derive(version, kbpk, 1)?;
+    let key_data = Zeroizing::new(decrypt_blocks(version.algorithm(), &kbek, mac, encrypted)?);
+    let mac_input = Zeroizing::new([&key_block.as_bytes()[..header_len], &key_data[..]].concat());
+    let expected = cmac(version.algorithm(), &kbmk, &mac_input)?;
+    if !bool::from(expected.ct_eq(mac)) {
+        return Err(Error::Decryption(
+            "TR-31 key block MAC verification failed".into(),
+        ));
+    }
+
+    let bits = u16::from_be_bytes([key_data[0], key_data[1]]) as usize;
+    if bits == 0 || !bits.is_multiple_of(8) || 2 + bits / 8 > key_data.len() {
+        return Err(invalid("key length does not fit the key data"));
+    }
+    Ok((header, key_data[2..2 + bits / 8].to_vec()))
+}
@@ -0,0 +1,64 @@
+use argon2::password_hash::SaltString;
+use argon2::{Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version};
+
+use crate::{Error, Result};
+
+/// Argon2 parameters. Unset fields fall back to the same defaults as the
+/// Node package (Argon2id, t=3, m=64 MiB, p=4, 32-byte output).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HashOptions {
+    /// Salt encoded as unpadded base64 (PHC `B64`). Required.
+    pub salt: Option<String>,
+    pub time_cost: Option<u32>,
+    pub memory_cost: Option<u32>,
+    pub parallelism: Option<u32>,
+    /// `"id"`, `"i"`, `"d"` or the `argon2`-prefixed forms.
+    pub variant: Option<String>,
+    pub key_length: Option<u32>,
+}
+
+/// Hashes `password` and returns the PHC string.
+pub fn hash_password(password: &[u8], opts: &HashOptions) -> Result<String> {
+    let salt = match &opts.salt {
+        Some(s) => SaltString::from_b64(s)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid salt: {}", e)))?,
+        None => {
+            return Err(Error::InvalidParameter(
+                "Salt is required. Please generate in JS".into(),
+            ))
+        }
+    };
+
+    let alg = match opts.variant.as_deref() {
+        Some("i") | Some("argon2i") => Algorithm::Argon2i,
+        Some("d") | Some("argon2d") => Algorithm::Argon2d,
+        _ => Algorithm::Argon2id,
+    };
+
+    let params = Params::new(
+        opts.memory_cost.unwrap_or(65536),
+        opts.time_cost.unwrap_or(3),
+        opts.parallelism.unwrap_or(4),
+        Some(opts.key_length.unwrap_or(32) as usize),
+    )
+    .map_err(|e| Error::InvalidParameter(format!("Invalid params: {}", e)))?;
+
+    let argon2 = Argon2::new(alg, Version::V0x13, params);
+
+    let hash = argon2
+        .hash_password(password, &salt)
+        .map_err(|e| Error::Operation(format!("Hash error: {}", e)))?;
+
+    Ok(hash.to_string())
+}
+
+/// Checks `password` against a PHC string produced by [`hash_password`].
+pub fn verify_password(password: &[u8], hashed_password: &str) -> Result<bool> {
+    let parsed_hash = PasswordHash::new(hashed_password)
+        .map_err(|e| Error::InvalidParameter(format!("Parse hash error: {}", e)))?;
+
+    Ok(Argon2::default()
+        .verify_password(password, &parsed_hash)
+        .is_ok())
+}
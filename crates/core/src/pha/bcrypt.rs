@@ -0,0 +1,26 @@
+use bcrypt::DEFAULT_COST;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HashOptions {
+    /// Work factor; [`bcrypt::DEFAULT_COST`] when unset.
+    pub cost: Option<u32>,
+}
+
+fn password_str(password: &[u8]) -> Result<&str> {
+    std::str::from_utf8(password)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid password: {}", e)))
+}
+
+/// Hashes a UTF-8 `password` with a random salt and returns the `$2b$` string.
+pub fn hash_password(password: &[u8], opts: &HashOptions) -> Result<String> {
+    bcrypt::hash(password_str(password)?, opts.cost.unwrap_or(DEFAULT_COST))
+        .map_err(|e| Error::Operation(format!("Error hashing password: {}", e)))
+}
+
+pub fn verify_password(password: &[u8], hashed_password: &str) -> Result<bool> {
+    bcrypt::verify(password_str(password)?, hashed_password)
+        .map_err(|e| Error::Operation(format!("Error verifying password: {}", e)))
+}
@@ -0,0 +1,5 @@
+//! Password hashing and key derivation.
+
+pub mod argon2;
+pub mod bcrypt;
+pub mod pbkdf2;
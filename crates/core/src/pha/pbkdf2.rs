@@ -0,0 +1,60 @@
+use base64::{engine::general_purpose, Engine as _};
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha256;
+
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HashOptions {
+    /// Salt as standard base64. Required.
+    pub salt: Option<String>,
+    /// Defaults to 100 000.
+    pub iterations: Option<u32>,
+    /// Defaults to 32 bytes.
+    pub key_length: Option<usize>,
+}
+
+/// PBKDF2-HMAC-SHA256 of `password`, returned as standard base64.
+pub fn hash_password(password: &[u8], opts: &HashOptions) -> Result<String> {
+    let salt_bytes = match &opts.salt {
+        Some(s) => general_purpose::STANDARD
+            .decode(s)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid salt (base64): {}", e)))?,
+        None => {
+            return Err(Error::InvalidParameter(
+                "Salt is required. Please generate in JS".into(),
+            ))
+        }
+    };
+
+    let iterations = opts.iterations.unwrap_or(100_000);
+
+    let mut derived_key = vec![0u8; opts.key_length.unwrap_or(32)];
+
+    pbkdf2_hmac::<Sha256>(password, &salt_bytes, iterations, &mut derived_key);
+
+    Ok(general_purpose::STANDARD.encode(&derived_key))
+}
+
+/// Recomputes the key for `password` and compares it to `encoded_hash`.
+pub fn verify_password(
+    password: &[u8],
+    encoded_hash: &str,
+    salt: &str,
+    iterations: u32,
+) -> Result<bool> {
+    let salt_bytes = general_purpose::STANDARD
+        .decode(salt)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid salt: {}", e)))?;
+
+    let expected_hash = general_purpose::STANDARD
+        .decode(encoded_hash)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid encoded hash: {}", e)))?;
+
+    let mut derived_key = vec![0u8; expected_hash.len()];
+
+    pbkdf2_hmac::<Sha256>(password, &salt_bytes, iterations, &mut derived_key);
+
+    Ok(derived_key == expected_hash)
+}
@@ -0,0 +1,87 @@
+//! Ascon-Hash256 and Ascon-XOF128 (NIST SP 800-232).
+
+use ascon_hash::{AsconHash256, AsconXof128, Digest, ExtendableOutput, Update, XofReader};
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum AsconType {
+    /// Ascon-Hash256, a 32-byte digest.
+    Hash256,
+    /// Ascon-XOF128, any output length.
+    Xof128,
+}
+
+fn read_xof(xof: AsconXof128, len: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; len];
+
+    xof.finalize_xof().read(&mut buf);
+
+    buf
+}
+
+fn xof_length(hash_length: Option<usize>) -> Result<usize> {
+    hash_length.ok_or_else(|| Error::InvalidParameter("Ascon-XOF128 requires hash_length".into()))
+}
+
+/// Ascon digest of `input`. Ascon-XOF128 requires `hash_length`;
+/// Ascon-Hash256 ignores it.
+pub fn hash(input: &[u8], algo: AsconType, hash_length: Option<usize>) -> Result<Vec<u8>> {
+    let output = match algo {
+        AsconType::Hash256 => AsconHash256::digest(input).to_vec(),
+        AsconType::Xof128 => {
+            let len = xof_length(hash_length)?;
+
+            let mut hasher = AsconXof128::default();
+
+            hasher.update(input);
+
+            read_xof(hasher, len)
+        }
+    };
+
+    Ok(output)
+}
+
+#[derive(Clone)]
+enum AsconImpl {
+    Hash256(AsconHash256),
+    Xof128(AsconXof128),
+}
+
+/// Incremental Ascon-Hash256/Ascon-XOF128.
+#[derive(Clone)]
+pub struct AsconHasher {
+    inner: AsconImpl,
+}
+
+impl AsconHasher {
+    pub fn new(algo: AsconType) -> Self {
+        let inner = match algo {
+            AsconType::Hash256 => AsconImpl::Hash256(AsconHash256::new()),
+            AsconType::Xof128 => AsconImpl::Xof128(AsconXof128::default()),
+        };
+
+        Self { inner }
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        match &mut self.inner {
+            AsconImpl::Hash256(h) => Digest::update(h, input),
+            AsconImpl::Xof128(h) => h.update(input),
+        }
+    }
+
+    /// Finishes the digest. `hash_length` is required for Ascon-XOF128 and
+    /// ignored for Ascon-Hash256.
+    pub fn finalize(self, hash_length: Option<usize>) -> Result<Vec<u8>> {
+        let output = match self.inner {
+            AsconImpl::Hash256(h) => h.finalize().to_vec(),
+            AsconImpl::Xof128(h) => read_xof(h, xof_length(hash_length)?),
+        };
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,56 @@
+use blake2::{Blake2b512, Blake2s256, Digest};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Blake2Type {
+    /// BLAKE2b with a 64-byte digest.
+    Blake2b,
+    /// BLAKE2s with a 32-byte digest.
+    Blake2s,
+}
+
+/// BLAKE2 digest of `input`.
+pub fn hash(input: &[u8], algo: Blake2Type) -> Vec<u8> {
+    match algo {
+        Blake2Type::Blake2b => Blake2b512::digest(input).to_vec(),
+        Blake2Type::Blake2s => Blake2s256::digest(input).to_vec(),
+    }
+}
+
+#[derive(Clone)]
+enum Blake2Impl {
+    Blake2b(Blake2b512),
+    Blake2s(Blake2s256),
+}
+
+/// Incremental BLAKE2b/BLAKE2s.
+#[derive(Clone)]
+pub struct Blake2Hasher {
+    inner: Blake2Impl,
+}
+
+impl Blake2Hasher {
+    pub fn new(algo: Blake2Type) -> Self {
+        let inner = match algo {
+            Blake2Type::Blake2b => Blake2Impl::Blake2b(Blake2b512::new()),
+            Blake2Type::Blake2s => Blake2Impl::Blake2s(Blake2s256::new()),
+        };
+
+        Self { inner }
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        match &mut self.inner {
+            Blake2Impl::Blake2b(h) => h.update(input),
+            Blake2Impl::Blake2s(h) => h.update(input),
+        }
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        match self.inner {
+            Blake2Impl::Blake2b(h) => h.finalize().to_vec(),
+            Blake2Impl::Blake2s(h) => h.finalize().to_vec(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use blake3::Hasher;
+
+use crate::{Error, Result};
+
+/// Largest output `hash` and [`Blake3Hasher::finalize_xof`] will produce.
+pub const MAX_HASH_LENGTH: usize = 1024;
+
+/// Options shared by one-shot and streaming BLAKE3.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HashOptions {
+    /// 32-byte key for keyed hashing.
+    pub keyed: Option<Vec<u8>>,
+    /// Context string for key derivation mode.
+    pub derive_key: Option<String>,
+    /// Extendable output length; the default 32-byte digest otherwise.
+    pub hash_length: Option<usize>,
+}
+
+fn new_hasher(opts: &HashOptions) -> Result<Hasher> {
+    match (&opts.keyed, &opts.derive_key) {
+        (Some(key), _) if key.len() == 32 => {
+            let key: [u8; 32] = key[..].try_into().expect("length checked");
+            Ok(Hasher::new_keyed(&key))
+        }
+        (_, Some(context)) if !context.is_empty() => Ok(Hasher::new_derive_key(context)),
+        (Some(_), _) => Err(Error::InvalidLength("Key must be 32 bytes".into())),
+        (_, Some(_)) => Err(Error::InvalidParameter(
+            "Derive key cannot be empty".into(),
+        )),
+        _ => Ok(Hasher::new()),
+    }
+}
+
+fn read_xof(hasher: &Hasher, length: usize) -> Result<Vec<u8>> {
+    if length > MAX_HASH_LENGTH {
+        return Err(Error::InvalidParameter(
+            "Hash length must be <= 1024".into(),
+        ));
+    }
+
+    let mut buf = vec![0u8; length];
+
+    hasher.finalize_xof().fill(&mut buf);
+
+    Ok(buf)
+}
+
+/// BLAKE3 digest of `input` in plain, keyed or derive-key mode.
+pub fn hash(input: &[u8], opts: &HashOptions) -> Result<Vec<u8>> {
+    let mut hasher = new_hasher(opts)?;
+
+    hasher.update(input);
+
+    match opts.hash_length {
+        Some(len) => read_xof(&hasher, len),
+        None => Ok(hasher.finalize().as_bytes().to_vec()),
+    }
+}
+
+/// Incremental BLAKE3. `hash_length` in the options is ignored here; use
+/// [`Blake3Hasher::finalize_xof`] for extended output.
+#[derive(Clone)]
+pub struct Blake3Hasher {
+    inner: Hasher,
+}
+
+impl Blake3Hasher {
+    pub fn new(opts: &HashOptions) -> Result<Self> {
+        Ok(Self {
+            inner: new_hasher(opts)?,
+        })
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().as_bytes().to_vec()
+    }
+
+    pub fn finalize_xof(self, length: usize) -> Result<Vec<u8>> {
+        read_xof(&self.inner, length)
+    }
+}
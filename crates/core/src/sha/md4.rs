@@ -0,0 +1,47 @@
+use md4::{Digest, Md4};
+
+use crate::{Error, Result};
+
+/// MD4 digest of `input`, optionally truncated to `hash_length` bytes.
+pub fn hash(input: &[u8], hash_length: Option<usize>) -> Result<Vec<u8>> {
+    let output = Md4::digest(input).to_vec();
+
+    match hash_length {
+        Some(len) if len > 16 => Err(Error::InvalidParameter(
+            "Hash length must be <= 16 for MD4".into(),
+        )),
+        Some(len) => Ok(output[..len].to_vec()),
+        None => Ok(output),
+    }
+}
+
+/// Incremental MD4.
+#[derive(Clone, Default)]
+pub struct Md4Hasher {
+    inner: Md4,
+}
+
+impl Md4Hasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().to_vec()
+    }
+
+    /// Digest truncated to `length` bytes.
+    pub fn finalize_xof(self, length: usize) -> Result<Vec<u8>> {
+        if length > 16 {
+            return Err(Error::InvalidParameter(
+                "Length must be <= 16 for MD4".into(),
+            ));
+        }
+
+        Ok(self.finalize()[..length].to_vec())
+    }
+}
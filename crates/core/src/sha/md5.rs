@@ -0,0 +1,26 @@
+use md5::{Digest, Md5};
+
+/// MD5 digest of `input`.
+pub fn hash(input: &[u8]) -> Vec<u8> {
+    Md5::digest(input).to_vec()
+}
+
+/// Incremental MD5.
+#[derive(Clone, Default)]
+pub struct Md5Hasher {
+    inner: Md5,
+}
+
+impl Md5Hasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().to_vec()
+    }
+}
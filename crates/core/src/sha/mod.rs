@@ -0,0 +1,12 @@
+//! Hash functions.
+
+pub mod ascon;
+pub mod blake2;
+pub mod blake3;
+pub mod md4;
+pub mod md5;
+pub mod ripemd160;
+pub mod sha1;
+pub mod sha2;
+pub mod sha3;
+pub mod whirlpool;
@@ -0,0 +1,26 @@
+use ripemd::{Digest, Ripemd160};
+
+/// RIPEMD-160 digest of `input`.
+pub fn hash(input: &[u8]) -> Vec<u8> {
+    Ripemd160::digest(input).to_vec()
+}
+
+/// Incremental RIPEMD-160.
+#[derive(Clone, Default)]
+pub struct Ripemd160Hasher {
+    inner: Ripemd160,
+}
+
+impl Ripemd160Hasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().to_vec()
+    }
+}
@@ -0,0 +1,26 @@
+use sha1::{Digest, Sha1};
+
+/// SHA-1 digest of `input`.
+pub fn hash(input: &[u8]) -> Vec<u8> {
+    Sha1::digest(input).to_vec()
+}
+
+/// Incremental SHA-1.
+#[derive(Clone, Default)]
+pub struct Sha1Hasher {
+    inner: Sha1,
+}
+
+impl Sha1Hasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().to_vec()
+    }
+}
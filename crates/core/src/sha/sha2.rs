@@ -0,0 +1,79 @@
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ShaType {
+    Sha224,
+    #[default]
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+}
+
+/// SHA-2 digest of `input`.
+pub fn hash(input: &[u8], algo: ShaType) -> Vec<u8> {
+    match algo {
+        ShaType::Sha224 => Sha224::digest(input).to_vec(),
+        ShaType::Sha256 => Sha256::digest(input).to_vec(),
+        ShaType::Sha384 => Sha384::digest(input).to_vec(),
+        ShaType::Sha512 => Sha512::digest(input).to_vec(),
+        ShaType::Sha512_224 => Sha512_224::digest(input).to_vec(),
+        ShaType::Sha512_256 => Sha512_256::digest(input).to_vec(),
+    }
+}
+
+#[derive(Clone)]
+enum Sha2Impl {
+    Sha224(Sha224),
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Sha512_224(Sha512_224),
+    Sha512_256(Sha512_256),
+}
+
+/// Incremental SHA-2.
+#[derive(Clone)]
+pub struct Sha2Hasher {
+    inner: Sha2Impl,
+}
+
+impl Sha2Hasher {
+    pub fn new(algo: ShaType) -> Self {
+        let inner = match algo {
+            ShaType::Sha224 => Sha2Impl::Sha224(Sha224::new()),
+            ShaType::Sha256 => Sha2Impl::Sha256(Sha256::new()),
+            ShaType::Sha384 => Sha2Impl::Sha384(Sha384::new()),
+            ShaType::Sha512 => Sha2Impl::Sha512(Sha512::new()),
+            ShaType::Sha512_224 => Sha2Impl::Sha512_224(Sha512_224::new()),
+            ShaType::Sha512_256 => Sha2Impl::Sha512_256(Sha512_256::new()),
+        };
+
+        Self { inner }
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        match &mut self.inner {
+            Sha2Impl::Sha224(h) => h.update(input),
+            Sha2Impl::Sha256(h) => h.update(input),
+            Sha2Impl::Sha384(h) => h.update(input),
+            Sha2Impl::Sha512(h) => h.update(input),
+            Sha2Impl::Sha512_224(h) => h.update(input),
+            Sha2Impl::Sha512_256(h) => h.update(input),
+        }
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        match self.inner {
+            Sha2Impl::Sha224(h) => h.finalize().to_vec(),
+            Sha2Impl::Sha256(h) => h.finalize().to_vec(),
+            Sha2Impl::Sha384(h) => h.finalize().to_vec(),
+            Sha2Impl::Sha512(h) => h.finalize().to_vec(),
+            Sha2Impl::Sha512_224(h) => h.finalize().to_vec(),
+            Sha2Impl::Sha512_256(h) => h.finalize().to_vec(),
+        }
+    }
+}
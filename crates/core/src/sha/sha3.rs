@@ -0,0 +1,146 @@
+use sha3::{
+    digest::{Digest, ExtendableOutput, Update, XofReader},
+    Keccak224, Keccak256, Keccak384, Keccak512, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128,
+    Shake256,
+};
+
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Sha3Type {
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake128,
+    Shake256,
+    Keccak224,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+}
+
+fn read_xof<X: ExtendableOutput>(xof: X, len: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; len];
+
+    xof.finalize_xof().read(&mut buf);
+
+    buf
+}
+
+/// SHA-3/Keccak digest of `input`. SHAKE variants require `hash_length`.
+pub fn hash(input: &[u8], algo: Sha3Type, hash_length: Option<usize>) -> Result<Vec<u8>> {
+    let output = match algo {
+        Sha3Type::Sha3_224 => Sha3_224::digest(input).to_vec(),
+        Sha3Type::Sha3_256 => Sha3_256::digest(input).to_vec(),
+        Sha3Type::Sha3_384 => Sha3_384::digest(input).to_vec(),
+        Sha3Type::Sha3_512 => Sha3_512::digest(input).to_vec(),
+        Sha3Type::Keccak224 => Keccak224::digest(input).to_vec(),
+        Sha3Type::Keccak256 => Keccak256::digest(input).to_vec(),
+        Sha3Type::Keccak384 => Keccak384::digest(input).to_vec(),
+        Sha3Type::Keccak512 => Keccak512::digest(input).to_vec(),
+        Sha3Type::Shake128 => {
+            let len = hash_length.ok_or_else(|| {
+                Error::InvalidParameter("Shake128 requires hash_length".into())
+            })?;
+
+            let mut hasher = Shake128::default();
+
+            hasher.update(input);
+
+            read_xof(hasher, len)
+        }
+        Sha3Type::Shake256 => {
+            let len = hash_length.ok_or_else(|| {
+                Error::InvalidParameter("Shake256 requires hash_length".into())
+            })?;
+
+            let mut hasher = Shake256::default();
+
+            hasher.update(input);
+
+            read_xof(hasher, len)
+        }
+    };
+
+    Ok(output)
+}
+
+#[derive(Clone)]
+enum Sha3Impl {
+    Sha3_224(Sha3_224),
+    Sha3_256(Sha3_256),
+    Sha3_384(Sha3_384),
+    Sha3_512(Sha3_512),
+    Keccak224(Keccak224),
+    Keccak256(Keccak256),
+    Keccak384(Keccak384),
+    Keccak512(Keccak512),
+    Shake128(Shake128),
+    Shake256(Shake256),
+}
+
+/// Incremental SHA-3/Keccak/SHAKE.
+#[derive(Clone)]
+pub struct Sha3Hasher {
+    inner: Sha3Impl,
+}
+
+impl Sha3Hasher {
+    pub fn new(algo: Sha3Type) -> Self {
+        let inner = match algo {
+            Sha3Type::Sha3_224 => Sha3Impl::Sha3_224(Sha3_224::new()),
+            Sha3Type::Sha3_256 => Sha3Impl::Sha3_256(Sha3_256::new()),
+            Sha3Type::Sha3_384 => Sha3Impl::Sha3_384(Sha3_384::new()),
+            Sha3Type::Sha3_512 => Sha3Impl::Sha3_512(Sha3_512::new()),
+            Sha3Type::Keccak224 => Sha3Impl::Keccak224(Keccak224::new()),
+            Sha3Type::Keccak256 => Sha3Impl::Keccak256(Keccak256::new()),
+            Sha3Type::Keccak384 => Sha3Impl::Keccak384(Keccak384::new()),
+            Sha3Type::Keccak512 => Sha3Impl::Keccak512(Keccak512::new()),
+            Sha3Type::Shake128 => Sha3Impl::Shake128(Shake128::default()),
+            Sha3Type::Shake256 => Sha3Impl::Shake256(Shake256::default()),
+        };
+
+        Self { inner }
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        match &mut self.inner {
+            Sha3Impl::Sha3_224(h) => Digest::update(h, input),
+            Sha3Impl::Sha3_256(h) => Digest::update(h, input),
+            Sha3Impl::Sha3_384(h) => Digest::update(h, input),
+            Sha3Impl::Sha3_512(h) => Digest::update(h, input),
+            Sha3Impl::Keccak224(h) => Digest::update(h, input),
+            Sha3Impl::Keccak256(h) => Digest::update(h, input),
+            Sha3Impl::Keccak384(h) => Digest::update(h, input),
+            Sha3Impl::Keccak512(h) => Digest::update(h, input),
+            Sha3Impl::Shake128(h) => h.update(input),
+            Sha3Impl::Shake256(h) => h.update(input),
+        }
+    }
+
+    /// Finishes the digest. `hash_length` is required for SHAKE and ignored
+    /// for fixed-size variants.
+    pub fn finalize(self, hash_length: Option<usize>) -> Result<Vec<u8>> {
+        let output = match self.inner {
+            Sha3Impl::Sha3_224(h) => h.finalize().to_vec(),
+            Sha3Impl::Sha3_256(h) => h.finalize().to_vec(),
+            Sha3Impl::Sha3_384(h) => h.finalize().to_vec(),
+            Sha3Impl::Sha3_512(h) => h.finalize().to_vec(),
+            Sha3Impl::Keccak224(h) => h.finalize().to_vec(),
+            Sha3Impl::Keccak256(h) => h.finalize().to_vec(),
+            Sha3Impl::Keccak384(h) => h.finalize().to_vec(),
+            Sha3Impl::Keccak512(h) => h.finalize().to_vec(),
+            Sha3Impl::Shake128(h) => read_xof(h, xof_length(hash_length)?),
+            Sha3Impl::Shake256(h) => read_xof(h, xof_length(hash_length)?),
+        };
+
+        Ok(output)
+    }
+}
+
+fn xof_length(hash_length: Option<usize>) -> Result<usize> {
+    hash_length.ok_or_else(|| Error::InvalidParameter("XOF requires hash_length".into()))
+}
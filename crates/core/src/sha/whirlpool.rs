@@ -0,0 +1,26 @@
+use whirlpool::{Digest, Whirlpool};
+
+/// Whirlpool digest of `input`.
+pub fn hash(input: &[u8]) -> Vec<u8> {
+    Whirlpool::digest(input).to_vec()
+}
+
+/// Incremental Whirlpool.
+#[derive(Clone, Default)]
+pub struct WhirlpoolHasher {
+    inner: Whirlpool,
+}
+
+impl WhirlpoolHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().to_vec()
+    }
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the `*_wasm` binding crates.
+
+use js_sys::Uint8Array;
+use zeroize::Zeroizing;
+
+/// Bytes of a JS `Uint8Array` (or Node `Buffer`), copied into Rust-owned
+/// memory with `copy_to`.
+///
+/// Views over the module's own linear memory are copied too: those bytes are
+/// not owned by any Rust value, so the allocator may hand the same region out
+/// (for an output buffer, say) and overwrite it while a borrowed slice of it
+/// is still being read.
+///
+/// Every binding must read its inputs through this function.
+pub fn input_bytes(input: &Uint8Array) -> Vec<u8> {
+    let mut buf = vec![0u8; input.length() as usize];
+
+    input.copy_to(&mut buf);
+
+    buf
+}
+
+/// [`input_bytes`] for key material: the bytes are copied into a buffer that
+/// is zeroized when dropped.
+pub fn secret_bytes(input: &Uint8Array) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(input_bytes(input))
+}
@@ -0,0 +1,39 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cryptographer_core::dsa::{
+    ecdsa::{self, Curve},
+    ed25519,
+};
+
+fn h(s: &str) -> Vec<u8> {
+    hex::decode(s).unwrap()
+}
+
+// RFC 8032 section 7.1, test 1.
+#[test]
+fn ed25519_rfc8032_test1() {
+    let sk = h("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+    let pk = h("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+    let sig = ed25519::sign(&sk, b"").unwrap();
+    assert_eq!(
+        sig.to_vec(),
+        h(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+           5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        )
+    );
+    assert!(ed25519::verify(&pk, b"", &sig).unwrap());
+    assert!(!ed25519::verify(&pk, b"x", &sig).unwrap());
+}
+
+#[test]
+fn ecdsa_sign_verify() {
+    for curve in [Curve::P256, Curve::Secp256k1] {
+        let (sk, pk) = ecdsa::generate_keypair(curve);
+        let digest = [7u8; 32];
+        let sig = ecdsa::sign(curve, &sk, &digest).unwrap();
+        assert!(ecdsa::verify(curve, &pk, &digest, &sig).unwrap());
+        assert!(!ecdsa::verify(curve, &pk, &[8u8; 32], &sig).unwrap());
+    }
+}
@@ -0,0 +1,230 @@
+//! Known-answer tests for every `HashAlgorithm`.
+//!
+//! MD5/SHA-1/SHA-2/RIPEMD-160 use RFC 2202, RFC 4231 and RFC 2286 vectors;
+//! SHA-3 and the truncated SHA-512 variants were generated with Python's
+//! `hmac` module. Digests without a published HMAC vector (MD4, RIPEMD-256,
+//! RIPEMD-320, Whirlpool) are checked against a textbook RFC 2104 HMAC.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cryptographer_core::hmac::{hmac, HashAlgorithm, HmacHasher};
+use digest::{core_api::BlockSizeUser, Digest};
+
+fn h(s: &str) -> Vec<u8> {
+    hex::decode(s).unwrap()
+}
+
+// RFC 2202 / RFC 4231 test case 2.
+const KEY: &[u8] = b"Jefe";
+const DATA: &[u8] = b"what do ya want for nothing?";
+
+// RFC 4231 / RFC 2286 test case 1.
+const KEY_0B: &[u8] = &[0x0b; 20];
+const HI_THERE: &[u8] = b"Hi There";
+
+const ALL: [HashAlgorithm; 17] = [
+    HashAlgorithm::Md4,
+    HashAlgorithm::Md5,
+    HashAlgorithm::Sha1,
+    HashAlgorithm::Sha224,
+    HashAlgorithm::Sha256,
+    HashAlgorithm::Sha384,
+    HashAlgorithm::Sha512,
+    HashAlgorithm::Sha512_224,
+    HashAlgorithm::Sha512_256,
+    HashAlgorithm::Sha3_224,
+    HashAlgorithm::Sha3_256,
+    HashAlgorithm::Sha3_384,
+    HashAlgorithm::Sha3_512,
+    HashAlgorithm::Ripemd160,
+    HashAlgorithm::Ripemd256,
+    HashAlgorithm::Ripemd320,
+    HashAlgorithm::Whirlpool,
+];
+
+#[test]
+fn hmac_md5_rfc2202() {
+    assert_eq!(
+        hmac(KEY, DATA, HashAlgorithm::Md5).unwrap(),
+        h("750c783e6ab0b503eaa86e310a5db738")
+    );
+}
+
+#[test]
+fn hmac_sha1_rfc2202() {
+    assert_eq!(
+        hmac(KEY_0B, HI_THERE, HashAlgorithm::Sha1).unwrap(),
+        h("b617318655057264e28bc0b6fb378c8ef146be00")
+    );
+}
+
+#[test]
+fn hmac_sha256_rfc4231() {
+    assert_eq!(
+        hmac(KEY, DATA, HashAlgorithm::Sha256).unwrap(),
+        h("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+    );
+}
+
+#[test]
+fn hmac_sha2_hi_there() {
+    let vectors = [
+        (
+            HashAlgorithm::Sha224,
+            "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22",
+        ),
+        (
+            HashAlgorithm::Sha256,
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        ),
+        (
+            HashAlgorithm::Sha384,
+            "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59c\
+             faea9ea9076ede7f4af152e8b2fa9cb6",
+        ),
+        (
+            HashAlgorithm::Sha512,
+            "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde\
+             daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+        ),
+        (
+            HashAlgorithm::Sha512_224,
+            "b244ba01307c0e7a8ccaad13b1067a4cf6b961fe0c6a20bda3d92039",
+        ),
+        (
+            HashAlgorithm::Sha512_256,
+            "9f9126c3d9c3c330d760425ca8a217e31feae31bfe70196ff81642b868402eab",
+        ),
+    ];
+    for (algo, expected) in vectors {
+        assert_eq!(
+            hmac(KEY_0B, HI_THERE, algo).unwrap(),
+            h(expected),
+            "{:?}",
+            algo
+        );
+    }
+}
+
+#[test]
+fn hmac_sha3_hi_there() {
+    let vectors = [
+        (
+            HashAlgorithm::Sha3_224,
+            "3b16546bbc7be2706a031dcafd56373d9884367641d8c59af3c860f7",
+        ),
+        (
+            HashAlgorithm::Sha3_256,
+            "ba85192310dffa96e2a3a40e69774351140bb7185e1202cdcc917589f95e16bb",
+        ),
+        (
+            HashAlgorithm::Sha3_384,
+            "68d2dcf7fd4ddd0a2240c8a437305f61fb7334cfb5d0226e1bc27dc10a2e723a\
+             20d370b47743130e26ac7e3d532886bd",
+        ),
+        (
+            HashAlgorithm::Sha3_512,
+            "eb3fbd4b2eaab8f5c504bd3a41465aacec15770a7cabac531e482f860b5ec7ba\
+             47ccb2c6f2afce8f88d22b6dc61380f23a668fd3888bb80537c0a0b86407689e",
+        ),
+    ];
+    for (algo, expected) in vectors {
+        assert_eq!(
+            hmac(KEY_0B, HI_THERE, algo).unwrap(),
+            h(expected),
+            "{:?}",
+            algo
+        );
+    }
+}
+
+#[test]
+fn hmac_ripemd160_rfc2286() {
+    assert_eq!(
+        hmac(KEY_0B, HI_THERE, HashAlgorithm::Ripemd160).unwrap(),
+        h("24cb4bd67d20fc1a5d2ed7732dcc39377f0a5668")
+    );
+}
+
+/// RFC 2104 HMAC written out by hand, keys longer than a block are hashed.
+fn reference_hmac<D: Digest + BlockSizeUser>(key: &[u8], msg: &[u8]) -> Vec<u8> {
+    let block = D::block_size();
+    let mut k = if key.len() > block {
+        D::digest(key).to_vec()
+    } else {
+        key.to_vec()
+    };
+    k.resize(block, 0);
+
+    let ipad: Vec<u8> = k.iter().map(|b| b ^ 0x36).collect();
+    let opad: Vec<u8> = k.iter().map(|b| b ^ 0x5c).collect();
+    let inner = D::new().chain_update(&ipad).chain_update(msg).finalize();
+    D::new()
+        .chain_update(&opad)
+        .chain_update(inner)
+        .finalize()
+        .to_vec()
+}
+
+#[test]
+fn hmac_digests_without_published_vectors() {
+    let long_key = [0xaa; 131];
+    for key in [KEY, KEY_0B, &long_key[..]] {
+        assert_eq!(
+            hmac(key, DATA, HashAlgorithm::Md4).unwrap(),
+            reference_hmac::<md4::Md4>(key, DATA)
+        );
+        assert_eq!(
+            hmac(key, DATA, HashAlgorithm::Ripemd256).unwrap(),
+            reference_hmac::<ripemd::Ripemd256>(key, DATA)
+        );
+        assert_eq!(
+            hmac(key, DATA, HashAlgorithm::Ripemd320).unwrap(),
+            reference_hmac::<ripemd::Ripemd320>(key, DATA)
+        );
+        assert_eq!(
+            hmac(key, DATA, HashAlgorithm::Whirlpool).unwrap(),
+            reference_hmac::<whirlpool::Whirlpool>(key, DATA)
+        );
+    }
+}
+
+// The underlying digests themselves, so the reference HMAC above is anchored.
+#[test]
+fn ripemd256_320_empty() {
+    assert_eq!(
+        ripemd::Ripemd256::digest(b"").to_vec(),
+        h("02ba4c4e5f8ecd1877fc52d64d30e37a2d9774fb1e5d026380ae0168e3c5522d")
+    );
+    assert_eq!(
+        ripemd::Ripemd320::digest(b"").to_vec(),
+        h("22d65d5661536cdc75c1fdf5c6de7b41b9f27325ebc61e8557177d705a0ec880151c3a32a00899b8")
+    );
+}
+
+#[test]
+fn streaming_matches_one_shot() {
+    let mut mac = HmacHasher::new(KEY, HashAlgorithm::Sha256).unwrap();
+    mac.update(&DATA[..10]);
+    mac.update(&DATA[10..]);
+    assert_eq!(
+        mac.finalize(),
+        hmac(KEY, DATA, HashAlgorithm::Sha256).unwrap()
+    );
+}
+
+#[test]
+fn streaming_matches_one_shot_all_algorithms() {
+    for algo in ALL {
+        let mut mac = HmacHasher::new(KEY_0B, algo).unwrap();
+        mac.update(&HI_THERE[..3]);
+        mac.update(&HI_THERE[3..]);
+        assert_eq!(
+            mac.finalize(),
+            hmac(KEY_0B, HI_THERE, algo).unwrap(),
+            "{:?}",
+            algo
+        );
+    }
+}
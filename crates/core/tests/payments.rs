@@ -0,0 +1,322 @@
+//! Known-answer tests for the payments module.
+//!
+//! Each test names where its vectors come from. PIN blocks and key blocks
+//! that carry random padding are otherwise checked by deciphering them
+//! step by step with the plain block ciphers.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cryptographer_core::cipher::{
+    aes::{self, AesAlgorithm},
+    des::{self, DesAlgorithm, Padding},
+};
+use cryptographer_core::payments::{
+    kcv::{kcv, KcvMethod},
+    pin_block::{self, PinBlockFormat},
+    tr31::{self, KeyBlockHeader, KeyBlockVersion, OptionalBlock},
+    KeyAlgorithm,
+};
+use cryptographer_core::Error;
+
+fn h(s: &str) -> Vec<u8> {
+    hex::decode(s).unwrap()
+}
+
+const TDES_KEY: &str = "0123456789abcdeffedcba9876543210";
+const AES_KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+const PAN: &str = "43219876543210987";
+
+// SP 800-38B example keys with their published subkeys: L = E(K, 0) and
+// K1 = dbl(L). A zero-block KCV is the start of L, and the CMAC of one
+// zero block is E(K, K1), so the CMAC KCV is the start of that.
+const TDES_CMAC_KEY: &str = "8aa83bf8cbda10620bc1bf19fbb6cd58bc313d4a371ca8b5";
+const TDES_CMAC_L: &str = "c8cc74e98a7329a2";
+const TDES_CMAC_K1: &str = "9198e9d314e6535f";
+const AES_CMAC_L: &str = "7df76b0c1ab899b33e42f047b91b546f";
+const AES_CMAC_K1: &str = "fbeed618357133667c85e08f7236a8de";
+
+fn tdes_ecb(key: &[u8], block: &[u8]) -> Vec<u8> {
+    des::encrypt_padded(block, key, &[], Padding::NoPadding, DesAlgorithm::TdesEcb).unwrap()
+}
+
+fn aes_ecb(key: &[u8], block: &[u8]) -> Vec<u8> {
+    aes::encrypt_padded(block, key, &[], Padding::NoPadding, AesAlgorithm::Aes128Ecb).unwrap()
+}
+
+#[test]
+fn kcv_zero_block_and_cmac() {
+    // The common 3DES example: 0123456789ABCDEF FEDCBA9876543210 -> 08D7B4
+    assert_eq!(
+        kcv(&h(TDES_KEY), KeyAlgorithm::Tdes, KcvMethod::ZeroBlock).unwrap(),
+        h("08d7b4")
+    );
+
+    let tdes = h(TDES_CMAC_KEY);
+    assert_eq!(tdes_ecb(&tdes, &[0; 8]), h(TDES_CMAC_L));
+    assert_eq!(
+        kcv(&tdes, KeyAlgorithm::Tdes, KcvMethod::ZeroBlock).unwrap(),
+        h(&TDES_CMAC_L[..6])
+    );
+    assert_eq!(
+        kcv(&tdes, KeyAlgorithm::Tdes, KcvMethod::Cmac).unwrap(),
+        tdes_ecb(&tdes, &h(TDES_CMAC_K1))[..5]
+    );
+
+    let aes = h(AES_KEY);
+    assert_eq!(aes_ecb(&aes, &[0; 16]), h(AES_CMAC_L));
+    assert_eq!(
+        kcv(&aes, KeyAlgorithm::Aes, KcvMethod::ZeroBlock).unwrap(),
+        h(&AES_CMAC_L[..6])
+    );
+    assert_eq!(
+        kcv(&aes, KeyAlgorithm::Aes, KcvMethod::Cmac).unwrap(),
+        aes_ecb(&aes, &h(AES_CMAC_K1))[..5]
+    );
+
+    // A DES key is an EDE2 key with K1 = K2
+    let des = &h(TDES_KEY)[..8];
+    assert_eq!(
+        kcv(des, KeyAlgorithm::Tdes, KcvMethod::ZeroBlock).unwrap(),
+        kcv(&des.repeat(2), KeyAlgorithm::Tdes, KcvMethod::ZeroBlock).unwrap()
+    );
+    assert!(kcv(des, KeyAlgorithm::Tdes, KcvMethod::Cmac).is_err());
+    assert!(kcv(&h(TDES_KEY), KeyAlgorithm::Aes, KcvMethod::Cmac).is_ok());
+    assert!(kcv(&h(AES_KEY)[..12], KeyAlgorithm::Aes, KcvMethod::Cmac).is_err());
+}
+
+// ISO 9564-1 format 0 example: PIN 1234, PAN 4321987654321098(7).
+#[test]
+fn pin_block_iso0() {
+    let block = pin_block::encode("1234", PAN, PinBlockFormat::Iso0).unwrap();
+    assert_eq!(block, h("0412ac89abcdef67"));
+    assert_eq!(
+        pin_block::decode(&block, PAN, PinBlockFormat::Iso0).unwrap(),
+        "1234"
+    );
+
+    let key = h(TDES_KEY);
+    let enciphered = pin_block::encrypt("1234", PAN, &key, PinBlockFormat::Iso0).unwrap();
+    assert_eq!(enciphered.len(), 8);
+    assert_eq!(
+        pin_block::decrypt(&enciphered, PAN, &key, PinBlockFormat::Iso0).unwrap(),
+        "1234"
+    );
+
+    // The fill must be F and the PAN must match
+    let mut bad = block.clone();
+    bad[7] ^= 0x01;
+    assert!(pin_block::decode(&bad, PAN, PinBlockFormat::Iso0).is_err());
+    assert!(pin_block::decode(&block, PAN, PinBlockFormat::Iso3).is_err());
+    // The check digit is not part of the PAN field
+    assert!(pin_block::decode(&block, "43219876543210980", PinBlockFormat::Iso0).is_ok());
+    assert!(pin_block::decode(&block, "5321987654321098", PinBlockFormat::Iso0).is_err());
+}
+
+#[test]
+fn pin_block_iso1_iso3() {
+    let key = h(TDES_KEY);
+    for (format, control) in [(PinBlockFormat::Iso1, 0x1), (PinBlockFormat::Iso3, 0x3)] {
+        for pin in ["0000", "123456", "987654321012"] {
+            let block = pin_block::encode(pin, PAN, format).unwrap();
+            assert_eq!(block[0], control << 4 | pin.len() as u8);
+            assert_eq!(pin_block::decode(&block, PAN, format).unwrap(), pin);
+
+            let enciphered = pin_block::encrypt(pin, PAN, &key, format).unwrap();
+            assert_eq!(
+                pin_block::decrypt(&enciphered, PAN, &key, format).unwrap(),
+                pin
+            );
+        }
+    }
+
+    // Format 1 ignores the PAN; format 3 fills with A-F only
+    let block = pin_block::encode("1234", "", PinBlockFormat::Iso1).unwrap();
+    assert_eq!(
+        pin_block::decode(&block, "", PinBlockFormat::Iso1).unwrap(),
+        "1234"
+    );
+    let block = pin_block::encode("1234", PAN, PinBlockFormat::Iso3).unwrap();
+    let clear = pin_block::encode("1234", PAN, PinBlockFormat::Iso0).unwrap();
+    let fill = (3..8).map(|i| block[i] ^ clear[i] ^ 0xff);
+    assert!(fill.into_iter().all(|b| b >> 4 >= 0xa && b & 0xf >= 0xa));
+}
+
+fn aes_ecb_decrypt(key: &[u8], block: &[u8]) -> Vec<u8> {
+    aes::decrypt_padded(block, key, &[], Padding::NoPadding, AesAlgorithm::Aes128Ecb).unwrap()
+}
+
+// Format 4 carries 8 random bytes, so the block is deciphered by hand and
+// checked against the ISO 9564-1 layouts: PIN field 4 || N || PIN || A-fill
+// || random, PAN field M || PAN (left-padded to 12 digits) || 0-fill.
+#[test]
+fn pin_block_iso4() {
+    let key = h("00112233445566778899aabbccddeeff");
+    let cases = [
+        ("432198765432109870", "6432198765432109870000000000000000"),
+        ("1234567", "00000012345670000000000000000000"),
+    ];
+    for (pan, pan_field) in cases {
+        let block = pin_block::encrypt("1234", pan, &key, PinBlockFormat::Iso4).unwrap();
+        let mut intermediate = aes_ecb_decrypt(&key, &block);
+        for (b, p) in intermediate.iter_mut().zip(h(&pan_field[..32])) {
+            *b ^= p;
+        }
+        let pin_field = aes_ecb_decrypt(&key, &intermediate);
+        assert_eq!(pin_field[..8], h("441234aaaaaaaaaa"), "{}", pan);
+        assert_eq!(
+            pin_block::decrypt(&block, pan, &key, PinBlockFormat::Iso4).unwrap(),
+            "1234"
+        );
+    }
+
+    let pan = "432198765432109870";
+    let block = pin_block::encrypt("1234", pan, &key, PinBlockFormat::Iso4).unwrap();
+
+    for pan in [pan, "1234567", "4111111111111111"] {
+        let block = pin_block::encrypt("24680", pan, &key, PinBlockFormat::Iso4).unwrap();
+        assert_eq!(block.len(), 16);
+        assert_eq!(
+            pin_block::decrypt(&block, pan, &key, PinBlockFormat::Iso4).unwrap(),
+            "24680"
+        );
+    }
+    assert!(pin_block::decrypt(&block, "432198765432109871", &key, PinBlockFormat::Iso4).is_err());
+
+    assert!(pin_block::encode("1234", pan, PinBlockFormat::Iso4).is_err());
+    assert!(pin_block::encrypt("1234", pan, &h(TDES_KEY)[..8], PinBlockFormat::Iso4).is_err());
+}
+
+#[test]
+fn pin_block_rejects_bad_input() {
+    for pin in ["123", "1234567890123", "12a4", ""] {
+        assert!(pin_block::encode(pin, PAN, PinBlockFormat::Iso0).is_err());
+    }
+    assert!(pin_block::encode("1234", "", PinBlockFormat::Iso0).is_err());
+    assert!(pin_block::encode("1234", "12345678901234567890", PinBlockFormat::Iso3).is_err());
+    assert!(pin_block::encode("1234", "4321-9876", PinBlockFormat::Iso0).is_err());
+    assert!(pin_block::decode(&[0; 16], PAN, PinBlockFormat::Iso0).is_err());
+    assert!(pin_block::decrypt(&[0; 8], PAN, &[0; 12], PinBlockFormat::Iso0).is_err());
+}
+
+// ANSI X9.143 (TR-31:2018) annex A examples: the same AES PIN key wrapped
+// under a two-key 3DES KBPK (version B) and an AES-256 KBPK (version D).
+const TR31_B_KBPK: &str = "dd7515f2bfc17f85ce48f3ca25cb21f6";
+const TR31_B_BLOCK: &str =
+    "B0080P0TE00E000094B420079CC80BA3461F86FE26EFC4A3B8E4FA4C5F5341176EED7B727B8A248E";
+const TR31_D_KBPK: &str = "88e1ab2a2e3dd38c1fa039a536500cc8a87ab9d62dc92c01058fa79f44657de6";
+const TR31_D_BLOCK: &str =
+    "D0112P0AE00E0000B82679114F470F540165EDFBF7E250FCEA43F810D215F8D207E2E417C07156A27E8E31\
+     DA05F7425509593D03A457DC34";
+const TR31_KEY: &str = "3f419e1cb7079442aa37474c2efbf8b8";
+
+#[test]
+fn tr31_known_blocks() {
+    for (kbpk, block, version) in [
+        (TR31_B_KBPK, TR31_B_BLOCK, KeyBlockVersion::B),
+        (TR31_D_KBPK, TR31_D_BLOCK, KeyBlockVersion::D),
+    ] {
+        let (header, unwrapped) = tr31::unwrap(&h(kbpk), block).unwrap();
+        assert_eq!(unwrapped, h(TR31_KEY));
+        assert_eq!(header, block.parse::<KeyBlockHeader>().unwrap());
+        assert_eq!(header.version, version);
+        assert_eq!(header.key_usage, "P0");
+        assert_eq!((header.mode_of_use, header.exportability), ('E', 'E'));
+        assert_eq!(header.key_version, "00");
+        assert!(header.optional_blocks.is_empty());
+    }
+}
+
+#[test]
+fn tr31_roundtrip() {
+    let cases = [
+        ("B0000P0TE00N0000", "89e88cf7931444f334bd7547fc3f380c", 16),
+        (
+            "B0000K0TB00N0000",
+            "0123456789abcdef23456789abcdef01456789abcdef0123",
+            24,
+        ),
+        ("D0000P0AE00E0000", "000102030405060708090a0b0c0d0e0f", 16),
+        (
+            "D0000K0AB00S0000",
+            "000102030405060708090a0b0c0d0e0f1011121314151617",
+            32,
+        ),
+        (
+            "D0000D0AD00N0000",
+            "88e1ab2a2e3dd38c1fa039a536500cc8a87ab9d62dc92c01058fa79f44657de6",
+            24,
+        ),
+    ];
+    for (header, kbpk, key_len) in cases {
+        let mut header: KeyBlockHeader = header.parse().unwrap();
+        let kbpk = h(kbpk);
+        let key: Vec<u8> = (0..key_len as u8).collect();
+
+        let block = tr31::wrap(&kbpk, &header, &key).unwrap();
+        assert_eq!(
+            tr31::unwrap(&kbpk, &block).unwrap(),
+            (header.clone(), key.clone())
+        );
+
+        // Optional blocks are padded to a whole cipher block with PB
+        header.optional_blocks = vec![OptionalBlock {
+            id: "KS".into(),
+            data: "00604B120F929280000".into(),
+        }];
+        let block = tr31::wrap(&kbpk, &header, &key).unwrap();
+        assert!(block[16..].starts_with("KS1700604B120F929280000PB0900000"));
+        assert_eq!(&block[12..14], "02");
+        assert_eq!(block[1..5].parse::<usize>().unwrap(), block.len());
+        assert_eq!(tr31::unwrap(&kbpk, &block).unwrap(), (header, key));
+    }
+}
+
+#[test]
+fn tr31_rejects_tampering() {
+    let kbpk = h(TR31_B_KBPK);
+    let block = TR31_B_BLOCK;
+
+    // Header, key data and MAC are all covered
+    for i in [5, 20, 79] {
+        let mut tampered = block.to_string().into_bytes();
+        tampered[i] = if tampered[i] == b'0' { b'1' } else { b'0' };
+        let tampered = String::from_utf8(tampered).unwrap();
+        assert!(matches!(
+            tr31::unwrap(&kbpk, &tampered),
+            Err(Error::Decryption(_))
+        ));
+    }
+    let mut wrong = kbpk.clone();
+    wrong[0] ^= 2;
+    assert!(matches!(
+        tr31::unwrap(&wrong, block),
+        Err(Error::Decryption(_))
+    ));
+
+    assert!(tr31::unwrap(&kbpk, &block[..78]).is_err());
+    assert!(tr31::unwrap(&kbpk, &block.replacen("B0080", "B0081", 1)).is_err());
+    assert!(tr31::unwrap(&kbpk, &block.replacen('B', "A", 1)).is_err());
+    assert!(tr31::unwrap(&kbpk[..8], block).is_err());
+    assert!(tr31::unwrap(&h(AES_KEY)[..15], &block.replacen('B', "D", 1)).is_err());
+
+    let header: KeyBlockHeader = "D0000P0AE00E0000".parse().unwrap();
+    assert!(tr31::wrap(&kbpk[..8], &header, &[0; 16]).is_err());
+    assert!(tr31::wrap(&kbpk, &header, &[]).is_err());
+
+    // Numeric fields take digits only, not a sign
+    for malformed in [
+        block.replacen("B0080", "B+080", 1),
+        block.replacen("E0000", "E+000", 1),
+        "D0000P0AE00E0100KS+C00604B12".to_string(),
+    ] {
+        assert!(matches!(
+            tr31::unwrap(&kbpk, &malformed),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+    assert!("D0000P0AE00E+100".parse::<KeyBlockHeader>().is_err());
+    assert!("D0000P0AE00E0100KS+C00604B12"
+        .parse::<KeyBlockHeader>()
+        .is_err());
+}
@@ -0,0 +1,161 @@
+//! Known-answer tests for the password hashers: RFC 7914 PBKDF2-HMAC-SHA256,
+//! the OpenBSD bcrypt suite and the Argon2 reference implementation's
+//! `password`/`somesalt` vectors.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cryptographer_core::pha::{argon2, bcrypt, pbkdf2};
+
+#[test]
+fn pbkdf2_sha256_one_iteration() {
+    let opts = pbkdf2::HashOptions {
+        salt: Some("c2FsdA==".into()), // "salt"
+        iterations: Some(1),
+        key_length: Some(32),
+    };
+    let encoded = pbkdf2::hash_password(b"password", &opts).unwrap();
+    assert_eq!(
+        hex::encode(base64_decode(&encoded)),
+        "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+    );
+    assert!(pbkdf2::verify_password(b"password", &encoded, "c2FsdA==", 1).unwrap());
+    assert!(!pbkdf2::verify_password(b"passwore", &encoded, "c2FsdA==", 1).unwrap());
+}
+
+// RFC 7914 section 11.
+#[test]
+fn pbkdf2_sha256_rfc7914() {
+    let vectors = [
+        (
+            &b"passwd"[..],
+            "c2FsdA==", // "salt"
+            1,
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+             49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783",
+        ),
+        (
+            &b"Password"[..],
+            "TmFDbA==", // "NaCl"
+            80000,
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56\
+             a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d",
+        ),
+    ];
+    for (password, salt, iterations, expected) in vectors {
+        let opts = pbkdf2::HashOptions {
+            salt: Some(salt.into()),
+            iterations: Some(iterations),
+            key_length: Some(64),
+        };
+        let encoded = pbkdf2::hash_password(password, &opts).unwrap();
+        assert_eq!(hex::encode(base64_decode(&encoded)), expected);
+        assert!(pbkdf2::verify_password(password, &encoded, salt, iterations).unwrap());
+    }
+}
+
+#[test]
+fn bcrypt_openbsd_vector() {
+    let hash = "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW";
+    assert!(bcrypt::verify_password(b"U*U", hash).unwrap());
+    assert!(!bcrypt::verify_password(b"U*V", hash).unwrap());
+}
+
+#[test]
+fn bcrypt_openbsd_suite() {
+    let vectors: [(&[u8], &str); 2] = [
+        (
+            b"",
+            "$2a$06$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.",
+        ),
+        (
+            b"a",
+            "$2a$06$m0CrhHm10qJ3lXRY.5zDGO3rS2KdeeWLuGmsfGlMfOxih58VYVfxe",
+        ),
+    ];
+    for (password, hash) in vectors {
+        assert!(bcrypt::verify_password(password, hash).unwrap());
+        assert!(!bcrypt::verify_password(b"b", hash).unwrap());
+    }
+}
+
+#[test]
+fn bcrypt_round_trip() {
+    let opts = bcrypt::HashOptions { cost: Some(4) };
+    let hash = bcrypt::hash_password(b"correct horse", &opts).unwrap();
+    assert!(hash.starts_with("$2b$04$"));
+    assert!(bcrypt::verify_password(b"correct horse", &hash).unwrap());
+    assert!(!bcrypt::verify_password(b"battery staple", &hash).unwrap());
+}
+
+fn argon2_opts(variant: &str, parallelism: u32) -> argon2::HashOptions {
+    argon2::HashOptions {
+        salt: Some("c29tZXNhbHQ".into()), // "somesalt"
+        time_cost: Some(2),
+        memory_cost: Some(256),
+        parallelism: Some(parallelism),
+        variant: Some(variant.into()),
+        key_length: Some(32),
+    }
+}
+
+// Argon2 reference implementation `test.c`, version 0x13.
+#[test]
+fn argon2_reference_vectors() {
+    let vectors = [
+        (
+            "i",
+            1,
+            "$argon2i$v=19$m=256,t=2,p=1$c29tZXNhbHQ$iekCn0Y3spW+sCcFanM2xBT63UP2sghkUoHLIUpWRS8",
+        ),
+        (
+            "i",
+            2,
+            "$argon2i$v=19$m=256,t=2,p=2$c29tZXNhbHQ$T/XOJ2mh1/TIpJHfCdQan76Q5esCFVoT5MAeIM1Oq2E",
+        ),
+        (
+            "id",
+            1,
+            "$argon2id$v=19$m=256,t=2,p=1$c29tZXNhbHQ$nf65EOgLrQMR/uIPnA4rEsF5h7TKyQwu9U1bMCHGi/4",
+        ),
+        (
+            "id",
+            2,
+            "$argon2id$v=19$m=256,t=2,p=2$c29tZXNhbHQ$bQk8UB/VmZZF4Oo79iDXuL5/0ttZwg2f/5U52iv1cDc",
+        ),
+    ];
+    for (variant, parallelism, expected) in vectors {
+        let phc = argon2::hash_password(b"password", &argon2_opts(variant, parallelism)).unwrap();
+        assert_eq!(phc, expected);
+        assert!(argon2::verify_password(b"password", expected).unwrap());
+        assert!(!argon2::verify_password(b"passwore", expected).unwrap());
+    }
+}
+
+#[test]
+fn argon2d_round_trip() {
+    let phc = argon2::hash_password(b"password", &argon2_opts("argon2d", 1)).unwrap();
+    assert!(phc.starts_with("$argon2d$v=19$m=256,t=2,p=1$c29tZXNhbHQ$"));
+    assert!(argon2::verify_password(b"password", &phc).unwrap());
+    assert!(!argon2::verify_password(b"passwore", &phc).unwrap());
+}
+
+#[test]
+fn argon2_round_trip() {
+    let opts = argon2::HashOptions {
+        salt: Some("c29tZXNhbHQ".into()),
+        time_cost: Some(1),
+        memory_cost: Some(64),
+        parallelism: Some(1),
+        ..Default::default()
+    };
+    let phc = argon2::hash_password(b"password", &opts).unwrap();
+    assert!(phc.starts_with("$argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHQ$"));
+    assert!(argon2::verify_password(b"password", &phc).unwrap());
+    assert!(!argon2::verify_password(b"passwore", &phc).unwrap());
+}
+
+fn base64_decode(s: &str) -> Vec<u8> {
+    use base64::Engine as _;
+    base64::engine::general_purpose::STANDARD.decode(s).unwrap()
+}
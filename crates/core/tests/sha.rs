@@ -0,0 +1,462 @@
+//! Known-answer tests for every digest in `cryptographer_core::sha`.
+//!
+//! Vectors come from the algorithm specifications (RFC 1320, FIPS 180-4,
+//! FIPS 202, RFC 7693, the BLAKE3 reference `test_vectors.json`, the ascon-c
+//! `LWC_HASH_KAT` files) and were
+//! cross-checked against Python's `hashlib` where it implements the digest.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cryptographer_core::sha::{
+    ascon::{self, AsconHasher, AsconType},
+    blake2::{self, Blake2Hasher, Blake2Type},
+    blake3::{self, Blake3Hasher, HashOptions},
+    md4, md5, ripemd160, sha1,
+    sha2::{self, Sha2Hasher, ShaType},
+    sha3::{self, Sha3Hasher, Sha3Type},
+    whirlpool,
+};
+
+fn h(s: &str) -> Vec<u8> {
+    hex::decode(s).unwrap()
+}
+
+// RFC 1320 appendix A.5.
+#[test]
+fn md4_rfc1320_suite() {
+    let vectors: &[(&[u8], &str)] = &[
+        (b"", "31d6cfe0d16ae931b73c59d7e0c089c0"),
+        (b"a", "bde52cb31de33e46245e05fbdbd6fb24"),
+        (b"abc", "a448017aaf21d8525fc10ae87aa6729d"),
+        (b"message digest", "d9130a8164549fe818874806e1c7014b"),
+        (
+            b"abcdefghijklmnopqrstuvwxyz",
+            "d79e1c308aa5bbcdeea8ed63df412da9",
+        ),
+        (
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            "043f8582f241db351ce627e153e7f0e4",
+        ),
+        (
+            b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            "e33b4ddc9c38f2199c3e7b164fcc0536",
+        ),
+    ];
+    for (input, expected) in vectors {
+        assert_eq!(md4::hash(input, None).unwrap(), h(expected));
+    }
+}
+
+#[test]
+fn md4_truncation() {
+    assert_eq!(md4::hash(b"abc", Some(4)).unwrap(), h("a448017a"));
+    assert!(md4::hash(b"abc", Some(17)).is_err());
+}
+
+#[test]
+fn md5_abc() {
+    assert_eq!(md5::hash(b"abc"), h("900150983cd24fb0d6963f7d28e17f72"));
+    assert_eq!(md5::hash(b""), h("d41d8cd98f00b204e9800998ecf8427e"));
+}
+
+#[test]
+fn sha1_abc() {
+    assert_eq!(
+        sha1::hash(b"abc"),
+        h("a9993e364706816aba3e25717850c26c9cd0d89d")
+    );
+    assert_eq!(
+        sha1::hash(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+        h("84983e441c3bd26ebaae4aa1f95129e5e54670f1")
+    );
+}
+
+// FIPS 180-4 "abc" examples for every `ShaType`.
+#[test]
+fn sha2_abc_all_types() {
+    let vectors = [
+        (
+            ShaType::Sha224,
+            "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7",
+        ),
+        (
+            ShaType::Sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            ShaType::Sha384,
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+             8086072ba1e7cc2358baeca134c825a7",
+        ),
+        (
+            ShaType::Sha512,
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        ),
+        (
+            ShaType::Sha512_224,
+            "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa",
+        ),
+        (
+            ShaType::Sha512_256,
+            "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23",
+        ),
+    ];
+    for (algo, expected) in vectors {
+        assert_eq!(sha2::hash(b"abc", algo), h(expected), "{:?}", algo);
+
+        let mut hasher = Sha2Hasher::new(algo);
+        hasher.update(b"a");
+        hasher.update(b"bc");
+        assert_eq!(hasher.finalize(), h(expected), "{:?} streaming", algo);
+    }
+}
+
+// FIPS 202 "abc" examples.
+#[test]
+fn sha3_abc_all_fixed_types() {
+    let vectors = [
+        (
+            Sha3Type::Sha3_224,
+            "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf",
+        ),
+        (
+            Sha3Type::Sha3_256,
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+        ),
+        (
+            Sha3Type::Sha3_384,
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+             98d88cea927ac7f539f1edf228376d25",
+        ),
+        (
+            Sha3Type::Sha3_512,
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+        ),
+    ];
+    for (algo, expected) in vectors {
+        assert_eq!(
+            sha3::hash(b"abc", algo, None).unwrap(),
+            h(expected),
+            "{:?}",
+            algo
+        );
+
+        let mut hasher = Sha3Hasher::new(algo);
+        hasher.update(b"ab");
+        hasher.update(b"c");
+        assert_eq!(
+            hasher.finalize(None).unwrap(),
+            h(expected),
+            "{:?} streaming",
+            algo
+        );
+    }
+}
+
+// Original (pre-FIPS) Keccak padding, as used by Ethereum.
+#[test]
+fn keccak_empty_all_types() {
+    let vectors = [
+        (
+            Sha3Type::Keccak224,
+            "f71837502ba8e10837bdd8d365adb85591895602fc552b48b7390abd",
+        ),
+        (
+            Sha3Type::Keccak256,
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            Sha3Type::Keccak384,
+            "2c23146a63a29acf99e73b88f8c24eaa7dc60aa771780ccc006afbfa8fe2479b\
+             2dd2b21362337441ac12b515911957ff",
+        ),
+        (
+            Sha3Type::Keccak512,
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304\
+             c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e",
+        ),
+    ];
+    for (algo, expected) in vectors {
+        assert_eq!(
+            sha3::hash(b"", algo, None).unwrap(),
+            h(expected),
+            "{:?}",
+            algo
+        );
+    }
+}
+
+// SHAKE output is a prefix-stable stream, so each length must extend the last.
+#[test]
+fn shake_abc_lengths() {
+    let shake128 = h(
+        "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8\
+         44c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca578378\
+         9a41f8611214ce612394df286a62d1a2252aa94db9c538956c717dc2bed4f232\
+         a0294c85",
+    );
+    let shake256 = h(
+        "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+         d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4\
+         1385141204f329979fd3047a13c5657724ada64d2470157b3cdc288620944d78\
+         dbcddbd9",
+    );
+    for len in [1, 16, 32, 64, 100] {
+        assert_eq!(
+            sha3::hash(b"abc", Sha3Type::Shake128, Some(len)).unwrap(),
+            &shake128[..len]
+        );
+        assert_eq!(
+            sha3::hash(b"abc", Sha3Type::Shake256, Some(len)).unwrap(),
+            &shake256[..len]
+        );
+
+        let mut hasher = Sha3Hasher::new(Sha3Type::Shake256);
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize(Some(len)).unwrap(), &shake256[..len]);
+    }
+}
+
+#[test]
+fn shake128_empty() {
+    assert_eq!(
+        sha3::hash(b"", Sha3Type::Shake128, Some(32)).unwrap(),
+        h("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")
+    );
+}
+
+#[test]
+fn ripemd160_abc() {
+    assert_eq!(
+        ripemd160::hash(b"abc"),
+        h("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+    );
+    assert_eq!(
+        ripemd160::hash(b""),
+        h("9c1185a5c5e9fc54612808977ee8f548b2258d31")
+    );
+}
+
+// NESSIE Whirlpool test vectors.
+#[test]
+fn whirlpool_abc() {
+    assert_eq!(
+        whirlpool::hash(b"abc"),
+        h(
+            "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c\
+           7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5"
+        )
+    );
+    assert_eq!(
+        whirlpool::hash(b""),
+        h(
+            "19fa61d75522a4669b44e39c1d2e1726c530232130d407f89afee0964997f7a7\
+           3e83be698b288febcf88e3e03c4f0757ea8964e59b63d93708b138cc42a66eb3"
+        )
+    );
+}
+
+// RFC 7693 appendix A/B.
+#[test]
+fn blake2_abc() {
+    assert_eq!(
+        blake2::hash(b"abc", Blake2Type::Blake2s),
+        h("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982")
+    );
+    assert_eq!(
+        blake2::hash(b"abc", Blake2Type::Blake2b),
+        h(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+           7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        )
+    );
+}
+
+#[test]
+fn blake2_empty_streaming() {
+    let vectors = [
+        (
+            Blake2Type::Blake2s,
+            "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9",
+        ),
+        (
+            Blake2Type::Blake2b,
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+             d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce",
+        ),
+    ];
+    for (algo, expected) in vectors {
+        assert_eq!(Blake2Hasher::new(algo).finalize(), h(expected));
+    }
+}
+
+// BLAKE3 `test_vectors.json`, input_len 0.
+const BLAKE3_KEY: &[u8] = b"whats the Elvish word for friend";
+const BLAKE3_CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+#[test]
+fn blake3_empty() {
+    assert_eq!(
+        blake3::hash(b"", &Default::default()).unwrap(),
+        h("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+    );
+}
+
+#[test]
+fn blake3_keyed() {
+    let opts = HashOptions {
+        keyed: Some(BLAKE3_KEY.to_vec()),
+        ..Default::default()
+    };
+    assert_eq!(
+        blake3::hash(b"", &opts).unwrap(),
+        h("92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26")
+    );
+}
+
+#[test]
+fn blake3_derive_key() {
+    let opts = HashOptions {
+        derive_key: Some(BLAKE3_CONTEXT.into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        blake3::hash(b"", &opts).unwrap(),
+        h("2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d")
+    );
+}
+
+#[test]
+fn blake3_extended_output() {
+    let opts = HashOptions {
+        hash_length: Some(64),
+        ..Default::default()
+    };
+    let expected = h(
+        "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262\
+         e00f03e7b69af26b7faaf09fcd333050338ddfe085b8cc869ca98b206c08243a",
+    );
+    assert_eq!(blake3::hash(b"", &opts).unwrap(), expected);
+
+    let hasher = Blake3Hasher::new(&Default::default()).unwrap();
+    assert_eq!(hasher.finalize_xof(64).unwrap(), expected);
+}
+
+#[test]
+fn blake3_rejects_bad_key() {
+    let opts = HashOptions {
+        keyed: Some(vec![0; 31]),
+        ..Default::default()
+    };
+    assert!(blake3::hash(b"", &opts).is_err());
+}
+
+// ascon-c `LWC_HASH_KAT_128_256.txt`: messages are 00, 01, 02, ... of the
+// given length.
+const ASCON_HASH256: [(usize, &str); 6] = [
+    (
+        0,
+        "0b3be5850f2f6b98caf29f8fdea89b64a1fa70aa249b8f839bd53baa304d92b2",
+    ),
+    (
+        1,
+        "0728621035af3ed2bca03bf6fde900f9456f5330e4b5ee23e7f6a1e70291bc80",
+    ),
+    (
+        8,
+        "b88e497ae8e6fb641b87ef622eb8f2fca0ed95383f7ffebe167acf1099ba764f",
+    ),
+    (
+        33,
+        "a58665a2cb9530c502096a7957a76e428af4ad044b4da5c471f9da6f7b3e5868",
+    ),
+    (
+        64,
+        "a6f241bea5d16405812c06019d9f72d60132bd7c089c60549b2e56bb01c64f48",
+    ),
+    (
+        1024,
+        "48140032bb7df2e2b5c95d403c9ab69b4bc00453980bf85f15a84cae2b09a0e9",
+    ),
+];
+
+// ascon-c `LWC_HASH_KAT_128_256.txt` for Ascon-XOF128, 32 bytes of output.
+const ASCON_XOF128: [(usize, &str); 6] = [
+    (
+        0,
+        "473d5e6164f58b39dfd84aacdb8ae42ec2d91fed33388ee0d960d9b3993295c6",
+    ),
+    (
+        1,
+        "51430e0438ecdf642b393630d977625f5f337656ba58ab1e960784ac32a16e0d",
+    ),
+    (
+        8,
+        "8d1886f5d3ec4af8d15b44bc62b74da6ea91bc28fb82f9c34079b5ed6e38b6c9",
+    ),
+    (
+        33,
+        "fef74b7ebd183ba1d87bf414000b29258d6a2233a2a03ed519c646b351bc0084",
+    ),
+    (
+        64,
+        "0865c2fa92c71058e79e5c4214f3a1505540411586920536ccee85fbf2940b9f",
+    ),
+    (
+        1024,
+        "0419d6692d415cf4a9dfeec69910dd7199d018b042cb23f49f5ef1acde24e0fa",
+    ),
+];
+
+#[test]
+fn ascon_reference_kats() {
+    for (algo, vectors) in [
+        (AsconType::Hash256, ASCON_HASH256),
+        (AsconType::Xof128, ASCON_XOF128),
+    ] {
+        for (len, expected) in vectors {
+            let msg: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let expected = h(expected);
+            assert_eq!(
+                ascon::hash(&msg, algo, Some(32)).unwrap(),
+                expected,
+                "{:?} {} bytes",
+                algo,
+                len
+            );
+
+            let mut hasher = AsconHasher::new(algo);
+            for chunk in msg.chunks(7) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(Some(32)).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn ascon_xof128_lengths() {
+    let full = h(ASCON_XOF128[3].1);
+    let msg: Vec<u8> = (0..33).collect();
+    for len in [1, 16, 31] {
+        assert_eq!(
+            ascon::hash(&msg, AsconType::Xof128, Some(len)).unwrap(),
+            &full[..len]
+        );
+    }
+    assert_eq!(
+        ascon::hash(b"", AsconType::Xof128, Some(100))
+            .unwrap()
+            .len(),
+        100
+    );
+    assert!(ascon::hash(b"", AsconType::Xof128, None).is_err());
+    assert!(AsconHasher::new(AsconType::Xof128).finalize(None).is_err());
+    assert_eq!(
+        ascon::hash(b"", AsconType::Hash256, None).unwrap(),
+        h(ASCON_HASH256[0].1)
+    );
+}
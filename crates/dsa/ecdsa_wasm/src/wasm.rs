@@ -0,0 +1,38 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::dsa::ecdsa::{self, Curve};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+fn parse_curve(curve: JsValue) -> Result<Curve, JsValue> {
+    serde_wasm_bindgen::from_value(curve).map_err(|_| JsValue::from_str("Invalid curve"))
+}
+
+#[wasm_bindgen]
+pub fn ecdsa_generate_keypair(curve: JsValue) -> Result<js_sys::Array, JsValue> {
+    let which = parse_curve(curve)?;
+    let (sk, pk) = ecdsa::generate_keypair(which);
+    let arr = js_sys::Array::new();
+    arr.push(&Uint8Array::from(sk.as_slice()));
+    arr.push(&Uint8Array::from(pk.as_slice()));
+    Ok(arr)
+}
+
+#[wasm_bindgen]
+pub fn ecdsa_sign(curve: JsValue, private_key: Uint8Array, message_hash32: Uint8Array) -> Result<Uint8Array, JsValue> {
+    let which = parse_curve(curve)?;
+    let pk = secret_bytes(&private_key);
+    let m = input_bytes(&message_hash32);
+    let sig = ecdsa::sign(which, &pk, &m).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(sig.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn ecdsa_verify(curve: JsValue, public_key_uncompressed: Uint8Array, message_hash32: Uint8Array, signature_der: Uint8Array) -> Result<bool, JsValue> {
+    let which = parse_curve(curve)?;
+    let pk = input_bytes(&public_key_uncompressed);
+    let m = input_bytes(&message_hash32);
+    let sig_der = input_bytes(&signature_der);
+    ecdsa::verify(which, &pk, &m, &sig_der).map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,28 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::dsa::ed25519;
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn ed25519_generate_keypair() -> js_sys::Array {
+    let (sk, pk) = ed25519::generate_keypair();
+    let arr = js_sys::Array::new();
+    arr.push(&Uint8Array::from(sk.as_slice()));
+    arr.push(&Uint8Array::from(pk.as_slice()));
+    arr
+}
+
+#[wasm_bindgen]
+pub fn ed25519_sign(private_key: Uint8Array, message: Uint8Array) -> Result<Uint8Array, JsValue> {
+    let sig = ed25519::sign(&secret_bytes(&private_key), &input_bytes(&message))
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(sig.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn ed25519_verify(public_key: Uint8Array, message: Uint8Array, signature: Uint8Array) -> Result<bool, JsValue> {
+    ed25519::verify(&input_bytes(&public_key), &input_bytes(&message), &input_bytes(&signature))
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
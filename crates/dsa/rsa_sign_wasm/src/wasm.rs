@@ -0,0 +1,40 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::dsa::rsa_sign::{self, HashAlg};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+fn parse_hash(hash: JsValue) -> Result<HashAlg, JsValue> {
+    serde_wasm_bindgen::from_value(hash).map_err(|_| JsValue::from_str("Invalid hash algorithm"))
+}
+
+#[wasm_bindgen]
+pub fn rsa_pss_sign(private_key_der: Uint8Array, message: Uint8Array, hash: JsValue) -> Result<Uint8Array, JsValue> {
+    let which = parse_hash(hash)?;
+    let sig = rsa_sign::pss_sign(&secret_bytes(&private_key_der), &input_bytes(&message), which)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(sig.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn rsa_pss_verify(public_key_der: Uint8Array, message: Uint8Array, signature: Uint8Array, hash: JsValue) -> Result<bool, JsValue> {
+    let which = parse_hash(hash)?;
+    rsa_sign::pss_verify(&input_bytes(&public_key_der), &input_bytes(&message), &input_bytes(&signature), which)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn rsa_pkcs1v15_sign(private_key_der: Uint8Array, message: Uint8Array, hash: JsValue) -> Result<Uint8Array, JsValue> {
+    let which = parse_hash(hash)?;
+    let sig = rsa_sign::pkcs1v15_sign(&secret_bytes(&private_key_der), &input_bytes(&message), which)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(Uint8Array::from(sig.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn rsa_pkcs1v15_verify(public_key_der: Uint8Array, message: Uint8Array, signature: Uint8Array, hash: JsValue) -> Result<bool, JsValue> {
+    let which = parse_hash(hash)?;
+    rsa_sign::pkcs1v15_verify(&input_bytes(&public_key_der), &input_bytes(&message), &input_bytes(&signature), which)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
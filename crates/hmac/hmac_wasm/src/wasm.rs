@@ -0,0 +1,105 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::hmac::{self as core_hmac, HmacHasher};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum HashAlgorithm {
+    Md4,
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Ripemd160,
+    Ripemd256,
+    Ripemd320,
+    Whirlpool,
+}
+
+impl From<HashAlgorithm> for core_hmac::HashAlgorithm {
+    fn from(algo: HashAlgorithm) -> Self {
+        match algo {
+            HashAlgorithm::Md4 => Self::Md4,
+            HashAlgorithm::Md5 => Self::Md5,
+            HashAlgorithm::Sha1 => Self::Sha1,
+            HashAlgorithm::Sha224 => Self::Sha224,
+            HashAlgorithm::Sha256 => Self::Sha256,
+            HashAlgorithm::Sha384 => Self::Sha384,
+            HashAlgorithm::Sha512 => Self::Sha512,
+            HashAlgorithm::Sha512_224 => Self::Sha512_224,
+            HashAlgorithm::Sha512_256 => Self::Sha512_256,
+            HashAlgorithm::Sha3_224 => Self::Sha3_224,
+            HashAlgorithm::Sha3_256 => Self::Sha3_256,
+            HashAlgorithm::Sha3_384 => Self::Sha3_384,
+            HashAlgorithm::Sha3_512 => Self::Sha3_512,
+            HashAlgorithm::Ripemd160 => Self::Ripemd160,
+            HashAlgorithm::Ripemd256 => Self::Ripemd256,
+            HashAlgorithm::Ripemd320 => Self::Ripemd320,
+            HashAlgorithm::Whirlpool => Self::Whirlpool,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn hmac(
+    key: Uint8Array,
+    message: Uint8Array,
+    algo: HashAlgorithm,
+) -> Result<Box<[u8]>, JsValue> {
+    let key_input_slice = secret_bytes(&key);
+
+    let message_input_slice = input_bytes(&message);
+
+    core_hmac::hmac(&key_input_slice, &message_input_slice, algo.into())
+        .map(Vec::into_boxed_slice)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+pub struct StreamingHmac {
+    inner: Option<HmacHasher>,
+}
+
+#[wasm_bindgen]
+impl StreamingHmac {
+    #[wasm_bindgen(constructor)]
+    pub fn new(key: Uint8Array, algo: HashAlgorithm) -> Result<StreamingHmac, JsValue> {
+        let key_input_slice = secret_bytes(&key);
+
+        let inner = HmacHasher::new(&key_input_slice, algo.into())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingHmac { inner: Some(inner) })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        match self.inner.as_mut() {
+            Some(mac) => mac.update(&input_slice),
+            None => return Err(JsValue::from_str("HMAC has been finalized")),
+        }
+
+        Ok(())
+    }
+
+    pub fn finalize(&mut self) -> Result<Box<[u8]>, JsValue> {
+        let inner = self
+            .inner
+            .take()
+            .ok_or_else(|| JsValue::from_str("Already finalized"))?;
+
+        Ok(inner.finalize().into_boxed_slice())
+    }
+}
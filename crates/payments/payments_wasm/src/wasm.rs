@@ -0,0 +1,208 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::payments::{kcv, pin_block, tr31, KeyAlgorithm as CoreKeyAlgorithm};
+use cryptographer_core::wasm::{input_bytes, secret_bytes};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum KeyAlgorithm {
+    /// DES or 3DES (8, 16 or 24-byte key)
+    Tdes,
+    /// AES (16, 24 or 32-byte key)
+    Aes,
+}
+
+impl From<KeyAlgorithm> for CoreKeyAlgorithm {
+    fn from(algo: KeyAlgorithm) -> Self {
+        match algo {
+            KeyAlgorithm::Tdes => Self::Tdes,
+            KeyAlgorithm::Aes => Self::Aes,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum KcvMethod {
+    ZeroBlock,
+    Cmac,
+}
+
+impl From<KcvMethod> for kcv::KcvMethod {
+    fn from(method: KcvMethod) -> Self {
+        match method {
+            KcvMethod::ZeroBlock => Self::ZeroBlock,
+            KcvMethod::Cmac => Self::Cmac,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum PinBlockFormat {
+    Iso0,
+    Iso1,
+    Iso3,
+    Iso4,
+}
+
+impl From<PinBlockFormat> for pin_block::PinBlockFormat {
+    fn from(format: PinBlockFormat) -> Self {
+        match format {
+            PinBlockFormat::Iso0 => Self::Iso0,
+            PinBlockFormat::Iso1 => Self::Iso1,
+            PinBlockFormat::Iso3 => Self::Iso3,
+            PinBlockFormat::Iso4 => Self::Iso4,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub fn kcv(key: Uint8Array, algo: KeyAlgorithm, method: KcvMethod) -> Result<Uint8Array, JsValue> {
+    let key = secret_bytes(&key);
+
+    let kcv = kcv::kcv(&key, algo.into(), method.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(kcv.as_slice()))
+}
+
+/// Clear format 0, 1 or 3 PIN block.
+#[wasm_bindgen]
+pub fn pin_block_encode(
+    pin: &str,
+    pan: &str,
+    format: PinBlockFormat,
+) -> Result<Uint8Array, JsValue> {
+    let block = pin_block::encode(pin, pan, format.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(block.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn pin_block_decode(
+    block: Uint8Array,
+    pan: &str,
+    format: PinBlockFormat,
+) -> Result<String, JsValue> {
+    let block = secret_bytes(&block);
+
+    pin_block::decode(&block, pan, format.into()).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Enciphered PIN block: DES/3DES for formats 0, 1 and 3, AES for format 4.
+#[wasm_bindgen]
+pub fn pin_block_encrypt(
+    pin: &str,
+    pan: &str,
+    key: Uint8Array,
+    format: PinBlockFormat,
+) -> Result<Uint8Array, JsValue> {
+    let key = secret_bytes(&key);
+
+    let block = pin_block::encrypt(pin, pan, &key, format.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(Uint8Array::from(block.as_slice()))
+}
+
+#[wasm_bindgen]
+pub fn pin_block_decrypt(
+    block: Uint8Array,
+    pan: &str,
+    key: Uint8Array,
+    format: PinBlockFormat,
+) -> Result<String, JsValue> {
+    let block = input_bytes(&block);
+    let key = secret_bytes(&key);
+
+    pin_block::decrypt(&block, pan, &key, format.into())
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// `header` is the 16-character header, optionally followed by optional
+/// blocks; its key block length digits are ignored.
+#[wasm_bindgen]
+pub fn tr31_wrap(kbpk: Uint8Array, header: &str, key: Uint8Array) -> Result<String, JsValue> {
+    let kbpk = secret_bytes(&kbpk);
+    let key = secret_bytes(&key);
+
+    header
+        .parse::<tr31::KeyBlockHeader>()
+        .and_then(|header| tr31::wrap(&kbpk, &header, &key))
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Result of [`tr31_unwrap`]; `free()` drops the unwrapped key.
+#[wasm_bindgen]
+pub struct UnwrappedKeyBlock {
+    header: tr31::KeyBlockHeader,
+    key: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl UnwrappedKeyBlock {
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> String {
+        self.header.version.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn key_usage(&self) -> String {
+        self.header.key_usage.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> String {
+        self.header.algorithm.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mode_of_use(&self) -> String {
+        self.header.mode_of_use.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn key_version(&self) -> String {
+        self.header.key_version.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn exportability(&self) -> String {
+        self.header.exportability.to_string()
+    }
+
+    /// `[id, data]` pairs, without `PB` padding blocks.
+    #[wasm_bindgen(getter)]
+    pub fn optional_blocks(&self) -> Array {
+        self.header
+            .optional_blocks
+            .iter()
+            .map(|b| Array::of2(&JsValue::from_str(&b.id), &JsValue::from_str(&b.data)))
+            .collect()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn key(&self) -> Uint8Array {
+        Uint8Array::from(self.key.as_slice())
+    }
+}
+
+impl Drop for UnwrappedKeyBlock {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+#[wasm_bindgen]
+pub fn tr31_unwrap(kbpk: Uint8Array, key_block: &str) -> Result<UnwrappedKeyBlock, JsValue> {
+    let kbpk = secret_bytes(&kbpk);
+
+    let (header, key) =
+        tr31::unwrap(&kbpk, key_block).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(UnwrappedKeyBlock { header, key })
+}
@@ -0,0 +1,25 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::pha::bcrypt::{self, HashOptions};
+use cryptographer_core::wasm::secret_bytes;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsValue;
+
+#[wasm_bindgen]
+pub fn hash_password(password: Uint8Array, options: JsValue) -> Result<String, JsValue> {
+    let opts: HashOptions = serde_wasm_bindgen::from_value(options)
+        .map_err(|e| JsValue::from_str(&format!("Invalid options: {}", e)))?;
+
+    let password_bytes = secret_bytes(&password);
+
+    bcrypt::hash_password(&password_bytes, &opts).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn verify_password(password: Uint8Array, hashed_password: String) -> Result<bool, JsValue> {
+    let password_bytes = secret_bytes(&password);
+
+    bcrypt::verify_password(&password_bytes, &hashed_password)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
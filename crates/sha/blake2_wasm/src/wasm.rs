@@ -0,0 +1,61 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::blake2::{self, Blake2Hasher, Blake2Type};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+#[derive(Deserialize)]
+pub struct Blake2Options {
+    algo: Blake2Type,
+}
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
+    let opts: Blake2Options =
+        serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let input_slice = input_bytes(&input);
+
+    Ok(blake2::hash(&input_slice, opts.algo).into_boxed_slice())
+}
+
+#[wasm_bindgen]
+pub struct StreamingHasher {
+    inner: Option<Blake2Hasher>,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: JsValue) -> Result<StreamingHasher, JsValue> {
+        let opts: Blake2Options = serde_wasm_bindgen::from_value(options)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingHasher {
+            inner: Some(Blake2Hasher::new(opts.algo)),
+        })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        match &mut self.inner {
+            Some(h) => {
+                h.update(&input_slice);
+                Ok(())
+            }
+            None => Err(JsValue::from_str("Hasher has been finalized")),
+        }
+    }
+
+    pub fn finalize(&mut self) -> Result<Box<[u8]>, JsValue> {
+        let inner = self
+            .inner
+            .take()
+            .ok_or_else(|| JsValue::from_str("Already finalized"))?;
+
+        Ok(inner.finalize().into_boxed_slice())
+    }
+}
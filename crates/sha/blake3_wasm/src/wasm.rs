@@ -0,0 +1,71 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::blake3::{self, Blake3Hasher, HashOptions};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
+    let opts: HashOptions = serde_wasm_bindgen::from_value(options)
+        .map_err(|e| JsValue::from_str(&format!("Invalid options: {}", e)))?;
+
+    let input_slice = input_bytes(&input);
+
+    blake3::hash(&input_slice, &opts)
+        .map(Vec::into_boxed_slice)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+pub struct StreamingHasher {
+    inner: Option<Blake3Hasher>,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: JsValue) -> Result<StreamingHasher, JsValue> {
+        let opts: HashOptions = serde_wasm_bindgen::from_value(options)
+            .map_err(|e| JsValue::from_str(&format!("Invalid options: {}", e)))?;
+
+        let hasher = Blake3Hasher::new(&opts).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(StreamingHasher {
+            inner: Some(hasher),
+        })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        if let Some(ref mut hasher) = self.inner {
+            let input_slice = input_bytes(&input);
+
+            hasher.update(&input_slice);
+
+            Ok(())
+        } else {
+            Err(JsValue::from_str("Hasher has been finalized"))
+        }
+    }
+
+    pub fn finalize(&mut self) -> Result<Box<[u8]>, JsValue> {
+        match self.inner.take() {
+            Some(hasher) => Ok(hasher.finalize().into_boxed_slice()),
+            None => Err(JsValue::from_str("Already finalized")),
+        }
+    }
+
+    pub fn finalize_xof(&mut self, length: usize) -> Result<Box<[u8]>, JsValue> {
+        if length > blake3::MAX_HASH_LENGTH {
+            return Err(JsValue::from_str("Hash length must be <= 1024"));
+        }
+
+        match self.inner.take() {
+            Some(hasher) => hasher
+                .finalize_xof(length)
+                .map(Vec::into_boxed_slice)
+                .map_err(|e| JsValue::from_str(&e.to_string())),
+            None => Err(JsValue::from_str("Already finalized")),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::md4::{self, Md4Hasher};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+#[derive(Deserialize)]
+struct HashOptions {
+    #[serde(default)]
+    hash_length: Option<usize>,
+}
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
+    let opts: HashOptions =
+        serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let input_slice = input_bytes(&input);
+
+    md4::hash(&input_slice, opts.hash_length)
+        .map(Vec::into_boxed_slice)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct StreamingHasher {
+    inner: Md4Hasher,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> StreamingHasher {
+        StreamingHasher {
+            inner: Md4Hasher::new(),
+        }
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        self.inner.update(&input_slice);
+
+        Ok(())
+    }
+
+    pub fn finalize(&self) -> Box<[u8]> {
+        self.inner.clone().finalize().into_boxed_slice()
+    }
+
+    pub fn finalize_xof(&self, length: usize) -> Result<Box<[u8]>, JsValue> {
+        self.inner
+            .clone()
+            .finalize_xof(length)
+            .map(Vec::into_boxed_slice)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
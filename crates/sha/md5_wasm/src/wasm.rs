@@ -0,0 +1,41 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::md5::{self, Md5Hasher};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array) -> Box<[u8]> {
+    let input_slice = input_bytes(&input);
+
+    md5::hash(&input_slice).into_boxed_slice()
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct StreamingMd5 {
+    hasher: Md5Hasher,
+}
+
+#[wasm_bindgen]
+impl StreamingMd5 {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> StreamingMd5 {
+        StreamingMd5 {
+            hasher: Md5Hasher::new(),
+        }
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        self.hasher.update(&input_slice);
+
+        Ok(())
+    }
+
+    pub fn finalize(&mut self) -> Box<[u8]> {
+        self.hasher.clone().finalize().into_boxed_slice()
+    }
+}
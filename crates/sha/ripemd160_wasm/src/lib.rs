@@ -1,57 +1,14 @@
-use js_sys::Uint8Array;
-use ripemd::{Digest, Ripemd160};
-use wasm_bindgen::prelude::*;
+//! Thin wrapper around [`cryptographer_core::sha::ripemd160`].
+//!
+//! With the `wasm` feature enabled this crate exports the wasm-bindgen
+//! bindings used by the Node package; without it, it re-exports the native
+//! Rust API so the crate can be built and tested on any target.
 
-#[wasm_bindgen]
-pub fn hash(input: Uint8Array) -> Box<[u8]> {
-    let mut hasher = Ripemd160::new();
+#[cfg(feature = "wasm")]
+mod wasm;
 
-    let offset = input.byte_offset() as usize;
+#[cfg(feature = "wasm")]
+pub use wasm::*;
 
-    let len = input.length() as usize;
-
-    let ptr = offset as *const u8;
-
-    let input_slice = unsafe { std::slice::from_raw_parts(ptr, len) };
-
-    hasher.update(input_slice);
-
-    let full_hash = hasher.finalize().to_vec();
-
-    full_hash.into_boxed_slice()
-}
-
-#[wasm_bindgen]
-pub struct StreamingHasher {
-    hasher: Ripemd160,
-}
-
-#[wasm_bindgen]
-impl StreamingHasher {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Self {
-        StreamingHasher {
-            hasher: Ripemd160::new(),
-        }
-    }
-
-    #[wasm_bindgen]
-    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
-        let offset = input.byte_offset() as usize;
-
-        let len = input.length() as usize;
-
-        let ptr = offset as *const u8;
-
-        let input_slice = unsafe { std::slice::from_raw_parts(ptr, len) };
-
-        self.hasher.update(input_slice);
-
-        Ok(())
-    }
-
-    #[wasm_bindgen]
-    pub fn finalize(&self) -> Box<[u8]> {
-        self.hasher.clone().finalize().to_vec().into_boxed_slice()
-    }
-}
+#[cfg(not(feature = "wasm"))]
+pub use cryptographer_core::sha::ripemd160::*;
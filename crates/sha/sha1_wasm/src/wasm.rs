@@ -0,0 +1,41 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::sha1::{self, Sha1Hasher};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array) -> Box<[u8]> {
+    let input_slice = input_bytes(&input);
+
+    sha1::hash(&input_slice).into_boxed_slice()
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct StreamingSha1 {
+    inner: Sha1Hasher,
+}
+
+#[wasm_bindgen]
+impl StreamingSha1 {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> StreamingSha1 {
+        StreamingSha1 {
+            inner: Sha1Hasher::new(),
+        }
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        self.inner.update(&input_slice);
+
+        Ok(())
+    }
+
+    pub fn finalize(&self) -> Box<[u8]> {
+        self.inner.clone().finalize().into_boxed_slice()
+    }
+}
@@ -0,0 +1,63 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::sha2::{self, Sha2Hasher, ShaType};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+#[derive(Deserialize)]
+struct HashOptions {
+    #[serde(default)]
+    algo: Option<ShaType>,
+}
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
+    let opts: HashOptions =
+        serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from(e.to_string()))?;
+
+    let input_slice = input_bytes(&input);
+
+    Ok(sha2::hash(&input_slice, opts.algo.unwrap_or_default()).into_boxed_slice())
+}
+
+#[wasm_bindgen]
+pub struct StreamingHasher {
+    inner: Option<Sha2Hasher>,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: JsValue) -> Result<StreamingHasher, JsValue> {
+        let opts: HashOptions =
+            serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from(e.to_string()))?;
+
+        Ok(Self {
+            inner: Some(Sha2Hasher::new(opts.algo.unwrap_or_default())),
+        })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        match &mut self.inner {
+            Some(h) => {
+                h.update(&input_slice);
+
+                Ok(())
+            }
+            None => Err(JsValue::from_str("Hasher has been finalized")),
+        }
+    }
+
+    pub fn finalize(&mut self) -> Result<Box<[u8]>, JsValue> {
+        let inner = self
+            .inner
+            .take()
+            .ok_or_else(|| JsValue::from_str("Already finalized"))?;
+
+        Ok(inner.finalize().into_boxed_slice())
+    }
+}
@@ -1,195 +1,14 @@
-use js_sys::Uint8Array;
-use serde::Deserialize;
-use sha3::{
-    digest::{Digest, ExtendableOutput, Update, XofReader},
-    Keccak224, Keccak256, Keccak384, Keccak512, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128,
-    Shake256,
-};
-use wasm_bindgen::prelude::*;
+//! Thin wrapper around [`cryptographer_core::sha::sha3`].
+//!
+//! With the `wasm` feature enabled this crate exports the wasm-bindgen
+//! bindings used by the Node package; without it, it re-exports the native
+//! Rust API so the crate can be built and tested on any target.
 
-#[derive(Deserialize, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum Sha3Type {
-    Sha3_224,
-    Sha3_256,
-    Sha3_384,
-    Sha3_512,
-    Shake128,
-    Shake256,
-    Keccak224,
-    Keccak256,
-    Keccak384,
-    Keccak512,
-}
+#[cfg(feature = "wasm")]
+mod wasm;
 
-#[derive(Deserialize)]
-struct HashOptions {
-    algo: Sha3Type,
-    #[serde(default)]
-    pub hash_length: Option<usize>,
-}
+#[cfg(feature = "wasm")]
+pub use wasm::*;
 
-#[wasm_bindgen]
-pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
-    let opts: HashOptions =
-        serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from_str(&e.to_string()))?;
-
-    let offset = input.byte_offset() as usize;
-
-    let len = input.length() as usize;
-
-    let ptr = offset as *const u8;
-
-    let input_slice = unsafe { std::slice::from_raw_parts(ptr, len) };
-
-    let output = match opts.algo {
-        Sha3Type::Sha3_224 => Sha3_224::digest(input_slice).to_vec(),
-        Sha3Type::Sha3_256 => Sha3_256::digest(input_slice).to_vec(),
-        Sha3Type::Sha3_384 => Sha3_384::digest(input_slice).to_vec(),
-        Sha3Type::Sha3_512 => Sha3_512::digest(input_slice).to_vec(),
-        Sha3Type::Keccak224 => Keccak224::digest(input_slice).to_vec(),
-        Sha3Type::Keccak256 => Keccak256::digest(input_slice).to_vec(),
-        Sha3Type::Keccak384 => Keccak384::digest(input_slice).to_vec(),
-        Sha3Type::Keccak512 => Keccak512::digest(input_slice).to_vec(),
-        Sha3Type::Shake128 => {
-            let len = opts.hash_length.ok_or("Shake128 requires hash_length")?;
-
-            let mut hasher = Shake128::default();
-
-            hasher.update(input_slice);
-
-            let mut buf = vec![0u8; len];
-
-            hasher.finalize_xof().read(&mut buf);
-
-            buf
-        }
-        Sha3Type::Shake256 => {
-            let len = opts.hash_length.ok_or("Shake256 requires hash_length")?;
-
-            let mut hasher = Shake256::default();
-
-            hasher.update(input_slice);
-
-            let mut buf = vec![0u8; len];
-
-            hasher.finalize_xof().read(&mut buf);
-
-            buf
-        }
-    };
-
-    Ok(output.into_boxed_slice())
-}
-
-#[wasm_bindgen]
-pub struct StreamingHasher {
-    inner: StreamingInner,
-}
-
-enum StreamingInner {
-    Digest(DigestImpl),
-    Xof(XofImpl),
-}
-
-enum DigestImpl {
-    Sha3_224(Sha3_224),
-    Sha3_256(Sha3_256),
-    Sha3_384(Sha3_384),
-    Sha3_512(Sha3_512),
-    Keccak224(Keccak224),
-    Keccak256(Keccak256),
-    Keccak384(Keccak384),
-    Keccak512(Keccak512),
-}
-
-enum XofImpl {
-    Shake128(Shake128),
-    Shake256(Shake256),
-}
-
-#[wasm_bindgen]
-impl StreamingHasher {
-    #[wasm_bindgen(constructor)]
-    pub fn new(options: JsValue) -> Result<StreamingHasher, JsValue> {
-        let opts: HashOptions = serde_wasm_bindgen::from_value(options)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
-
-        let inner = match opts.algo {
-            Sha3Type::Sha3_224 => StreamingInner::Digest(DigestImpl::Sha3_224(Sha3_224::new())),
-            Sha3Type::Sha3_256 => StreamingInner::Digest(DigestImpl::Sha3_256(Sha3_256::new())),
-            Sha3Type::Sha3_384 => StreamingInner::Digest(DigestImpl::Sha3_384(Sha3_384::new())),
-            Sha3Type::Sha3_512 => StreamingInner::Digest(DigestImpl::Sha3_512(Sha3_512::new())),
-            Sha3Type::Keccak224 => StreamingInner::Digest(DigestImpl::Keccak224(Keccak224::new())),
-            Sha3Type::Keccak256 => StreamingInner::Digest(DigestImpl::Keccak256(Keccak256::new())),
-            Sha3Type::Keccak384 => StreamingInner::Digest(DigestImpl::Keccak384(Keccak384::new())),
-            Sha3Type::Keccak512 => StreamingInner::Digest(DigestImpl::Keccak512(Keccak512::new())),
-            Sha3Type::Shake128 => StreamingInner::Xof(XofImpl::Shake128(Shake128::default())),
-            Sha3Type::Shake256 => StreamingInner::Xof(XofImpl::Shake256(Shake256::default())),
-        };
-
-        Ok(Self { inner })
-    }
-
-    pub fn update(&mut self, input: Uint8Array) {
-        let offset = input.byte_offset() as usize;
-
-        let len = input.length() as usize;
-
-        let ptr = offset as *const u8;
-
-        let input_slice = unsafe { std::slice::from_raw_parts(ptr, len) };
-
-        match &mut self.inner {
-            StreamingInner::Digest(d) => match d {
-                DigestImpl::Sha3_224(h) => Digest::update(h, input_slice),
-                DigestImpl::Sha3_256(h) => Digest::update(h, input_slice),
-                DigestImpl::Sha3_384(h) => Digest::update(h, input_slice),
-                DigestImpl::Sha3_512(h) => Digest::update(h, input_slice),
-                DigestImpl::Keccak224(h) => Digest::update(h, input_slice),
-                DigestImpl::Keccak256(h) => Digest::update(h, input_slice),
-                DigestImpl::Keccak384(h) => Digest::update(h, input_slice),
-                DigestImpl::Keccak512(h) => Digest::update(h, input_slice),
-            },
-            StreamingInner::Xof(x) => match x {
-                XofImpl::Shake128(h) => h.update(input_slice),
-                XofImpl::Shake256(h) => h.update(input_slice),
-            },
-        }
-    }
-
-    pub fn finalize(&mut self, hash_length: Option<usize>) -> Result<Box<[u8]>, JsValue> {
-        match std::mem::replace(
-            &mut self.inner,
-            StreamingInner::Digest(DigestImpl::Sha3_224(Sha3_224::new())),
-        ) {
-            StreamingInner::Digest(d) => {
-                let result = match d {
-                    DigestImpl::Sha3_224(h) => h.finalize().to_vec(),
-                    DigestImpl::Sha3_256(h) => h.finalize().to_vec(),
-                    DigestImpl::Sha3_384(h) => h.finalize().to_vec(),
-                    DigestImpl::Sha3_512(h) => h.finalize().to_vec(),
-                    DigestImpl::Keccak224(h) => h.finalize().to_vec(),
-                    DigestImpl::Keccak256(h) => h.finalize().to_vec(),
-                    DigestImpl::Keccak384(h) => h.finalize().to_vec(),
-                    DigestImpl::Keccak512(h) => h.finalize().to_vec(),
-                };
-
-                Ok(result.into_boxed_slice())
-            }
-            StreamingInner::Xof(x) => {
-                let len =
-                    hash_length.ok_or_else(|| JsValue::from_str("XOF requires hash_length"))?;
-
-                let mut buf = vec![0u8; len];
-
-                match x {
-                    XofImpl::Shake128(h) => h.finalize_xof().read(&mut buf),
-                    XofImpl::Shake256(h) => h.finalize_xof().read(&mut buf),
-                };
-
-                Ok(buf.into_boxed_slice())
-            }
-        }
-    }
-}
+#[cfg(not(feature = "wasm"))]
+pub use cryptographer_core::sha::sha3::*;
@@ -0,0 +1,57 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::sha3::{self, Sha3Hasher, Sha3Type};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+#[derive(Deserialize)]
+struct HashOptions {
+    algo: Sha3Type,
+    #[serde(default)]
+    pub hash_length: Option<usize>,
+}
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array, options: JsValue) -> Result<Box<[u8]>, JsValue> {
+    let opts: HashOptions =
+        serde_wasm_bindgen::from_value(options).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let input_slice = input_bytes(&input);
+
+    sha3::hash(&input_slice, opts.algo, opts.hash_length)
+        .map(Vec::into_boxed_slice)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen]
+pub struct StreamingHasher {
+    inner: Sha3Hasher,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: JsValue) -> Result<StreamingHasher, JsValue> {
+        let opts: HashOptions = serde_wasm_bindgen::from_value(options)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(Self {
+            inner: Sha3Hasher::new(opts.algo),
+        })
+    }
+
+    pub fn update(&mut self, input: Uint8Array) {
+        let input_slice = input_bytes(&input);
+
+        self.inner.update(&input_slice);
+    }
+
+    pub fn finalize(&mut self, hash_length: Option<usize>) -> Result<Box<[u8]>, JsValue> {
+        std::mem::replace(&mut self.inner, Sha3Hasher::new(Sha3Type::Sha3_224))
+            .finalize(hash_length)
+            .map(Vec::into_boxed_slice)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
@@ -0,0 +1,43 @@
+//! wasm-bindgen bindings, built with `--features wasm`.
+
+use cryptographer_core::sha::whirlpool::{self, WhirlpoolHasher};
+use cryptographer_core::wasm::input_bytes;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn hash(input: Uint8Array) -> Box<[u8]> {
+    let input_slice = input_bytes(&input);
+
+    whirlpool::hash(&input_slice).into_boxed_slice()
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct StreamingHasher {
+    inner: WhirlpoolHasher,
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> StreamingHasher {
+        StreamingHasher {
+            inner: WhirlpoolHasher::new(),
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn update(&mut self, input: Uint8Array) -> Result<(), JsValue> {
+        let input_slice = input_bytes(&input);
+
+        self.inner.update(&input_slice);
+
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub fn finalize(&self) -> Box<[u8]> {
+        self.inner.clone().finalize().into_boxed_slice()
+    }
+}
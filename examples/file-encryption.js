@@ -342,64 +342,92 @@ console.log();
 console.log('🌊 Streaming File Encryption (for large files):');
 
 class StreamingFileEncryption {
-  /**
-   * Encrypt large files in chunks to avoid memory issues
-   */
-  encryptLargeFile(inputPath, outputPath, password, chunkSize = 64 * 1024) {
-    const salt = nodeCrypto.randomBytes(32);
-    const iv = nodeCrypto.randomBytes(16);
-    
-    // Derive key
-    const key = crypto.kdf.pbkdf2(password, {
+  deriveKey(password, salt) {
+    return crypto.kdf.pbkdf2(password, {
       salt: salt,
       iterations: 100000,
       keyLength: 32,
       outputFormat: 'buffer'
     });
-    
-    // Create header
+  }
+
+  /**
+   * Encrypt large files in chunks with AES-256-GCM STREAM: every segment
+   * carries its own tag, so only one chunk is held in memory at a time
+   */
+  encryptLargeFile(inputPath, outputPath, password, chunkSize = 64 * 1024) {
+    const salt = nodeCrypto.randomBytes(32);
+    const nonce = nodeCrypto.randomBytes(7); // STREAM nonce prefix
+
     const header = {
-      version: '1.0',
-      algorithm: 'AES-256-CBC',
+      version: '2.0',
+      algorithm: 'AES-256-GCM-STREAM',
       kdf: 'PBKDF2',
       salt: salt.toString('base64'),
-      iv: iv.toString('base64'),
-      chunkSize: chunkSize
+      nonce: nonce.toString('base64'),
+      segmentSize: chunkSize
     };
-    
+
     const headerData = Buffer.from(JSON.stringify(header));
     const headerLength = Buffer.alloc(4);
     headerLength.writeUInt32BE(headerData.length, 0);
-    
-    // Write header to output file
     fs.writeFileSync(outputPath, Buffer.concat([headerLength, headerData]));
-    
-    // Process file in chunks
+
+    const encryptor = crypto.cipher.aes.createStreamingEncryptor({
+      key: this.deriveKey(password, salt),
+      iv: nonce,
+      segmentSize: chunkSize
+    });
+
     const inputFd = fs.openSync(inputPath, 'r');
     const outputFd = fs.openSync(outputPath, 'a');
-    
     try {
       const buffer = Buffer.alloc(chunkSize);
-      let position = 0;
-      
-      while (true) {
-        const bytesRead = fs.readSync(inputFd, buffer, 0, chunkSize, position);
-        if (bytesRead === 0) break;
-        
-        const chunk = buffer.slice(0, bytesRead);
-        
-        // For simplicity, we're not implementing proper streaming cipher mode
-        // In a real implementation, you'd use CTR mode or implement proper padding
-        console.log(`Processing chunk at position ${position}, size: ${bytesRead}`);
-        
+      let bytesRead;
+      while ((bytesRead = fs.readSync(inputFd, buffer, 0, chunkSize, null)) > 0) {
+        fs.writeSync(outputFd, encryptor.update(buffer.subarray(0, bytesRead)));
+      }
+      fs.writeSync(outputFd, encryptor.final());
+    } finally {
+      fs.closeSync(inputFd);
+      fs.closeSync(outputFd);
+    }
+    console.log(`✓ Large file encryption completed: ${outputPath}`);
+  }
+
+  /**
+   * Decrypt a file written by encryptLargeFile; throws if any segment was
+   * altered, reordered or the file was cut short
+   */
+  decryptLargeFile(inputPath, outputPath, password) {
+    const inputFd = fs.openSync(inputPath, 'r');
+    const outputFd = fs.openSync(outputPath, 'w');
+    try {
+      const lengthBuf = Buffer.alloc(4);
+      fs.readSync(inputFd, lengthBuf, 0, 4, 0);
+      const headerData = Buffer.alloc(lengthBuf.readUInt32BE(0));
+      fs.readSync(inputFd, headerData, 0, headerData.length, 4);
+      const header = JSON.parse(headerData.toString());
+
+      const decryptor = crypto.cipher.aes.createStreamingDecryptor({
+        key: this.deriveKey(password, Buffer.from(header.salt, 'base64')),
+        iv: Buffer.from(header.nonce, 'base64'),
+        segmentSize: header.segmentSize
+      });
+
+      const buffer = Buffer.alloc(header.segmentSize + 16);
+      let position = 4 + headerData.length;
+      let bytesRead;
+      while ((bytesRead = fs.readSync(inputFd, buffer, 0, buffer.length, position)) > 0) {
+        fs.writeSync(outputFd, decryptor.update(buffer.subarray(0, bytesRead)));
         position += bytesRead;
       }
-      
-      console.log(`✓ Large file encryption completed: ${outputPath}`);
+      fs.writeSync(outputFd, decryptor.final());
     } finally {
       fs.closeSync(inputFd);
       fs.closeSync(outputFd);
     }
+    console.log(`✓ Large file decryption completed: ${outputPath}`);
   }
 }
 
@@ -409,9 +437,12 @@ const largeFile = createTempFile('large.txt', largeContent);
 
 console.log(`Large file size: ${fs.statSync(largeFile).size} bytes`);
 
-// Note: This is a simplified demonstration
-// In production, you'd want to implement proper streaming encryption
-console.log('Note: Streaming encryption is conceptual in this demo');
+const streaming = new StreamingFileEncryption();
+const largeEncrypted = largeFile + '.enc';
+const largeDecrypted = largeFile + '.dec';
+streaming.encryptLargeFile(largeFile, largeEncrypted, password, 4096);
+streaming.decryptLargeFile(largeEncrypted, largeDecrypted, password);
+console.log(`Round trip matches: ${fs.readFileSync(largeDecrypted, 'utf8') === largeContent}`);
 
 console.log();
 
@@ -152,6 +152,16 @@ echo "Building WASM for $CRATE_NAME..."
 cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
 wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
 
+# ------- CAMELLIA/ARIA/SM4 WASM -------
+CRATE_NAME="block_wasm"
+TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
+
+mkdir -p "$TARGET_DIR"
+
+echo "Building WASM for $CRATE_NAME..."
+cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
+wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
+
 # ------- CHACHA20 WASM -------
 CRATE_NAME="chacha20_wasm"
 TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
@@ -162,6 +172,16 @@ echo "Building WASM for $CRATE_NAME..."
 cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
 wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
 
+# ------- ASCON WASM -------
+CRATE_NAME="ascon_wasm"
+TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
+
+mkdir -p "$TARGET_DIR"
+
+echo "Building WASM for $CRATE_NAME..."
+cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
+wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
+
 # ------- DES/3DES WASM -------
 CRATE_NAME="des_wasm"
 TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
@@ -198,6 +218,25 @@ TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
 
 mkdir -p "$TARGET_DIR"
 
+echo "Building WASM for $CRATE_NAME..."
+cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
+RUSTFLAGS='--cfg getrandom_backend="wasm_js"' wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
+# ------- HPKE WASM -------
+CRATE_NAME="hpke_wasm"
+TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
+
+mkdir -p "$TARGET_DIR"
+
+echo "Building WASM for $CRATE_NAME..."
+cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
+RUSTFLAGS='--cfg getrandom_backend="wasm_js"' wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
+
+# ------- ECIES WASM -------
+CRATE_NAME="ecies_wasm"
+TARGET_DIR="$SCRIPT_DIR/../packages/cipher/$CRATE_NAME"
+
+mkdir -p "$TARGET_DIR"
+
 echo "Building WASM for $CRATE_NAME..."
 cd "$SCRIPT_DIR/../crates/cipher/$CRATE_NAME"
 RUSTFLAGS='--cfg getrandom_backend="wasm_js"' wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
@@ -233,3 +272,15 @@ mkdir -p "$TARGET_DIR"
 echo "Building WASM for $CRATE_NAME..."
 cd "$SCRIPT_DIR/../crates/dsa/$CRATE_NAME"
 RUSTFLAGS='--cfg getrandom_backend="wasm_js"' wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
+
+####### PAYMENTS #######
+
+# ------- PIN blocks / KCV / TR-31 WASM -------
+CRATE_NAME="payments_wasm"
+TARGET_DIR="$SCRIPT_DIR/../packages/payments/$CRATE_NAME"
+
+mkdir -p "$TARGET_DIR"
+
+echo "Building WASM for $CRATE_NAME..."
+cd "$SCRIPT_DIR/../crates/payments/$CRATE_NAME"
+RUSTFLAGS='--cfg getrandom_backend="wasm_js"' wasm-pack build --target nodejs --release --out-dir "$TARGET_DIR" -- --features wasm
@@ -50,3 +50,8 @@ rm -rf "$SCRIPT_DIR/../crates/pha/pbkdf2_wasm/target" "$SCRIPT_DIR/../crates/pha
 
 # clean up the build artifacts and directories of the AES WASM crate
 rm -rf "$SCRIPT_DIR/../crates/cipher/aes_wasm/target" "$SCRIPT_DIR/../crates/cipher/aes_wasm/Cargo.lock" "$SCRIPT_DIR/../packages/cipher/aes_wasm"
+
+# ------- WORKSPACE -------
+
+# clean up the shared workspace build artifacts (all crates build into the root target)
+rm -rf "$SCRIPT_DIR/../target"
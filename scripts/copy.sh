@@ -31,17 +31,24 @@ done
 
 # CIPHER
 copy_crate "$PKG_DIR/cipher/aes_wasm" "$DIST_DIR/cipher/aes_wasm"
+copy_crate "$PKG_DIR/cipher/block_wasm" "$DIST_DIR/cipher/block_wasm"
 copy_crate "$PKG_DIR/cipher/chacha20_wasm" "$DIST_DIR/cipher/chacha20_wasm"
+copy_crate "$PKG_DIR/cipher/ascon_wasm" "$DIST_DIR/cipher/ascon_wasm"
 copy_crate "$PKG_DIR/cipher/des_wasm" "$DIST_DIR/cipher/des_wasm"
 copy_crate "$PKG_DIR/cipher/rsa_wasm" "$DIST_DIR/cipher/rsa_wasm"
 copy_crate "$PKG_DIR/cipher/x25519_wasm" "$DIST_DIR/cipher/x25519_wasm"
 copy_crate "$PKG_DIR/cipher/ecdh_wasm" "$DIST_DIR/cipher/ecdh_wasm"
+copy_crate "$PKG_DIR/cipher/hpke_wasm" "$DIST_DIR/cipher/hpke_wasm"
+copy_crate "$PKG_DIR/cipher/ecies_wasm" "$DIST_DIR/cipher/ecies_wasm"
 
 # DSA
 copy_crate "$PKG_DIR/dsa/ed25519_wasm" "$DIST_DIR/dsa/ed25519_wasm"
 copy_crate "$PKG_DIR/dsa/ecdsa_wasm" "$DIST_DIR/dsa/ecdsa_wasm"
 copy_crate "$PKG_DIR/dsa/rsa_sign_wasm" "$DIST_DIR/dsa/rsa_sign_wasm"
 
+# PAYMENTS
+copy_crate "$PKG_DIR/payments/payments_wasm" "$DIST_DIR/payments/payments_wasm"
+
 echo "Copied WASM artifacts into dist/* layout."
 
 
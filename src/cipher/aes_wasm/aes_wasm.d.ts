@@ -1,7 +1,48 @@
 /* tslint:disable */
 /* eslint-disable */
-export function encrypt(plaintext: Uint8Array, key: Uint8Array, nonce_or_iv: Uint8Array, algo: AesAlgorithm): Uint8Array;
-export function decrypt(ciphertext: Uint8Array, key: Uint8Array, nonce_or_iv: Uint8Array, algo: AesAlgorithm): Uint8Array;
+export function encrypt(plaintext: Uint8Array, key: Uint8Array, nonce_or_iv: Uint8Array, algo: AesAlgorithm, aad?: Uint8Array | null, padding?: Padding | null): Uint8Array;
+export function decrypt(ciphertext: Uint8Array, key: Uint8Array, nonce_or_iv: Uint8Array, algo: AesAlgorithm, aad?: Uint8Array | null, padding?: Padding | null): Uint8Array;
+export function wrap_key(key_data: Uint8Array, kek: Uint8Array, algo: AesAlgorithm): Uint8Array;
+export function unwrap_key(wrapped: Uint8Array, kek: Uint8Array, algo: AesAlgorithm): Uint8Array;
+export function xts_encrypt_sectors(data: Uint8Array, key: Uint8Array, sector_size: number, first_sector: bigint, algo: AesAlgorithm): Uint8Array;
+export function xts_decrypt_sectors(data: Uint8Array, key: Uint8Array, sector_size: number, first_sector: bigint, algo: AesAlgorithm): Uint8Array;
+export function siv_encrypt(plaintext: Uint8Array, key: Uint8Array, headers: Uint8Array[], algo: AesAlgorithm): Uint8Array;
+export function siv_decrypt(ciphertext: Uint8Array, key: Uint8Array, headers: Uint8Array[], algo: AesAlgorithm): Uint8Array;
+export function encrypt_detached(plaintext: Uint8Array, key: Uint8Array, nonce: Uint8Array, algo: AesAlgorithm, aad?: Uint8Array | null, tag_length?: number | null): [Uint8Array, Uint8Array];
+export function decrypt_detached(ciphertext: Uint8Array, tag: Uint8Array, key: Uint8Array, nonce: Uint8Array, algo: AesAlgorithm, aad?: Uint8Array | null): Uint8Array;
+export class StreamingEncryptor {
+  free(): void;
+  constructor(key: Uint8Array, nonce: Uint8Array, algo: AesAlgorithm, segment_size?: number | null);
+  update(plaintext: Uint8Array): Uint8Array;
+  finalize(): Uint8Array;
+}
+export class StreamingDecryptor {
+  free(): void;
+  constructor(key: Uint8Array, nonce: Uint8Array, algo: AesAlgorithm, segment_size?: number | null);
+  update(ciphertext: Uint8Array): Uint8Array;
+  finalize(): Uint8Array;
+}
+export class AesGcmCipher {
+  free(): void;
+  constructor(key: Uint8Array, algo: AesAlgorithm);
+  encrypt(plaintext: Uint8Array, nonce: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+  decrypt(ciphertext: Uint8Array, nonce: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+}
+export class AesSivCipher {
+  free(): void;
+  constructor(key: Uint8Array, algo: AesAlgorithm);
+  encrypt(plaintext: Uint8Array, nonce: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+  decrypt(ciphertext: Uint8Array, nonce: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+  encrypt_with_headers(plaintext: Uint8Array, headers: Uint8Array[]): Uint8Array;
+  decrypt_with_headers(ciphertext: Uint8Array, headers: Uint8Array[]): Uint8Array;
+}
+export class Keystream {
+  free(): void;
+  constructor(key: Uint8Array, iv: Uint8Array, algo: AesAlgorithm, layout?: CounterLayout | null);
+  apply(data: Uint8Array): Uint8Array;
+  seek(offset: bigint): void;
+  readonly position: bigint;
+}
 export enum AesAlgorithm {
   Aes128Gcm = 0,
   Aes192Gcm = 1,
@@ -14,4 +55,49 @@ export enum AesAlgorithm {
   Aes256Ccm = 8,
   Aes128Siv = 9,
   Aes256Siv = 10,
+  Aes128GcmSiv = 11,
+  Aes256GcmSiv = 12,
+  Aes128Cbc = 13,
+  Aes192Cbc = 14,
+  Aes256Cbc = 15,
+  Aes128Ecb = 16,
+  Aes192Ecb = 17,
+  Aes256Ecb = 18,
+  Aes128Cfb8 = 19,
+  Aes192Cfb8 = 20,
+  Aes256Cfb8 = 21,
+  Aes128Cfb128 = 22,
+  Aes192Cfb128 = 23,
+  Aes256Cfb128 = 24,
+  Aes128Ofb = 25,
+  Aes192Ofb = 26,
+  Aes256Ofb = 27,
+  Aes128Kw = 28,
+  Aes192Kw = 29,
+  Aes256Kw = 30,
+  Aes128Kwp = 31,
+  Aes192Kwp = 32,
+  Aes256Kwp = 33,
+  Aes128Xts = 34,
+  Aes256Xts = 35,
+  Aes128Ocb3 = 36,
+  Aes192Ocb3 = 37,
+  Aes256Ocb3 = 38,
+  Aes128Eax = 39,
+  Aes192Eax = 40,
+  Aes256Eax = 41,
+}
+export enum Padding {
+  Pkcs7 = 0,
+  Iso7816 = 1,
+  AnsiX923 = 2,
+  Iso10126 = 3,
+  ZeroPadding = 4,
+  NoPadding = 5,
+}
+export enum CounterLayout {
+  Ctr32BE = 0,
+  Ctr64BE = 1,
+  Ctr128BE = 2,
+  Ctr32LE = 3,
 }
@@ -0,0 +1,27 @@
+export function encrypt(
+  plaintext: Uint8Array,
+  key: Uint8Array,
+  nonce: Uint8Array,
+  aad?: Uint8Array | null
+): Uint8Array;
+
+export function decrypt(
+  ciphertext: Uint8Array,
+  key: Uint8Array,
+  nonce: Uint8Array,
+  aad?: Uint8Array | null
+): Uint8Array;
+
+export interface AsconHashOptions {
+  algo: 'hash256' | 'xof128';
+  hash_length?: number;
+}
+
+export function hash(input: Uint8Array, options: AsconHashOptions): Uint8Array;
+
+export class StreamingHasher {
+  free(): void;
+  constructor(options: AsconHashOptions);
+  update(input: Uint8Array): void;
+  finalize(hash_length?: number | null): Uint8Array;
+}
@@ -0,0 +1,39 @@
+export enum BlockAlgorithm {
+  Camellia128Gcm,
+  Camellia192Gcm,
+  Camellia256Gcm,
+  Camellia128Cbc,
+  Camellia192Cbc,
+  Camellia256Cbc,
+  Camellia128Ctr,
+  Camellia192Ctr,
+  Camellia256Ctr,
+  Aria128Gcm,
+  Aria192Gcm,
+  Aria256Gcm,
+  Aria128Cbc,
+  Aria192Cbc,
+  Aria256Cbc,
+  Aria128Ctr,
+  Aria192Ctr,
+  Aria256Ctr,
+  Sm4Gcm,
+  Sm4Cbc,
+  Sm4Ctr
+}
+
+export function encrypt(
+  plaintext: Uint8Array,
+  key: Uint8Array,
+  nonce_or_iv: Uint8Array,
+  algo: BlockAlgorithm,
+  aad?: Uint8Array | null
+): Uint8Array;
+
+export function decrypt(
+  ciphertext: Uint8Array,
+  key: Uint8Array,
+  nonce_or_iv: Uint8Array,
+  algo: BlockAlgorithm,
+  aad?: Uint8Array | null
+): Uint8Array;
@@ -1,18 +1,84 @@
 export enum ChaCha20Algorithm {
   Chacha20,
-  Chacha20Poly1305
+  Chacha20Poly1305,
+  XChacha20,
+  XChacha20Poly1305
 }
 
 export function encrypt(
   plaintext: Uint8Array,
   key: Uint8Array,
   nonce: Uint8Array,
-  algo: ChaCha20Algorithm
+  algo: ChaCha20Algorithm,
+  aad?: Uint8Array | null
 ): Uint8Array;
 
 export function decrypt(
   ciphertext: Uint8Array,
   key: Uint8Array,
   nonce: Uint8Array,
-  algo: ChaCha20Algorithm
+  algo: ChaCha20Algorithm,
+  aad?: Uint8Array | null
 ): Uint8Array;
+
+export function encrypt_detached(
+  plaintext: Uint8Array,
+  key: Uint8Array,
+  nonce: Uint8Array,
+  aad?: Uint8Array | null
+): [Uint8Array, Uint8Array];
+
+export function decrypt_detached(
+  ciphertext: Uint8Array,
+  tag: Uint8Array,
+  key: Uint8Array,
+  nonce: Uint8Array,
+  aad?: Uint8Array | null
+): Uint8Array;
+
+export function hchacha20(key: Uint8Array, input: Uint8Array): Uint8Array;
+
+export enum Salsa20Algorithm {
+  Salsa20,
+  XSalsa20
+}
+
+export function salsa20_xor(
+  data: Uint8Array,
+  key: Uint8Array,
+  nonce: Uint8Array,
+  algo: Salsa20Algorithm
+): Uint8Array;
+
+export function secretbox_seal(plaintext: Uint8Array, key: Uint8Array, nonce: Uint8Array): Uint8Array;
+
+export function secretbox_open(boxed: Uint8Array, key: Uint8Array, nonce: Uint8Array): Uint8Array;
+
+export class StreamingEncryptor {
+  free(): void;
+  constructor(key: Uint8Array, nonce: Uint8Array, segment_size?: number | null);
+  update(plaintext: Uint8Array): Uint8Array;
+  finalize(): Uint8Array;
+}
+
+export class StreamingDecryptor {
+  free(): void;
+  constructor(key: Uint8Array, nonce: Uint8Array, segment_size?: number | null);
+  update(ciphertext: Uint8Array): Uint8Array;
+  finalize(): Uint8Array;
+}
+
+export class ChaCha20Poly1305Cipher {
+  free(): void;
+  constructor(key: Uint8Array, algo: ChaCha20Algorithm);
+  encrypt(plaintext: Uint8Array, nonce: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+  decrypt(ciphertext: Uint8Array, nonce: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+}
+
+export class Keystream {
+  free(): void;
+  constructor(key: Uint8Array, iv: Uint8Array, algo: ChaCha20Algorithm);
+  apply(data: Uint8Array): Uint8Array;
+  seek(offset: bigint): void;
+  readonly position: bigint;
+}
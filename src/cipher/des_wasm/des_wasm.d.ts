@@ -2,19 +2,89 @@ export enum DesAlgorithm {
   DesCbc,
   TdesCbc,
   DesCtr,
-  TdesCtr
+  TdesCtr,
+  DesEcb,
+  TdesEcb,
+  TdesEde2Cbc,
+  TdesEde2Ctr,
+  TdesEde2Ecb
+}
+
+export enum Padding {
+  Pkcs7,
+  Iso7816,
+  AnsiX923,
+  Iso10126,
+  ZeroPadding,
+  NoPadding
 }
 
 export function encrypt(
   plaintext: Uint8Array,
   key: Uint8Array,
   iv: Uint8Array,
-  algo: DesAlgorithm
+  algo: DesAlgorithm,
+  padding?: Padding | null
 ): Uint8Array;
 
 export function decrypt(
   ciphertext: Uint8Array,
   key: Uint8Array,
   iv: Uint8Array,
-  algo: DesAlgorithm
+  algo: DesAlgorithm,
+  padding?: Padding | null
 ): Uint8Array;
+
+export class DesKeyCheck {
+  free(): void;
+  odd_parity: boolean;
+  weak: boolean;
+  semi_weak: boolean;
+  degenerate: boolean;
+}
+
+export function des_check_key(key: Uint8Array): DesKeyCheck;
+
+export function des_set_odd_parity(key: Uint8Array): Uint8Array;
+
+export enum MacAlgorithm {
+  Algorithm1,
+  Algorithm3
+}
+
+export enum MacPadding {
+  Method1,
+  Method2
+}
+
+export function des_mac(data: Uint8Array, key: Uint8Array, algo: MacAlgorithm, padding: MacPadding): Uint8Array;
+
+export function des_mac_verify(
+  data: Uint8Array,
+  key: Uint8Array,
+  tag: Uint8Array,
+  algo: MacAlgorithm,
+  padding: MacPadding
+): boolean;
+
+export class StreamingMac {
+  free(): void;
+  constructor(key: Uint8Array, algo: MacAlgorithm, padding: MacPadding);
+  update(input: Uint8Array): void;
+  finalize(): Uint8Array;
+  verify(tag: Uint8Array): boolean;
+}
+
+export enum CounterLayout {
+  Ctr32BE,
+  Ctr64BE,
+  Ctr32LE
+}
+
+export class Keystream {
+  free(): void;
+  constructor(key: Uint8Array, iv: Uint8Array, algo: DesAlgorithm, layout?: CounterLayout | null);
+  apply(data: Uint8Array): Uint8Array;
+  seek(offset: bigint): void;
+  readonly position: bigint;
+}
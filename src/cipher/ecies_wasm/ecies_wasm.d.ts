@@ -0,0 +1,23 @@
+export enum EciesCurve {
+  Secp256k1,
+  P256
+}
+
+export enum EciesAead {
+  Aes256Gcm,
+  XChaCha20Poly1305
+}
+
+export class Ecies {
+  free(): void;
+  constructor(
+    curve: EciesCurve,
+    aead: EciesAead,
+    aes_nonce_len: number,
+    compressed_ephemeral_key: boolean,
+    compressed_hkdf_key: boolean
+  );
+  generate_keypair(): Uint8Array[];
+  encrypt(receiver_public_key: Uint8Array, plaintext: Uint8Array): Uint8Array;
+  decrypt(receiver_private_key: Uint8Array, ciphertext: Uint8Array): Uint8Array;
+}
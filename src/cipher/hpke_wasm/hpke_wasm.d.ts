@@ -0,0 +1,53 @@
+export enum HpkeKem {
+  X25519HkdfSha256,
+  P256HkdfSha256,
+  P384HkdfSha384
+}
+
+export enum HpkeKdf {
+  HkdfSha256,
+  HkdfSha384,
+  HkdfSha512
+}
+
+export enum HpkeAead {
+  Aes128Gcm,
+  Aes256Gcm,
+  ChaCha20Poly1305,
+  ExportOnly
+}
+
+export class Hpke {
+  free(): void;
+  constructor(kem: HpkeKem, kdf: HpkeKdf, aead: HpkeAead);
+  generate_keypair(): Uint8Array[];
+  derive_keypair(ikm: Uint8Array): Uint8Array[];
+  setup_sender(
+    recipient_public_key: Uint8Array,
+    info?: Uint8Array | null,
+    psk?: Uint8Array | null,
+    psk_id?: Uint8Array | null,
+    auth_key?: Uint8Array | null
+  ): HpkeSenderContext;
+  setup_recipient(
+    enc: Uint8Array,
+    recipient_private_key: Uint8Array,
+    info?: Uint8Array | null,
+    psk?: Uint8Array | null,
+    psk_id?: Uint8Array | null,
+    auth_key?: Uint8Array | null
+  ): HpkeRecipientContext;
+}
+
+export class HpkeSenderContext {
+  free(): void;
+  readonly enc: Uint8Array;
+  seal(plaintext: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+  export(exporter_context: Uint8Array, length: number): Uint8Array;
+}
+
+export class HpkeRecipientContext {
+  free(): void;
+  open(ciphertext: Uint8Array, aad?: Uint8Array | null): Uint8Array;
+  export(exporter_context: Uint8Array, length: number): Uint8Array;
+}
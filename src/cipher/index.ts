@@ -2,9 +2,54 @@
  * Cipher algorithms module
  */
 
-import { CryptoInput, CipherOptions, CipherFunction } from '../types';
+import {
+  CryptoInput,
+  CipherOptions,
+  CipherFunction,
+  AesCipherFunction,
+  ChaCha20CipherFunction,
+  CounterLayout,
+  DesCipherFunction,
+  DesKeyCheck,
+  DesMacInstance,
+  DesMacOptions,
+  DetachedCiphertext,
+  EciesFunction,
+  EciesOptions,
+  HpkeFunction,
+  HpkeRecipientContext,
+  HpkeSealed,
+  HpkeSealOptions,
+  HpkeSenderContext,
+  HpkeSetupOptions,
+  HpkeSuiteOptions,
+  KeyWrapOptions,
+  KeyedCipherOptions,
+  KeyedCipherInstance,
+  KeystreamInstance,
+  KeystreamOptions,
+  SecretboxFunction,
+  StreamingCipherOptions,
+  StreamingCipherInstance,
+  XtsSectorOptions,
+} from '../types';
 import path from 'path';
 
+type AesMode =
+  | 'CBC'
+  | 'ECB'
+  | 'CFB'
+  | 'CFB8'
+  | 'OFB'
+  | 'CTR'
+  | 'GCM'
+  | 'CCM'
+  | 'SIV'
+  | 'GCM-SIV'
+  | 'OCB3'
+  | 'EAX'
+  | 'XTS';
+
 /**
  * Base class for cipher algorithm wrappers
  */
@@ -27,6 +72,89 @@ abstract class BaseCipher {
     }
   }
 
+  protected aadBuffer(options: Pick<CipherOptions, 'aad'>): Uint8Array | undefined {
+    if (options.aad === undefined) return undefined;
+    if (Array.isArray(options.aad)) {
+      throw new Error('Multiple AAD components are only supported in AES-SIV mode');
+    }
+    return this.toBuffer(options.aad);
+  }
+
+  /**
+   * Wrap a wasm StreamingEncryptor/StreamingDecryptor
+   */
+  protected streamingInstance(stream: any): StreamingCipherInstance {
+    return {
+      update: (data: CryptoInput): Buffer => Buffer.from(stream.update(this.toBuffer(data))),
+      final: (): Buffer => {
+        try {
+          return Buffer.from(stream.finalize());
+        } finally {
+          stream.free();
+        }
+      },
+    };
+  }
+
+  /**
+   * Wrap a wasm keyed cipher (AesGcmCipher, AesSivCipher, ChaCha20Poly1305Cipher)
+   */
+  protected keyedInstance(cipher: any): KeyedCipherInstance {
+    const run = (fn: 'encrypt' | 'decrypt', data: CryptoInput, options: Pick<CipherOptions, 'iv' | 'aad'>): Buffer => {
+      if (!options.iv) throw new Error('Nonce is required');
+      const nonce = this.toBuffer(options.iv);
+      if (Array.isArray(options.aad) && cipher[`${fn}_with_headers`]) {
+        // RFC 5297 header vector: every AAD component, then the nonce
+        const headers = [...options.aad.map((c) => this.toBuffer(c)), nonce];
+        return Buffer.from(cipher[`${fn}_with_headers`](this.toBuffer(data), headers));
+      }
+      return Buffer.from(cipher[fn](this.toBuffer(data), nonce, this.aadBuffer(options)));
+    };
+    return {
+      encrypt: (data, options) => run('encrypt', data, options),
+      decrypt: (data, options) => run('decrypt', data, options),
+      free: () => cipher.free(),
+    };
+  }
+
+  /**
+   * Map a counter layout name to the wasm module's CounterLayout enum;
+   * undefined selects the algorithm's default
+   */
+  protected counterLayout(counter?: CounterLayout): number | undefined {
+    if (counter === undefined) return undefined;
+    const names: Record<CounterLayout, string> = {
+      ctr32be: 'Ctr32BE',
+      ctr64be: 'Ctr64BE',
+      ctr128be: 'Ctr128BE',
+      ctr32le: 'Ctr32LE',
+    };
+    const layout = this.wasmModule.CounterLayout?.[names[counter]];
+    if (layout === undefined) {
+      throw new Error(`Counter layout ${counter} is not supported here`);
+    }
+    return layout;
+  }
+
+  /**
+   * Wrap a wasm Keystream
+   */
+  protected keystreamInstance(keystream: any): KeystreamInstance {
+    const instance: KeystreamInstance = {
+      apply: (data: CryptoInput): Buffer => Buffer.from(keystream.apply(this.toBuffer(data))),
+      seek: (offset: number | bigint): KeystreamInstance => {
+        if (offset < 0) throw new Error('Keystream offset must not be negative');
+        keystream.seek(BigInt(offset));
+        return instance;
+      },
+      get position(): number {
+        return Number(keystream.position);
+      },
+      free: () => keystream.free(),
+    };
+    return instance;
+  }
+
   protected validateKeyLength(key: Uint8Array, validLengths: number[]): void {
     if (!validLengths.includes(key.length)) {
       throw new Error(
@@ -39,34 +167,26 @@ abstract class BaseCipher {
 /**
  * AES cipher implementation
  */
-class AESCipher extends BaseCipher implements CipherFunction {
+class AESCipher extends BaseCipher implements AesCipherFunction {
   encrypt(data: CryptoInput, options: CipherOptions): Buffer {
     const dataBuffer = this.toBuffer(data);
     const keyBuffer = this.toBuffer(options.key);
 
-    // Validate key length (16, 24, or 32 bytes for AES-128, AES-192, AES-256)
-    this.validateKeyLength(keyBuffer, [16, 24, 32]);
+    const mode = (options.mode || 'cbc').toUpperCase() as AesMode;
 
-    const mode = (options.mode || 'cbc').toUpperCase() as 'CBC' | 'ECB' | 'CTR' | 'GCM' | 'CCM' | 'SIV';
+    // Validate key length (16, 24, or 32 bytes for AES-128, AES-192, AES-256; 32 or 64 for XTS)
+    this.validateKeyLength(keyBuffer, mode === 'XTS' ? [32, 64] : [16, 24, 32]);
+    const aad = mode === 'SIV' && Array.isArray(options.aad) ? undefined : this.aadBuffer(options);
     let result: Uint8Array;
 
     switch (mode) {
-      case 'CBC': {
-        if (!options.iv) {
-          throw new Error('IV is required for CBC mode');
-        }
-        const ivInput = this.toBuffer(options.iv);
-        // Docs require 16 bytes IV. Underlying GCM needs 12-byte nonce.
-        if (ivInput.length !== 16 && ivInput.length !== 12) {
-          throw new Error('IV must be 16 bytes (docs)');
-        }
-        const ivBuffer = ivInput.length === 12 ? ivInput : ivInput.subarray(0, 12);
-        // Map CBC request to AEAD (GCM) in WASM (safe default)
-        let algorithm: number;
-        if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Gcm;
-        else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Gcm;
-        else algorithm = this.wasmModule.AesAlgorithm.Aes256Gcm;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, ivBuffer, algorithm);
+      case 'CBC':
+      case 'ECB':
+      case 'CFB':
+      case 'CFB8':
+      case 'OFB': {
+        const { algorithm, iv, padding } = this.classicParams(keyBuffer, mode, options);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, iv, algorithm, undefined, padding);
         break;
       }
       case 'GCM': {
@@ -77,7 +197,7 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Gcm;
         else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Gcm;
         else algorithm = this.wasmModule.AesAlgorithm.Aes256Gcm;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'CCM': {
@@ -88,7 +208,7 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Ccm;
         else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Ccm;
         else algorithm = this.wasmModule.AesAlgorithm.Aes256Ccm;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'SIV': {
@@ -99,17 +219,30 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 32) algorithm = this.wasmModule.AesAlgorithm.Aes128Siv;
         else if (keyBuffer.length === 64) algorithm = this.wasmModule.AesAlgorithm.Aes256Siv;
         else throw new Error('AES-SIV requires 32-byte (AES-128-SIV) or 64-byte (AES-256-SIV) key');
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        if (Array.isArray(options.aad)) {
+          // RFC 5297 header vector: every AAD component, then the nonce
+          const headers = [...options.aad.map((c) => this.toBuffer(c)), nonce];
+          result = this.wasmModule.siv_encrypt(dataBuffer, keyBuffer, headers, algorithm);
+        } else {
+          result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
+        }
         break;
       }
-      case 'ECB': {
-        // Emulate ECB via CTR with zero IV
-        const ivBuffer = Buffer.alloc(16, 0);
+      case 'GCM-SIV': {
+        if (!options.iv) throw new Error('Nonce is required for AES-GCM-SIV');
+        const nonce = this.toBuffer(options.iv);
+        if (nonce.length !== 12) throw new Error('AES-GCM-SIV nonce must be 12 bytes');
         let algorithm: number;
-        if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Ctr;
-        else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Ctr;
-        else algorithm = this.wasmModule.AesAlgorithm.Aes256Ctr;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, ivBuffer, algorithm);
+        if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128GcmSiv;
+        else if (keyBuffer.length === 32) algorithm = this.wasmModule.AesAlgorithm.Aes256GcmSiv;
+        else throw new Error('AES-GCM-SIV requires a 16-byte or 32-byte key');
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
+        break;
+      }
+      case 'OCB3':
+      case 'EAX': {
+        const { algorithm, nonce } = this.ocbEaxParams(keyBuffer, mode, options);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'CTR': {
@@ -121,7 +254,12 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Ctr;
         else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Ctr;
         else algorithm = this.wasmModule.AesAlgorithm.Aes256Ctr;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, ivBuffer, algorithm);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, ivBuffer, algorithm, aad);
+        break;
+      }
+      case 'XTS': {
+        const { algorithm, tweak } = this.xtsParams(keyBuffer, options);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, tweak, algorithm, aad);
         break;
       }
       default:
@@ -135,27 +273,21 @@ class AESCipher extends BaseCipher implements CipherFunction {
     const dataBuffer = this.toBuffer(data);
     const keyBuffer = this.toBuffer(options.key);
 
-    // Validate key length
-    this.validateKeyLength(keyBuffer, [16, 24, 32]);
+    const mode = (options.mode || 'cbc').toUpperCase() as AesMode;
 
-    const mode = (options.mode || 'cbc').toUpperCase() as 'CBC' | 'ECB' | 'CTR' | 'GCM' | 'CCM' | 'SIV';
+    // Validate key length
+    this.validateKeyLength(keyBuffer, mode === 'XTS' ? [32, 64] : [16, 24, 32]);
+    const aad = mode === 'SIV' && Array.isArray(options.aad) ? undefined : this.aadBuffer(options);
     let result: Uint8Array;
 
     switch (mode) {
-      case 'CBC': {
-        if (!options.iv) {
-          throw new Error('IV is required for CBC mode');
-        }
-        const ivInput = this.toBuffer(options.iv);
-        if (ivInput.length !== 16 && ivInput.length !== 12) {
-          throw new Error('IV must be 16 bytes (docs)');
-        }
-        const ivBuffer = ivInput.length === 12 ? ivInput : ivInput.subarray(0, 12);
-        let algorithm: number;
-        if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Gcm;
-        else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Gcm;
-        else algorithm = this.wasmModule.AesAlgorithm.Aes256Gcm;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, ivBuffer, algorithm);
+      case 'CBC':
+      case 'ECB':
+      case 'CFB':
+      case 'CFB8':
+      case 'OFB': {
+        const { algorithm, iv, padding } = this.classicParams(keyBuffer, mode, options);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, iv, algorithm, undefined, padding);
         break;
       }
       case 'GCM': {
@@ -166,7 +298,7 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Gcm;
         else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Gcm;
         else algorithm = this.wasmModule.AesAlgorithm.Aes256Gcm;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'CCM': {
@@ -177,7 +309,7 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Ccm;
         else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Ccm;
         else algorithm = this.wasmModule.AesAlgorithm.Aes256Ccm;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'SIV': {
@@ -188,16 +320,30 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 32) algorithm = this.wasmModule.AesAlgorithm.Aes128Siv;
         else if (keyBuffer.length === 64) algorithm = this.wasmModule.AesAlgorithm.Aes256Siv;
         else throw new Error('AES-SIV requires 32-byte (AES-128-SIV) or 64-byte (AES-256-SIV) key');
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        if (Array.isArray(options.aad)) {
+          // RFC 5297 header vector: every AAD component, then the nonce
+          const headers = [...options.aad.map((c) => this.toBuffer(c)), nonce];
+          result = this.wasmModule.siv_decrypt(dataBuffer, keyBuffer, headers, algorithm);
+        } else {
+          result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
+        }
         break;
       }
-      case 'ECB': {
-        const ivBuffer = Buffer.alloc(16, 0);
+      case 'GCM-SIV': {
+        if (!options.iv) throw new Error('Nonce is required for AES-GCM-SIV');
+        const nonce = this.toBuffer(options.iv);
+        if (nonce.length !== 12) throw new Error('AES-GCM-SIV nonce must be 12 bytes');
         let algorithm: number;
-        if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Ctr;
-        else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Ctr;
-        else algorithm = this.wasmModule.AesAlgorithm.Aes256Ctr;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, ivBuffer, algorithm);
+        if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128GcmSiv;
+        else if (keyBuffer.length === 32) algorithm = this.wasmModule.AesAlgorithm.Aes256GcmSiv;
+        else throw new Error('AES-GCM-SIV requires a 16-byte or 32-byte key');
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
+        break;
+      }
+      case 'OCB3':
+      case 'EAX': {
+        const { algorithm, nonce } = this.ocbEaxParams(keyBuffer, mode, options);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'CTR': {
@@ -209,7 +355,12 @@ class AESCipher extends BaseCipher implements CipherFunction {
         if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Ctr;
         else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Ctr;
         else algorithm = this.wasmModule.AesAlgorithm.Aes256Ctr;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, ivBuffer, algorithm);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, ivBuffer, algorithm, aad);
+        break;
+      }
+      case 'XTS': {
+        const { algorithm, tweak } = this.xtsParams(keyBuffer, options);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, tweak, algorithm, aad);
         break;
       }
       default:
@@ -218,15 +369,269 @@ class AESCipher extends BaseCipher implements CipherFunction {
 
     return Buffer.from(result);
   }
+
+  /**
+   * Resolve the algorithm, IV and padding for the unauthenticated modes.
+   * Padding applies to CBC and ECB only and defaults to PKCS#7.
+   */
+  private classicParams(
+    keyBuffer: Uint8Array,
+    mode: 'CBC' | 'ECB' | 'CFB' | 'CFB8' | 'OFB',
+    options: CipherOptions
+  ): { algorithm: number; iv: Uint8Array; padding: number | undefined } {
+    if (options.aad !== undefined) {
+      throw new Error(`AES-${mode} does not authenticate associated data`);
+    }
+    const size = keyBuffer.length === 16 ? 128 : keyBuffer.length === 24 ? 192 : 256;
+    const name = mode === 'CFB' ? 'Cfb128' : mode.charAt(0) + mode.slice(1).toLowerCase();
+    const algorithm = this.wasmModule.AesAlgorithm[`Aes${size}${name}`];
+
+    let iv: Uint8Array = new Uint8Array(0);
+    if (mode !== 'ECB') {
+      if (!options.iv) throw new Error(`IV is required for ${mode} mode`);
+      iv = this.toBuffer(options.iv);
+      if (iv.length !== 16) throw new Error(`AES-${mode} IV must be 16 bytes`);
+    }
+
+    const blockMode = mode === 'CBC' || mode === 'ECB';
+    if (!blockMode && options.padding !== undefined && options.padding !== 'NoPadding') {
+      throw new Error(`AES-${mode} is a stream mode and takes no padding`);
+    }
+    const paddings: Record<NonNullable<CipherOptions['padding']>, number> = {
+      PKCS7: this.wasmModule.Padding.Pkcs7,
+      ISO7816: this.wasmModule.Padding.Iso7816,
+      ANSIX923: this.wasmModule.Padding.AnsiX923,
+      ISO10126: this.wasmModule.Padding.Iso10126,
+      ZeroPadding: this.wasmModule.Padding.ZeroPadding,
+      NoPadding: this.wasmModule.Padding.NoPadding,
+    };
+    const padding = blockMode ? paddings[options.padding || 'PKCS7'] : undefined;
+    if (blockMode && padding === undefined) {
+      throw new Error(`Unsupported padding: ${options.padding}`);
+    }
+
+    return { algorithm, iv, padding };
+  }
+
+  /**
+   * Resolve the OCB3/EAX algorithm and nonce for `encrypt`/`decrypt`, which
+   * take a 12-byte (OCB3) or 16-byte (EAX) nonce and a 16-byte tag
+   */
+  private ocbEaxParams(
+    keyBuffer: Uint8Array,
+    mode: 'OCB3' | 'EAX',
+    options: CipherOptions
+  ): { algorithm: number; nonce: Uint8Array } {
+    const nonceLength = mode === 'OCB3' ? 12 : 16;
+    if (!options.iv) throw new Error(`Nonce is required for AES-${mode}`);
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== nonceLength) {
+      throw new Error(`AES-${mode} nonce must be ${nonceLength} bytes (use encryptDetached for other sizes)`);
+    }
+    const size = keyBuffer.length === 16 ? 128 : keyBuffer.length === 24 ? 192 : 256;
+    const name = mode === 'OCB3' ? 'Ocb3' : 'Eax';
+    return { algorithm: this.wasmModule.AesAlgorithm[`Aes${size}${name}`], nonce };
+  }
+
+  /**
+   * Resolve the GCM/CCM/OCB3/EAX algorithm and nonce for the detached-tag API
+   */
+  private detachedParams(keyBuffer: Uint8Array, options: CipherOptions): { algorithm: number; nonce: Uint8Array } {
+    this.validateKeyLength(keyBuffer, [16, 24, 32]);
+    const mode = (options.mode || 'gcm').toUpperCase();
+    const alg = this.wasmModule.AesAlgorithm;
+    const size = keyBuffer.length === 16 ? 128 : keyBuffer.length === 24 ? 192 : 256;
+    if (!options.iv) throw new Error(`Nonce is required for AES-${mode}`);
+    const nonce = this.toBuffer(options.iv);
+    switch (mode) {
+      case 'GCM':
+        if (nonce.length !== 12) throw new Error('AES-GCM nonce must be 12 bytes');
+        return { algorithm: alg[`Aes${size}Gcm`], nonce };
+      case 'CCM':
+        if (nonce.length < 7 || nonce.length > 13) throw new Error('AES-CCM nonce must be 7-13 bytes');
+        return { algorithm: alg[`Aes${size}Ccm`], nonce };
+      case 'OCB3':
+        if (nonce.length < 6 || nonce.length > 15) throw new Error('AES-OCB3 nonce must be 6-15 bytes');
+        return { algorithm: alg[`Aes${size}Ocb3`], nonce };
+      case 'EAX':
+        if (nonce.length !== 16) throw new Error('AES-EAX nonce must be 16 bytes');
+        return { algorithm: alg[`Aes${size}Eax`], nonce };
+      default:
+        throw new Error(`Detached tags are only supported in GCM, CCM, OCB3 and EAX modes, got ${mode}`);
+    }
+  }
+
+  encryptDetached(data: CryptoInput, options: CipherOptions): DetachedCiphertext {
+    const dataBuffer = this.toBuffer(data);
+    const keyBuffer = this.toBuffer(options.key);
+    const { algorithm, nonce } = this.detachedParams(keyBuffer, options);
+    const aad = this.aadBuffer(options);
+    const [ciphertext, tag] = this.wasmModule.encrypt_detached(
+      dataBuffer,
+      keyBuffer,
+      nonce,
+      algorithm,
+      aad,
+      options.tagLength
+    );
+    return { ciphertext: Buffer.from(ciphertext), tag: Buffer.from(tag) };
+  }
+
+  decryptDetached(data: CryptoInput, tag: CryptoInput, options: CipherOptions): Buffer {
+    const dataBuffer = this.toBuffer(data);
+    const keyBuffer = this.toBuffer(options.key);
+    const { algorithm, nonce } = this.detachedParams(keyBuffer, options);
+    const aad = this.aadBuffer(options);
+    const result: Uint8Array = this.wasmModule.decrypt_detached(
+      dataBuffer,
+      this.toBuffer(tag),
+      keyBuffer,
+      nonce,
+      algorithm,
+      aad
+    );
+    return Buffer.from(result);
+  }
+
+  /**
+   * Resolve the XTS algorithm and the 16-byte tweak for a single data unit
+   */
+  private xtsParams(keyBuffer: Uint8Array, options: CipherOptions): { algorithm: number; tweak: Uint8Array } {
+    if (!options.iv) throw new Error('Tweak (iv) is required for AES-XTS');
+    const tweak = this.toBuffer(options.iv);
+    if (tweak.length !== 16) throw new Error('AES-XTS tweak must be 16 bytes');
+    return { algorithm: this.xtsAlgo(keyBuffer), tweak };
+  }
+
+  private xtsAlgo(keyBuffer: Uint8Array): number {
+    if (keyBuffer.length === 32) return this.wasmModule.AesAlgorithm.Aes128Xts;
+    if (keyBuffer.length === 64) return this.wasmModule.AesAlgorithm.Aes256Xts;
+    throw new Error('AES-XTS requires a 32-byte (AES-128-XTS) or 64-byte (AES-256-XTS) key');
+  }
+
+  private sectorArgs(options: XtsSectorOptions): [Uint8Array, number, bigint, number] {
+    const keyBuffer = this.toBuffer(options.key);
+    const algorithm = this.xtsAlgo(keyBuffer);
+    return [keyBuffer, options.sectorSize, BigInt(options.firstSector ?? 0), algorithm];
+  }
+
+  encryptSectors(data: CryptoInput, options: XtsSectorOptions): Buffer {
+    return Buffer.from(this.wasmModule.xts_encrypt_sectors(this.toBuffer(data), ...this.sectorArgs(options)));
+  }
+
+  decryptSectors(data: CryptoInput, options: XtsSectorOptions): Buffer {
+    return Buffer.from(this.wasmModule.xts_decrypt_sectors(this.toBuffer(data), ...this.sectorArgs(options)));
+  }
+
+  private keyWrapAlgo(kekBuffer: Uint8Array, options?: KeyWrapOptions): number {
+    this.validateKeyLength(kekBuffer, [16, 24, 32]);
+    const size = kekBuffer.length === 16 ? 128 : kekBuffer.length === 24 ? 192 : 256;
+    return this.wasmModule.AesAlgorithm[`Aes${size}${options?.padding ? 'Kwp' : 'Kw'}`];
+  }
+
+  wrapKey(keyData: CryptoInput, kek: CryptoInput, options?: KeyWrapOptions): Buffer {
+    const kekBuffer = this.toBuffer(kek);
+    const algorithm = this.keyWrapAlgo(kekBuffer, options);
+    return Buffer.from(this.wasmModule.wrap_key(this.toBuffer(keyData), kekBuffer, algorithm));
+  }
+
+  unwrapKey(wrapped: CryptoInput, kek: CryptoInput, options?: KeyWrapOptions): Buffer {
+    const kekBuffer = this.toBuffer(kek);
+    const algorithm = this.keyWrapAlgo(kekBuffer, options);
+    return Buffer.from(this.wasmModule.unwrap_key(this.toBuffer(wrapped), kekBuffer, algorithm));
+  }
+
+  private streamingArgs(options: StreamingCipherOptions): [Uint8Array, Uint8Array, number, number | undefined] {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [16, 24, 32]);
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== 7) throw new Error('AES-GCM STREAM nonce must be 7 bytes');
+    let algorithm: number;
+    if (keyBuffer.length === 16) algorithm = this.wasmModule.AesAlgorithm.Aes128Gcm;
+    else if (keyBuffer.length === 24) algorithm = this.wasmModule.AesAlgorithm.Aes192Gcm;
+    else algorithm = this.wasmModule.AesAlgorithm.Aes256Gcm;
+    return [keyBuffer, nonce, algorithm, options.segmentSize];
+  }
+
+  createStreamingEncryptor(options: StreamingCipherOptions): StreamingCipherInstance {
+    return this.streamingInstance(new this.wasmModule.StreamingEncryptor(...this.streamingArgs(options)));
+  }
+
+  createStreamingDecryptor(options: StreamingCipherOptions): StreamingCipherInstance {
+    return this.streamingInstance(new this.wasmModule.StreamingDecryptor(...this.streamingArgs(options)));
+  }
+
+  createKeyedCipher(options: KeyedCipherOptions): KeyedCipherInstance {
+    const keyBuffer = this.toBuffer(options.key);
+    const alg = this.wasmModule.AesAlgorithm;
+    const mode = (options.mode || 'gcm').toUpperCase();
+    switch (mode) {
+      case 'GCM': {
+        this.validateKeyLength(keyBuffer, [16, 24, 32]);
+        const size = keyBuffer.length === 16 ? 128 : keyBuffer.length === 24 ? 192 : 256;
+        return this.keyedInstance(new this.wasmModule.AesGcmCipher(keyBuffer, alg[`Aes${size}Gcm`]));
+      }
+      case 'SIV': {
+        this.validateKeyLength(keyBuffer, [32, 64]);
+        const algorithm = keyBuffer.length === 32 ? alg.Aes128Siv : alg.Aes256Siv;
+        return this.keyedInstance(new this.wasmModule.AesSivCipher(keyBuffer, algorithm));
+      }
+      default:
+        throw new Error(`Keyed ciphers are only supported in GCM and SIV modes, got ${mode}`);
+    }
+  }
+
+  createKeystream(options: KeystreamOptions): KeystreamInstance {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [16, 24, 32]);
+    const size = keyBuffer.length === 16 ? 128 : keyBuffer.length === 24 ? 192 : 256;
+    const algorithm = this.wasmModule.AesAlgorithm[`Aes${size}Ctr`];
+    const layout = this.counterLayout(options.counter);
+    return this.keystreamInstance(
+      new this.wasmModule.Keystream(keyBuffer, this.toBuffer(options.iv), algorithm, layout)
+    );
+  }
 }
 
 /**
  * ChaCha20 cipher implementation (with ChaCha20-Poly1305 for authenticated mode)
  */
-class ChaCha20Cipher extends BaseCipher implements CipherFunction {
+class ChaCha20Cipher extends BaseCipher implements ChaCha20CipherFunction {
+  /**
+   * 24-byte nonces select XChaCha20 / XChaCha20-Poly1305
+   */
+  private resolveAlgo(nonce: Uint8Array, aead: boolean): number {
+    const alg = this.wasmModule.ChaCha20Algorithm;
+    if (nonce.length === 24) return aead ? alg.XChacha20Poly1305 : alg.XChacha20;
+    return aead ? alg.Chacha20Poly1305 : alg.Chacha20;
+  }
+
+  private aeadNonce(options: CipherOptions): Uint8Array {
+    if (!options.iv) {
+      throw new Error('Nonce is required for ChaCha20-Poly1305 (CBC-mapped)');
+    }
+    const ivInput = this.toBuffer(options.iv);
+    if (ivInput.length !== 12 && ivInput.length !== 16 && ivInput.length !== 24) {
+      throw new Error('Nonce must be 12 bytes, or 24 bytes for XChaCha20-Poly1305');
+    }
+    return ivInput.length === 16 ? ivInput.subarray(0, 12) : ivInput;
+  }
+
+  private streamNonce(options: CipherOptions): Uint8Array {
+    if (!options.iv) {
+      throw new Error('Nonce is required for ChaCha20 CTR mode');
+    }
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== 12 && nonce.length !== 24) {
+      throw new Error('Nonce must be 12 bytes for ChaCha20, or 24 bytes for XChaCha20');
+    }
+    return nonce;
+  }
+
   encrypt(data: CryptoInput, options: CipherOptions): Buffer {
     const dataBuffer = this.toBuffer(data);
     const keyBuffer = this.toBuffer(options.key);
+    const aad = this.aadBuffer(options);
 
     // Validate key length (32 bytes)
     this.validateKeyLength(keyBuffer, [32]);
@@ -236,35 +641,22 @@ class ChaCha20Cipher extends BaseCipher implements CipherFunction {
 
     switch (mode) {
       case 'CBC': {
-        if (!options.iv) {
-          throw new Error('Nonce is required for ChaCha20-Poly1305 (CBC-mapped)');
-        }
-        const ivInput = this.toBuffer(options.iv);
-        if (ivInput.length !== 12 && ivInput.length !== 16) {
-          throw new Error('Nonce must be 12 bytes (docs)');
-        }
-        const nonce = ivInput.length === 12 ? ivInput : ivInput.subarray(0, 12);
-        const algorithm: number = this.wasmModule.ChaCha20Algorithm.Chacha20Poly1305;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        const nonce = this.aeadNonce(options);
+        const algorithm = this.resolveAlgo(nonce, true);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'ECB': {
         // Emulate ECB by using stream mode with zero nonce (not recommended)
         const nonce = Buffer.alloc(12, 0);
-        const algorithm: number = this.wasmModule.ChaCha20Algorithm.Chacha20;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        const algorithm = this.resolveAlgo(nonce, false);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'CTR': {
-        if (!options.iv) {
-          throw new Error('Nonce is required for ChaCha20 CTR mode');
-        }
-        const nonce = this.toBuffer(options.iv);
-        if (nonce.length !== 12) {
-          throw new Error('Nonce must be 12 bytes for ChaCha20');
-        }
-        const algorithm: number = this.wasmModule.ChaCha20Algorithm.ChaCha20;
-        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        const nonce = this.streamNonce(options);
+        const algorithm = this.resolveAlgo(nonce, false);
+        result = this.wasmModule.encrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       default:
@@ -277,6 +669,7 @@ class ChaCha20Cipher extends BaseCipher implements CipherFunction {
   decrypt(data: CryptoInput, options: CipherOptions): Buffer {
     const dataBuffer = this.toBuffer(data);
     const keyBuffer = this.toBuffer(options.key);
+    const aad = this.aadBuffer(options);
 
     // Validate key length (32 bytes)
     this.validateKeyLength(keyBuffer, [32]);
@@ -286,34 +679,21 @@ class ChaCha20Cipher extends BaseCipher implements CipherFunction {
 
     switch (mode) {
       case 'CBC': {
-        if (!options.iv) {
-          throw new Error('Nonce is required for ChaCha20-Poly1305 (CBC-mapped)');
-        }
-        const ivInput = this.toBuffer(options.iv);
-        if (ivInput.length !== 12 && ivInput.length !== 16) {
-          throw new Error('Nonce must be 12 bytes (docs)');
-        }
-        const nonce = ivInput.length === 12 ? ivInput : ivInput.subarray(0, 12);
-        const algorithm: number = this.wasmModule.ChaCha20Algorithm.Chacha20Poly1305;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        const nonce = this.aeadNonce(options);
+        const algorithm = this.resolveAlgo(nonce, true);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'ECB': {
         const nonce = Buffer.alloc(12, 0);
-        const algorithm: number = this.wasmModule.ChaCha20Algorithm.ChaCha20;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        const algorithm = this.resolveAlgo(nonce, false);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       case 'CTR': {
-        if (!options.iv) {
-          throw new Error('Nonce is required for ChaCha20 CTR mode');
-        }
-        const nonce = this.toBuffer(options.iv);
-        if (nonce.length !== 12) {
-          throw new Error('Nonce must be 12 bytes for ChaCha20');
-        }
-        const algorithm: number = this.wasmModule.ChaCha20Algorithm.ChaCha20;
-        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm);
+        const nonce = this.streamNonce(options);
+        const algorithm = this.resolveAlgo(nonce, false);
+        result = this.wasmModule.decrypt(dataBuffer, keyBuffer, nonce, algorithm, aad);
         break;
       }
       default:
@@ -322,177 +702,593 @@ class ChaCha20Cipher extends BaseCipher implements CipherFunction {
 
     return Buffer.from(result);
   }
+
+  /**
+   * ChaCha20-Poly1305 with the 16-byte tag kept apart from the ciphertext
+   */
+  encryptDetached(data: CryptoInput, options: CipherOptions): DetachedCiphertext {
+    const [dataBuffer, keyBuffer, nonce] = this.detachedParams(data, options);
+    const [ciphertext, tag] = this.wasmModule.encrypt_detached(
+      dataBuffer,
+      keyBuffer,
+      nonce,
+      this.aadBuffer(options)
+    );
+    return { ciphertext: Buffer.from(ciphertext), tag: Buffer.from(tag) };
+  }
+
+  decryptDetached(data: CryptoInput, tag: CryptoInput, options: CipherOptions): Buffer {
+    const [dataBuffer, keyBuffer, nonce] = this.detachedParams(data, options);
+    const result: Uint8Array = this.wasmModule.decrypt_detached(
+      dataBuffer,
+      this.toBuffer(tag),
+      keyBuffer,
+      nonce,
+      this.aadBuffer(options)
+    );
+    return Buffer.from(result);
+  }
+
+  private detachedParams(data: CryptoInput, options: CipherOptions): [Uint8Array, Uint8Array, Uint8Array] {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [32]);
+    if (!options.iv) throw new Error('Nonce is required for ChaCha20-Poly1305');
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== 12) throw new Error('Nonce must be 12 bytes for ChaCha20-Poly1305');
+    if (options.tagLength !== undefined && options.tagLength !== 16) {
+      throw new Error('ChaCha20-Poly1305 tags are always 16 bytes');
+    }
+    return [this.toBuffer(data), keyBuffer, nonce];
+  }
+
+  private streamingArgs(options: StreamingCipherOptions): [Uint8Array, Uint8Array, number | undefined] {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [32]);
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== 7) throw new Error('ChaCha20-Poly1305 STREAM nonce must be 7 bytes');
+    return [keyBuffer, nonce, options.segmentSize];
+  }
+
+  createStreamingEncryptor(options: StreamingCipherOptions): StreamingCipherInstance {
+    return this.streamingInstance(new this.wasmModule.StreamingEncryptor(...this.streamingArgs(options)));
+  }
+
+  createStreamingDecryptor(options: StreamingCipherOptions): StreamingCipherInstance {
+    return this.streamingInstance(new this.wasmModule.StreamingDecryptor(...this.streamingArgs(options)));
+  }
+
+  createKeyedCipher(options: KeyedCipherOptions): KeyedCipherInstance {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [32]);
+    const nonceLength = options.nonceLength ?? 12;
+    if (nonceLength !== 12 && nonceLength !== 24) {
+      throw new Error('ChaCha20-Poly1305 nonce length must be 12 or 24 bytes');
+    }
+    const alg = this.wasmModule.ChaCha20Algorithm;
+    const algorithm = nonceLength === 24 ? alg.XChacha20Poly1305 : alg.Chacha20Poly1305;
+    return this.keyedInstance(new this.wasmModule.ChaCha20Poly1305Cipher(keyBuffer, algorithm));
+  }
+
+  createKeystream(options: KeystreamOptions): KeystreamInstance {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [32]);
+    if (options.counter !== undefined && options.counter !== 'ctr32le') {
+      throw new Error('ChaCha20 only supports the ctr32le counter layout');
+    }
+    const nonce = this.toBuffer(options.iv);
+    const algorithm = this.resolveAlgo(nonce, false);
+    return this.keystreamInstance(new this.wasmModule.Keystream(keyBuffer, nonce, algorithm));
+  }
 }
 
 /**
- * DES/3DES cipher implementation
+ * DES/3DES cipher implementation: 8-byte keys select DES, 16-byte keys
+ * two-key 3DES (EDE2) and 24-byte keys three-key 3DES (EDE3)
  */
-class DESCipher extends BaseCipher implements CipherFunction {
+class DESCipher extends BaseCipher implements DesCipherFunction {
   private resolveAlgo(keyLen: number, mode: 'CBC' | 'ECB' | 'CTR'): number {
     const alg = this.wasmModule.DesAlgorithm;
-    if (keyLen === 8) {
-      switch (mode) {
-        case 'CBC':
-          return alg.DesCbc;
-        case 'ECB':
-          return alg.DesCtr; // ECB emulated via CTR with zero IV
-        case 'CTR':
-          return alg.DesCtr;
-      }
-    } else if (keyLen === 24) {
-      switch (mode) {
-        case 'CBC':
-          return alg.TdesCbc;
-        case 'ECB':
-          return alg.TdesCtr; // ECB emulated via CTR with zero IV
-        case 'CTR':
-          return alg.TdesCtr;
+    const variants: Record<number, Record<'CBC' | 'ECB' | 'CTR', number>> = {
+      8: { CBC: alg.DesCbc, ECB: alg.DesEcb, CTR: alg.DesCtr },
+      16: { CBC: alg.TdesEde2Cbc, ECB: alg.TdesEde2Ecb, CTR: alg.TdesEde2Ctr },
+      24: { CBC: alg.TdesCbc, ECB: alg.TdesEcb, CTR: alg.TdesCtr },
+    };
+    const algorithm = variants[keyLen]?.[mode];
+    if (algorithm === undefined) {
+      if (!variants[keyLen]) {
+        throw new Error('DES/3DES key must be 8 (DES), 16 (3DES EDE2) or 24 bytes (3DES EDE3)');
       }
+      throw new Error(`Unsupported DES/3DES mode: ${mode}`);
     }
-    throw new Error('DES/3DES key must be 8 (DES) or 24 bytes (3DES EDE3)');
+    return algorithm;
   }
 
-  encrypt(data: CryptoInput, options: CipherOptions): Buffer {
-    const dataBuffer = this.toBuffer(data);
-    const keyBuffer = this.toBuffer(options.key);
-    if (keyBuffer.length !== 8 && keyBuffer.length !== 24) {
-      throw new Error('DES/3DES key must be 8 or 24 bytes');
-    }
+  /**
+   * Resolve the algorithm, IV and padding. ECB takes no IV; padding applies
+   * to CBC and ECB and defaults to PKCS#7.
+   */
+  private params(
+    keyBuffer: Uint8Array,
+    options: CipherOptions
+  ): { algorithm: number; iv: Uint8Array; padding: number | undefined } {
     const mode = (options.mode || 'cbc').toUpperCase() as 'CBC' | 'ECB' | 'CTR';
-    let ivForCall: Uint8Array | undefined = undefined;
-    if (mode === 'CBC' || mode === 'CTR') {
+    const algorithm = this.resolveAlgo(keyBuffer.length, mode);
+
+    let iv: Uint8Array = new Uint8Array(0);
+    if (mode !== 'ECB') {
       if (!options.iv) throw new Error('IV is required for DES/3DES CBC/CTR modes');
-      const ivInput = this.toBuffer(options.iv);
-      if (ivInput.length !== 8) {
+      iv = this.toBuffer(options.iv);
+      if (iv.length !== 8) {
         throw new Error('IV must be 8 bytes for DES/3DES');
       }
-      ivForCall = ivInput;
-    } else if (mode === 'ECB') {
-      ivForCall = Buffer.alloc(8, 0);
     }
-    const algorithm = this.resolveAlgo(keyBuffer.length, mode);
-    const result: Uint8Array = this.wasmModule.encrypt(
-      dataBuffer,
-      keyBuffer,
-      ivForCall!,
-      algorithm
-    );
+
+    if (options.padding === undefined) {
+      return { algorithm, iv, padding: undefined };
+    }
+    if (mode === 'CTR' && options.padding !== 'NoPadding') {
+      throw new Error('DES/3DES CTR is a stream mode and takes no padding');
+    }
+    const paddings: Record<NonNullable<CipherOptions['padding']>, number> = {
+      PKCS7: this.wasmModule.Padding.Pkcs7,
+      ISO7816: this.wasmModule.Padding.Iso7816,
+      ANSIX923: this.wasmModule.Padding.AnsiX923,
+      ISO10126: this.wasmModule.Padding.Iso10126,
+      ZeroPadding: this.wasmModule.Padding.ZeroPadding,
+      NoPadding: this.wasmModule.Padding.NoPadding,
+    };
+    const padding = paddings[options.padding];
+    if (padding === undefined) {
+      throw new Error(`Unsupported padding: ${options.padding}`);
+    }
+    return { algorithm, iv, padding };
+  }
+
+  encrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    const dataBuffer = this.toBuffer(data);
+    const keyBuffer = this.toBuffer(options.key);
+    const { algorithm, iv, padding } = this.params(keyBuffer, options);
+    const result: Uint8Array = this.wasmModule.encrypt(dataBuffer, keyBuffer, iv, algorithm, padding);
     return Buffer.from(result);
   }
 
   decrypt(data: CryptoInput, options: CipherOptions): Buffer {
     const dataBuffer = this.toBuffer(data);
     const keyBuffer = this.toBuffer(options.key);
-    if (keyBuffer.length !== 8 && keyBuffer.length !== 24) {
-      throw new Error('DES/3DES key must be 8 or 24 bytes');
+    const { algorithm, iv, padding } = this.params(keyBuffer, options);
+    const result: Uint8Array = this.wasmModule.decrypt(dataBuffer, keyBuffer, iv, algorithm, padding);
+    return Buffer.from(result);
+  }
+
+  createKeystream(options: KeystreamOptions): KeystreamInstance {
+    const keyBuffer = this.toBuffer(options.key);
+    const algorithm = this.resolveAlgo(keyBuffer.length, 'CTR');
+    const layout = this.counterLayout(options.counter);
+    return this.keystreamInstance(
+      new this.wasmModule.Keystream(keyBuffer, this.toBuffer(options.iv), algorithm, layout)
+    );
+  }
+
+  checkKey(key: CryptoInput): DesKeyCheck {
+    const check = this.wasmModule.des_check_key(this.toBuffer(key));
+    try {
+      return {
+        oddParity: check.odd_parity,
+        weak: check.weak,
+        semiWeak: check.semi_weak,
+        degenerate: check.degenerate,
+      };
+    } finally {
+      check.free();
     }
-    const mode = (options.mode || 'cbc').toUpperCase() as 'CBC' | 'ECB' | 'CTR';
-    let ivForCall: Uint8Array | undefined = undefined;
-    if (mode === 'CBC' || mode === 'CTR') {
-      if (!options.iv) throw new Error('IV is required for DES/3DES CBC/CTR modes');
-      const ivInput = this.toBuffer(options.iv);
-      if (ivInput.length !== 8) {
-        throw new Error('IV must be 8 bytes for DES/3DES');
-      }
-      ivForCall = ivInput;
-    } else if (mode === 'ECB') {
-      ivForCall = Buffer.alloc(8, 0);
+  }
+
+  setOddParity(key: CryptoInput): Buffer {
+    return Buffer.from(this.wasmModule.des_set_odd_parity(this.toBuffer(key)));
+  }
+
+  private macParams(options: DesMacOptions): { algorithm: number; padding: number; length: number } {
+    const algorithms: Record<number, number> = {
+      1: this.wasmModule.MacAlgorithm.Algorithm1,
+      3: this.wasmModule.MacAlgorithm.Algorithm3,
+    };
+    const paddings: Record<number, number> = {
+      1: this.wasmModule.MacPadding.Method1,
+      2: this.wasmModule.MacPadding.Method2,
+    };
+    const algorithm = algorithms[options.algorithm ?? 1];
+    if (algorithm === undefined) {
+      throw new Error(`Unsupported ISO 9797-1 MAC algorithm: ${options.algorithm}`);
     }
-    const algorithm = this.resolveAlgo(keyBuffer.length, mode);
-    const result: Uint8Array = this.wasmModule.decrypt(
-      dataBuffer,
-      keyBuffer,
-      ivForCall!,
-      algorithm
+    const padding = paddings[options.padding ?? 1];
+    if (padding === undefined) {
+      throw new Error(`Unsupported ISO 9797-1 padding method: ${options.padding}`);
+    }
+    const length = options.length ?? 8;
+    if (!Number.isInteger(length) || length < 4 || length > 8) {
+      throw new Error('DES MAC length must be 4 to 8 bytes');
+    }
+    return { algorithm, padding, length };
+  }
+
+  mac(data: CryptoInput, options: DesMacOptions): Buffer {
+    const { algorithm, padding, length } = this.macParams(options);
+    const result: Uint8Array = this.wasmModule.des_mac(
+      this.toBuffer(data),
+      this.toBuffer(options.key),
+      algorithm,
+      padding
+    );
+    return Buffer.from(result.subarray(0, length));
+  }
+
+  verifyMac(data: CryptoInput, mac: CryptoInput, options: DesMacOptions): boolean {
+    const { algorithm, padding } = this.macParams(options);
+    return this.wasmModule.des_mac_verify(
+      this.toBuffer(data),
+      this.toBuffer(options.key),
+      this.toBuffer(mac),
+      algorithm,
+      padding
+    );
+  }
+
+  createMac(options: DesMacOptions): DesMacInstance {
+    const { algorithm, padding, length } = this.macParams(options);
+    const stream = new this.wasmModule.StreamingMac(this.toBuffer(options.key), algorithm, padding);
+    const instance: DesMacInstance = {
+      update: (data: CryptoInput): DesMacInstance => {
+        stream.update(this.toBuffer(data));
+        return instance;
+      },
+      digest: (): Buffer => {
+        try {
+          return Buffer.from((stream.finalize() as Uint8Array).subarray(0, length));
+        } finally {
+          stream.free();
+        }
+      },
+      verify: (mac: CryptoInput): boolean => {
+        try {
+          return stream.verify(this.toBuffer(mac));
+        } finally {
+          stream.free();
+        }
+      },
+      free: () => stream.free(),
+    };
+    return instance;
+  }
+}
+
+/**
+ * Ascon-AEAD128 (NIST SP 800-232); the 16-byte tag is appended to the ciphertext
+ */
+class AsconCipher extends BaseCipher implements CipherFunction {
+  private nonce(options: CipherOptions): Uint8Array {
+    if (!options.iv) throw new Error('Nonce is required for Ascon-AEAD128');
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== 16) throw new Error('Nonce must be 16 bytes for Ascon-AEAD128');
+    return nonce;
+  }
+
+  encrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [16]);
+    const nonce = this.nonce(options);
+    return Buffer.from(
+      this.wasmModule.encrypt(this.toBuffer(data), keyBuffer, nonce, this.aadBuffer(options))
+    );
+  }
+
+  decrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [16]);
+    const nonce = this.nonce(options);
+    return Buffer.from(
+      this.wasmModule.decrypt(this.toBuffer(data), keyBuffer, nonce, this.aadBuffer(options))
+    );
+  }
+}
+
+/**
+ * Salsa20 (8-byte nonce) and XSalsa20 (24-byte nonce); unauthenticated
+ */
+class Salsa20Cipher extends BaseCipher implements CipherFunction {
+  private xor(data: CryptoInput, options: CipherOptions): Buffer {
+    const keyBuffer = this.toBuffer(options.key);
+    this.validateKeyLength(keyBuffer, [32]);
+    if (!options.iv) throw new Error('Nonce is required for Salsa20');
+    const nonce = this.toBuffer(options.iv);
+    if (nonce.length !== 8 && nonce.length !== 24) {
+      throw new Error('Nonce must be 8 bytes for Salsa20, or 24 bytes for XSalsa20');
+    }
+    const alg = this.wasmModule.Salsa20Algorithm;
+    const algorithm = nonce.length === 24 ? alg.XSalsa20 : alg.Salsa20;
+    return Buffer.from(this.wasmModule.salsa20_xor(this.toBuffer(data), keyBuffer, nonce, algorithm));
+  }
+
+  encrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    return this.xor(data, options);
+  }
+
+  decrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    return this.xor(data, options);
+  }
+}
+
+type BlockFamily = 'Camellia' | 'Aria' | 'Sm4';
+
+/**
+ * Camellia, ARIA and SM4 in GCM, CBC (PKCS#7) and CTR mode; the key length
+ * selects the variant
+ */
+class Block128Cipher extends BaseCipher implements CipherFunction {
+  constructor(wasmModule: any, private family: BlockFamily) {
+    super(wasmModule);
+  }
+
+  private params(options: CipherOptions): { key: Uint8Array; iv: Uint8Array; algorithm: number } {
+    const key = this.toBuffer(options.key);
+    const label = this.family.toUpperCase();
+    this.validateKeyLength(key, this.family === 'Sm4' ? [16] : [16, 24, 32]);
+    const mode = (options.mode || 'cbc').toUpperCase();
+    if (mode !== 'GCM' && mode !== 'CBC' && mode !== 'CTR') {
+      throw new Error(`Unsupported cipher mode for ${label}: ${mode}`);
+    }
+    const ivLength = mode === 'GCM' ? 12 : 16;
+    if (!options.iv) throw new Error(`${mode === 'GCM' ? 'Nonce' : 'IV'} is required for ${label}-${mode}`);
+    const iv = this.toBuffer(options.iv);
+    if (iv.length !== ivLength) {
+      throw new Error(`${label}-${mode} ${mode === 'GCM' ? 'nonce' : 'IV'} must be ${ivLength} bytes`);
+    }
+    const bits = this.family === 'Sm4' ? '' : String(key.length * 8);
+    const name = `${this.family}${bits}${mode.charAt(0)}${mode.slice(1).toLowerCase()}`;
+    return { key, iv, algorithm: this.wasmModule.BlockAlgorithm[name] };
+  }
+
+  encrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    const { key, iv, algorithm } = this.params(options);
+    return Buffer.from(
+      this.wasmModule.encrypt(this.toBuffer(data), key, iv, algorithm, this.aadBuffer(options))
+    );
+  }
+
+  decrypt(data: CryptoInput, options: CipherOptions): Buffer {
+    const { key, iv, algorithm } = this.params(options);
+    return Buffer.from(
+      this.wasmModule.decrypt(this.toBuffer(data), key, iv, algorithm, this.aadBuffer(options))
     );
-    return Buffer.from(result);
   }
 }
 
 /**
  * Create cipher function wrapper
  */
-function createCipherFunction(): CipherFunction {
-  let wasmModule: any;
-  let cipherInstance: AESCipher;
+function createCipherFunction(): AesCipherFunction {
+  let cipherInstance: AESCipher | undefined;
+  const instance = (): AESCipher => {
+    if (!cipherInstance) {
+      const resolvedPath = path.join(__dirname, 'aes_wasm', 'aes_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      cipherInstance = new AESCipher(require(resolvedPath));
+    }
+    return cipherInstance;
+  };
 
   return {
     encrypt(data: CryptoInput, options: CipherOptions): Buffer {
-      if (!wasmModule) {
-        const resolvedPath = path.join(__dirname, 'aes_wasm', 'aes_wasm.js');
-        // eslint-disable-next-line @typescript-eslint/no-var-requires
-        wasmModule = require(resolvedPath);
-        cipherInstance = new AESCipher(wasmModule);
-      }
-      return cipherInstance.encrypt(data, options);
+      return instance().encrypt(data, options);
     },
 
     decrypt(data: CryptoInput, options: CipherOptions): Buffer {
-      if (!wasmModule) {
-        const resolvedPath = path.join(__dirname, 'aes_wasm', 'aes_wasm.js');
-        // eslint-disable-next-line @typescript-eslint/no-var-requires
-        wasmModule = require(resolvedPath);
-        cipherInstance = new AESCipher(wasmModule);
-      }
-      return cipherInstance.decrypt(data, options);
+      return instance().decrypt(data, options);
+    },
+
+    encryptDetached(data: CryptoInput, options: CipherOptions): DetachedCiphertext {
+      return instance().encryptDetached(data, options);
+    },
+
+    decryptDetached(data: CryptoInput, tag: CryptoInput, options: CipherOptions): Buffer {
+      return instance().decryptDetached(data, tag, options);
+    },
+
+    createStreamingEncryptor(options: StreamingCipherOptions): StreamingCipherInstance {
+      return instance().createStreamingEncryptor(options);
+    },
+
+    createStreamingDecryptor(options: StreamingCipherOptions): StreamingCipherInstance {
+      return instance().createStreamingDecryptor(options);
+    },
+
+    createKeyedCipher(options: KeyedCipherOptions): KeyedCipherInstance {
+      return instance().createKeyedCipher(options);
+    },
+
+    createKeystream(options: KeystreamOptions): KeystreamInstance {
+      return instance().createKeystream(options);
+    },
+
+    wrapKey(keyData: CryptoInput, kek: CryptoInput, options?: KeyWrapOptions): Buffer {
+      return instance().wrapKey(keyData, kek, options);
+    },
+
+    unwrapKey(wrapped: CryptoInput, kek: CryptoInput, options?: KeyWrapOptions): Buffer {
+      return instance().unwrapKey(wrapped, kek, options);
+    },
+
+    encryptSectors(data: CryptoInput, options: XtsSectorOptions): Buffer {
+      return instance().encryptSectors(data, options);
+    },
+
+    decryptSectors(data: CryptoInput, options: XtsSectorOptions): Buffer {
+      return instance().decryptSectors(data, options);
     },
   };
 }
 
 // Export cipher functions
 export const aes = createCipherFunction();
-function createChaCha20Function(): CipherFunction {
-  let wasmModule: any;
-  let cipherInstance: ChaCha20Cipher;
+function createChaCha20Function(): ChaCha20CipherFunction {
+  let cipherInstance: ChaCha20Cipher | undefined;
+  const instance = (): ChaCha20Cipher => {
+    if (!cipherInstance) {
+      const resolvedPath = path.join(__dirname, 'chacha20_wasm', 'chacha20_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      cipherInstance = new ChaCha20Cipher(require(resolvedPath));
+    }
+    return cipherInstance;
+  };
   return {
-    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => {
-      if (!wasmModule) {
-        const resolvedPath = path.join(__dirname, 'chacha20_wasm', 'chacha20_wasm.js');
-        // eslint-disable-next-line @typescript-eslint/no-var-requires
-        wasmModule = require(resolvedPath);
-        cipherInstance = new ChaCha20Cipher(wasmModule);
-      }
-      return cipherInstance.encrypt(data, options);
-    },
-    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => {
-      if (!wasmModule) {
-        const resolvedPath = path.join(__dirname, 'chacha20_wasm', 'chacha20_wasm.js');
-        // eslint-disable-next-line @typescript-eslint/no-var-requires
-        wasmModule = require(resolvedPath);
-        cipherInstance = new ChaCha20Cipher(wasmModule);
-      }
-      return cipherInstance.decrypt(data, options);
-    },
+    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().encrypt(data, options),
+    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().decrypt(data, options),
+    encryptDetached: (data: CryptoInput, options: CipherOptions): DetachedCiphertext =>
+      instance().encryptDetached(data, options),
+    decryptDetached: (data: CryptoInput, tag: CryptoInput, options: CipherOptions): Buffer =>
+      instance().decryptDetached(data, tag, options),
+    createStreamingEncryptor: (options: StreamingCipherOptions): StreamingCipherInstance =>
+      instance().createStreamingEncryptor(options),
+    createStreamingDecryptor: (options: StreamingCipherOptions): StreamingCipherInstance =>
+      instance().createStreamingDecryptor(options),
+    createKeyedCipher: (options: KeyedCipherOptions): KeyedCipherInstance =>
+      instance().createKeyedCipher(options),
+    createKeystream: (options: KeystreamOptions): KeystreamInstance =>
+      instance().createKeystream(options),
   };
 }
 
-function createDESFunction(): CipherFunction {
-  let wasmModule: any;
-  let cipherInstance: DESCipher;
+function createDESFunction(): DesCipherFunction {
+  let cipherInstance: DESCipher | undefined;
+  const instance = (): DESCipher => {
+    if (!cipherInstance) {
+      const resolvedPath = path.join(__dirname, 'des_wasm', 'des_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      cipherInstance = new DESCipher(require(resolvedPath));
+    }
+    return cipherInstance;
+  };
   return {
-    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => {
-      if (!wasmModule) {
-        const resolvedPath = path.join(__dirname, 'des_wasm', 'des_wasm.js');
-        // eslint-disable-next-line @typescript-eslint/no-var-requires
-        wasmModule = require(resolvedPath);
-        cipherInstance = new DESCipher(wasmModule);
-      }
-      return cipherInstance.encrypt(data, options);
-    },
-    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => {
-      if (!wasmModule) {
-        const resolvedPath = path.join(__dirname, 'des_wasm', 'des_wasm.js');
+    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().encrypt(data, options),
+    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().decrypt(data, options),
+    createKeystream: (options: KeystreamOptions): KeystreamInstance =>
+      instance().createKeystream(options),
+    checkKey: (key: CryptoInput): DesKeyCheck => instance().checkKey(key),
+    setOddParity: (key: CryptoInput): Buffer => instance().setOddParity(key),
+    mac: (data: CryptoInput, options: DesMacOptions): Buffer => instance().mac(data, options),
+    verifyMac: (data: CryptoInput, mac: CryptoInput, options: DesMacOptions): boolean =>
+      instance().verifyMac(data, mac, options),
+    createMac: (options: DesMacOptions): DesMacInstance => instance().createMac(options),
+  };
+}
+
+function createAsconFunction(): CipherFunction {
+  let cipherInstance: AsconCipher | undefined;
+  const instance = (): AsconCipher => {
+    if (!cipherInstance) {
+      const resolvedPath = path.join(__dirname, 'ascon_wasm', 'ascon_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      cipherInstance = new AsconCipher(require(resolvedPath));
+    }
+    return cipherInstance;
+  };
+  return {
+    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().encrypt(data, options),
+    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().decrypt(data, options),
+  };
+}
+
+let blockWasmModule: any;
+function createBlock128Function(family: BlockFamily): CipherFunction {
+  let cipherInstance: Block128Cipher | undefined;
+  const instance = (): Block128Cipher => {
+    if (!cipherInstance) {
+      if (!blockWasmModule) {
+        const resolvedPath = path.join(__dirname, 'block_wasm', 'block_wasm.js');
         // eslint-disable-next-line @typescript-eslint/no-var-requires
-        wasmModule = require(resolvedPath);
-        cipherInstance = new DESCipher(wasmModule);
+        blockWasmModule = require(resolvedPath);
       }
-      return cipherInstance.decrypt(data, options);
-    },
+      cipherInstance = new Block128Cipher(blockWasmModule, family);
+    }
+    return cipherInstance;
+  };
+  return {
+    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().encrypt(data, options),
+    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().decrypt(data, options),
   };
 }
 
 export const chacha20 = createChaCha20Function();
 export const des = createDESFunction();
+export const ascon = createAsconFunction();
+export const camellia = createBlock128Function('Camellia');
+export const aria = createBlock128Function('Aria');
+export const sm4 = createBlock128Function('Sm4');
+
+/**
+ * HChaCha20: derive a 32-byte subkey from a 32-byte key and 16-byte input
+ * (the first 16 bytes of an XChaCha20 nonce)
+ */
+export function hchacha20(key: CryptoInput, input: CryptoInput): Buffer {
+  const resolvedPath = path.join(__dirname, 'chacha20_wasm', 'chacha20_wasm.js');
+  // eslint-disable-next-line @typescript-eslint/no-var-requires
+  const wasmModule = require(resolvedPath);
+  const toBytes = (v: CryptoInput): Uint8Array => (typeof v === 'string' ? Buffer.from(v, 'utf8') : v);
+  return Buffer.from(wasmModule.hchacha20(toBytes(key), toBytes(input)));
+}
+
+function createSalsa20Function(): CipherFunction {
+  let cipherInstance: Salsa20Cipher | undefined;
+  const instance = (): Salsa20Cipher => {
+    if (!cipherInstance) {
+      const resolvedPath = path.join(__dirname, 'chacha20_wasm', 'chacha20_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      cipherInstance = new Salsa20Cipher(require(resolvedPath));
+    }
+    return cipherInstance;
+  };
+  return {
+    encrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().encrypt(data, options),
+    decrypt: (data: CryptoInput, options: CipherOptions): Buffer => instance().decrypt(data, options),
+  };
+}
+
+export const salsa20 = createSalsa20Function();
+
+/**
+ * NaCl/libsodium crypto_secretbox (XSalsa20-Poly1305): 32-byte key, 24-byte
+ * nonce, output is the 16-byte tag followed by the ciphertext
+ */
+class Secretbox extends BaseCipher implements SecretboxFunction {
+  seal(data: CryptoInput, key: CryptoInput, nonce: CryptoInput): Buffer {
+    return Buffer.from(
+      this.wasmModule.secretbox_seal(this.toBuffer(data), this.toBuffer(key), this.toBuffer(nonce))
+    );
+  }
+
+  open(boxed: CryptoInput, key: CryptoInput, nonce: CryptoInput): Buffer {
+    return Buffer.from(
+      this.wasmModule.secretbox_open(this.toBuffer(boxed), this.toBuffer(key), this.toBuffer(nonce))
+    );
+  }
+}
+
+function createSecretboxFunction(): SecretboxFunction {
+  let boxInstance: Secretbox | undefined;
+  const instance = (): Secretbox => {
+    if (!boxInstance) {
+      const resolvedPath = path.join(__dirname, 'chacha20_wasm', 'chacha20_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      boxInstance = new Secretbox(require(resolvedPath));
+    }
+    return boxInstance;
+  };
+  return {
+    seal: (data: CryptoInput, key: CryptoInput, nonce: CryptoInput): Buffer => instance().seal(data, key, nonce),
+    open: (boxed: CryptoInput, key: CryptoInput, nonce: CryptoInput): Buffer => instance().open(boxed, key, nonce),
+  };
+}
+
+export const secretbox = createSecretboxFunction();
 
 // Asymmetric/Key-exchange wrappers
 class RSAOAEP extends BaseCipher {
@@ -519,6 +1315,24 @@ class RSAOAEP extends BaseCipher {
     const out: Uint8Array = this.wasm.rsa_oaep_decrypt(ct, sk, hashValue, label);
     return Buffer.from(out);
   }
+  // Hybrid: a fresh AES-256-GCM key wrapped with OAEP, for payloads of any size.
+  // Container v1: version | hash id | wrapped key length (u16 BE) | wrapped key | nonce (12) | ciphertext | tag (16)
+  envelopeEncrypt(plaintext: CryptoInput, publicKeyDer: CryptoInput, options?: { hash?: 'sha1' | 'sha256' | 'sha384' | 'sha512'; label?: CryptoInput }): Buffer {
+    const data = this.toBuffer(plaintext);
+    const pk = this.toBuffer(publicKeyDer);
+    const hashValue = (options?.hash ?? 'sha256');
+    const label = options?.label ? this.toBuffer(options.label) : undefined;
+    const out: Uint8Array = this.wasm.rsa_envelope_encrypt(data, pk, hashValue, label);
+    return Buffer.from(out);
+  }
+  // The OAEP hash is read from the envelope header
+  envelopeDecrypt(envelope: CryptoInput, privateKeyDer: CryptoInput, options?: { label?: CryptoInput }): Buffer {
+    const data = this.toBuffer(envelope);
+    const sk = this.toBuffer(privateKeyDer);
+    const label = options?.label ? this.toBuffer(options.label) : undefined;
+    const out: Uint8Array = this.wasm.rsa_envelope_decrypt(data, sk, label);
+    return Buffer.from(out);
+  }
 }
 
 class X25519 extends BaseCipher {
@@ -539,6 +1353,40 @@ class X25519 extends BaseCipher {
     const ss: Uint8Array = this.wasm.x25519_derive_shared_secret(sk, pk);
     return Buffer.from(ss);
   }
+  // libsodium crypto_box: X25519 + HSalsa20 key derivation + XSalsa20-Poly1305
+  boxBeforenm(peerPublicKey: CryptoInput, privateKey: CryptoInput): Buffer {
+    return Buffer.from(this.wasm.box_beforenm(this.toBuffer(peerPublicKey), this.toBuffer(privateKey)));
+  }
+  boxSeal(plaintext: CryptoInput, nonce: CryptoInput, peerPublicKey: CryptoInput, privateKey: CryptoInput): Buffer {
+    const out: Uint8Array = this.wasm.box_seal(
+      this.toBuffer(plaintext),
+      this.toBuffer(nonce),
+      this.toBuffer(peerPublicKey),
+      this.toBuffer(privateKey)
+    );
+    return Buffer.from(out);
+  }
+  boxOpen(boxed: CryptoInput, nonce: CryptoInput, peerPublicKey: CryptoInput, privateKey: CryptoInput): Buffer {
+    const out: Uint8Array = this.wasm.box_open(
+      this.toBuffer(boxed),
+      this.toBuffer(nonce),
+      this.toBuffer(peerPublicKey),
+      this.toBuffer(privateKey)
+    );
+    return Buffer.from(out);
+  }
+  sealedBoxSeal(plaintext: CryptoInput, recipientPublicKey: CryptoInput): Buffer {
+    const out: Uint8Array = this.wasm.sealed_box_seal(this.toBuffer(plaintext), this.toBuffer(recipientPublicKey));
+    return Buffer.from(out);
+  }
+  sealedBoxOpen(sealed: CryptoInput, recipientPublicKey: CryptoInput, recipientPrivateKey: CryptoInput): Buffer {
+    const out: Uint8Array = this.wasm.sealed_box_open(
+      this.toBuffer(sealed),
+      this.toBuffer(recipientPublicKey),
+      this.toBuffer(recipientPrivateKey)
+    );
+    return Buffer.from(out);
+  }
 }
 
 class ECDH extends BaseCipher {
@@ -567,6 +1415,207 @@ class ECDH extends BaseCipher {
   }
 }
 
+const HPKE_KEMS: Record<string, string> = {
+  x25519: 'X25519HkdfSha256',
+  p256: 'P256HkdfSha256',
+  p384: 'P384HkdfSha384',
+};
+const HPKE_KDFS: Record<string, string> = { sha256: 'HkdfSha256', sha384: 'HkdfSha384', sha512: 'HkdfSha512' };
+const HPKE_AEADS: Record<string, string> = {
+  'aes-128-gcm': 'Aes128Gcm',
+  'aes-256-gcm': 'Aes256Gcm',
+  'chacha20-poly1305': 'ChaCha20Poly1305',
+  'export-only': 'ExportOnly',
+};
+
+/**
+ * HPKE (RFC 9180); defaults to DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+ * AES-128-GCM
+ */
+class HpkeCipher extends BaseCipher implements HpkeFunction {
+  private withSuite<T>(options: HpkeSuiteOptions, fn: (suite: any) => T): T {
+    const kem = HPKE_KEMS[options.kem ?? 'x25519'];
+    const kdf = HPKE_KDFS[options.kdf ?? 'sha256'];
+    const aead = HPKE_AEADS[options.aead ?? 'aes-128-gcm'];
+    if (!kem) throw new Error(`Unsupported HPKE KEM: ${options.kem}`);
+    if (!kdf) throw new Error(`Unsupported HPKE KDF: ${options.kdf}`);
+    if (!aead) throw new Error(`Unsupported HPKE AEAD: ${options.aead}`);
+    const suite = new this.wasmModule.Hpke(
+      this.wasmModule.HpkeKem[kem],
+      this.wasmModule.HpkeKdf[kdf],
+      this.wasmModule.HpkeAead[aead]
+    );
+    try {
+      return fn(suite);
+    } finally {
+      suite.free();
+    }
+  }
+
+  private optional(input?: CryptoInput): Uint8Array | undefined {
+    return input === undefined ? undefined : this.toBuffer(input);
+  }
+
+  private setupArgs(options: HpkeSetupOptions): (Uint8Array | undefined)[] {
+    return [options.info, options.psk, options.pskId, options.authKey].map((v) => this.optional(v));
+  }
+
+  generateKeypair(options: HpkeSuiteOptions = {}): { privateKey: Buffer; publicKey: Buffer } {
+    const arr = this.withSuite(options, (suite) => suite.generate_keypair());
+    return { privateKey: Buffer.from(arr[0]), publicKey: Buffer.from(arr[1]) };
+  }
+
+  deriveKeypair(ikm: CryptoInput, options: HpkeSuiteOptions = {}): { privateKey: Buffer; publicKey: Buffer } {
+    const arr = this.withSuite(options, (suite) => suite.derive_keypair(this.toBuffer(ikm)));
+    return { privateKey: Buffer.from(arr[0]), publicKey: Buffer.from(arr[1]) };
+  }
+
+  setupSender(recipientPublicKey: CryptoInput, options: HpkeSetupOptions = {}): HpkeSenderContext {
+    const ctx = this.withSuite(options, (suite) =>
+      suite.setup_sender(this.toBuffer(recipientPublicKey), ...this.setupArgs(options))
+    );
+    return {
+      enc: Buffer.from(ctx.enc),
+      seal: (data, aad) => Buffer.from(ctx.seal(this.toBuffer(data), this.optional(aad))),
+      export: (exporterContext, length) => Buffer.from(ctx.export(this.toBuffer(exporterContext), length)),
+      free: () => ctx.free(),
+    };
+  }
+
+  setupRecipient(
+    enc: CryptoInput,
+    recipientPrivateKey: CryptoInput,
+    options: HpkeSetupOptions = {}
+  ): HpkeRecipientContext {
+    const ctx = this.withSuite(options, (suite) =>
+      suite.setup_recipient(this.toBuffer(enc), this.toBuffer(recipientPrivateKey), ...this.setupArgs(options))
+    );
+    return {
+      open: (data, aad) => Buffer.from(ctx.open(this.toBuffer(data), this.optional(aad))),
+      export: (exporterContext, length) => Buffer.from(ctx.export(this.toBuffer(exporterContext), length)),
+      free: () => ctx.free(),
+    };
+  }
+
+  seal(data: CryptoInput, recipientPublicKey: CryptoInput, options: HpkeSealOptions = {}): HpkeSealed {
+    const ctx = this.setupSender(recipientPublicKey, options);
+    try {
+      return { enc: ctx.enc, ciphertext: ctx.seal(data, options.aad) };
+    } finally {
+      ctx.free();
+    }
+  }
+
+  open(
+    ciphertext: CryptoInput,
+    enc: CryptoInput,
+    recipientPrivateKey: CryptoInput,
+    options: HpkeSealOptions = {}
+  ): Buffer {
+    const ctx = this.setupRecipient(enc, recipientPrivateKey, options);
+    try {
+      return ctx.open(ciphertext, options.aad);
+    } finally {
+      ctx.free();
+    }
+  }
+}
+
+function createHpkeFunction(): HpkeFunction {
+  let hpkeInstance: HpkeCipher | undefined;
+  const instance = (): HpkeCipher => {
+    if (!hpkeInstance) {
+      const resolvedPath = path.join(__dirname, 'hpke_wasm', 'hpke_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      hpkeInstance = new HpkeCipher(require(resolvedPath));
+    }
+    return hpkeInstance;
+  };
+  return {
+    generateKeypair: (options?: HpkeSuiteOptions) => instance().generateKeypair(options),
+    deriveKeypair: (ikm: CryptoInput, options?: HpkeSuiteOptions) => instance().deriveKeypair(ikm, options),
+    seal: (data: CryptoInput, recipientPublicKey: CryptoInput, options?: HpkeSealOptions) =>
+      instance().seal(data, recipientPublicKey, options),
+    open: (ciphertext: CryptoInput, enc: CryptoInput, recipientPrivateKey: CryptoInput, options?: HpkeSealOptions) =>
+      instance().open(ciphertext, enc, recipientPrivateKey, options),
+    setupSender: (recipientPublicKey: CryptoInput, options?: HpkeSetupOptions) =>
+      instance().setupSender(recipientPublicKey, options),
+    setupRecipient: (enc: CryptoInput, recipientPrivateKey: CryptoInput, options?: HpkeSetupOptions) =>
+      instance().setupRecipient(enc, recipientPrivateKey, options),
+  };
+}
+
+export const hpke = createHpkeFunction();
+
+const ECIES_CURVES: Record<string, string> = { secp256k1: 'Secp256k1', p256: 'P256' };
+const ECIES_AEADS: Record<string, string> = {
+  'aes-256-gcm': 'Aes256Gcm',
+  'xchacha20-poly1305': 'XChaCha20Poly1305',
+};
+
+/**
+ * ECIES compatible with eciesjs; defaults to its secp256k1, AES-256-GCM
+ * configuration
+ */
+class EciesCipher extends BaseCipher implements EciesFunction {
+  private withEcies<T>(options: EciesOptions, fn: (ecies: any) => T): T {
+    const curve = ECIES_CURVES[options.curve ?? 'secp256k1'];
+    const aead = ECIES_AEADS[options.aead ?? 'aes-256-gcm'];
+    if (!curve) throw new Error(`Unsupported ECIES curve: ${options.curve}`);
+    if (!aead) throw new Error(`Unsupported ECIES cipher: ${options.aead}`);
+    const ecies = new this.wasmModule.Ecies(
+      this.wasmModule.EciesCurve[curve],
+      this.wasmModule.EciesAead[aead],
+      options.nonceLength ?? 16,
+      options.compressedEphemeralKey ?? false,
+      options.compressedHkdfKey ?? false
+    );
+    try {
+      return fn(ecies);
+    } finally {
+      ecies.free();
+    }
+  }
+
+  generateKeypair(options: EciesOptions = {}): { privateKey: Buffer; publicKey: Buffer } {
+    const arr = this.withEcies(options, (ecies) => ecies.generate_keypair());
+    return { privateKey: Buffer.from(arr[0]), publicKey: Buffer.from(arr[1]) };
+  }
+
+  encrypt(data: CryptoInput, receiverPublicKey: CryptoInput, options: EciesOptions = {}): Buffer {
+    return Buffer.from(
+      this.withEcies(options, (ecies) => ecies.encrypt(this.toBuffer(receiverPublicKey), this.toBuffer(data)))
+    );
+  }
+
+  decrypt(ciphertext: CryptoInput, receiverPrivateKey: CryptoInput, options: EciesOptions = {}): Buffer {
+    return Buffer.from(
+      this.withEcies(options, (ecies) => ecies.decrypt(this.toBuffer(receiverPrivateKey), this.toBuffer(ciphertext)))
+    );
+  }
+}
+
+function createEciesFunction(): EciesFunction {
+  let eciesInstance: EciesCipher | undefined;
+  const instance = (): EciesCipher => {
+    if (!eciesInstance) {
+      const resolvedPath = path.join(__dirname, 'ecies_wasm', 'ecies_wasm.js');
+      // eslint-disable-next-line @typescript-eslint/no-var-requires
+      eciesInstance = new EciesCipher(require(resolvedPath));
+    }
+    return eciesInstance;
+  };
+  return {
+    generateKeypair: (options?: EciesOptions) => instance().generateKeypair(options),
+    encrypt: (data: CryptoInput, receiverPublicKey: CryptoInput, options?: EciesOptions) =>
+      instance().encrypt(data, receiverPublicKey, options),
+    decrypt: (ciphertext: CryptoInput, receiverPrivateKey: CryptoInput, options?: EciesOptions) =>
+      instance().decrypt(ciphertext, receiverPrivateKey, options),
+  };
+}
+
+export const ecies = createEciesFunction();
+
 export const rsa_oaep = new (RSAOAEP as any)();
 export const x25519 = new (X25519 as any)();
 export const ecdh = new (ECDH as any)();
@@ -575,8 +1624,17 @@ export const ecdh = new (ECDH as any)();
 export const cipher = {
   aes,
   chacha20,
+  hchacha20,
+  salsa20,
+  secretbox,
   des,
+  ascon,
+  camellia,
+  aria,
+  sm4,
   rsa_oaep,
   x25519,
   ecdh,
+  hpke,
+  ecies,
 };
@@ -9,6 +9,7 @@ import {
   HashInstance,
   HashOutput,
   Blake3Options,
+  XofOptions,
 } from '../types';
 import path from 'path';
 
@@ -214,6 +215,10 @@ class Blake2bHash extends BaseHash {}
 class Blake2sHash extends BaseHash {}
 class Blake3Hash extends BaseHash {}
 
+// Ascon (NIST SP 800-232)
+class AsconHash256Hash extends BaseHash {}
+class AsconXof128Hash extends BaseHash {}
+
 // Others
 class WhirlpoolHash extends BaseHash {}
 class RIPEMD160Hash extends BaseHash {}
@@ -257,6 +262,19 @@ export const ripemd160 = createHashFunction(
   RIPEMD160Hash,
   { algo: 'ripemd160' }
 );
+export const ascon_hash256 = createHashFunction(
+  ['cipher', 'ascon_wasm', 'ascon_wasm.js'],
+  AsconHash256Hash,
+  { algo: 'hash256' }
+);
+export const ascon_xof128 = createHashFunction(
+  ['cipher', 'ascon_wasm', 'ascon_wasm.js'],
+  AsconXof128Hash,
+  { algo: 'xof128', hash_length: 32 }
+) as unknown as {
+  (input: CryptoInput, options?: XofOptions): string | Buffer;
+  create(options?: XofOptions): HashInstance;
+};
 
 // Export all hash functions as an object
 export const hash = {
@@ -272,6 +290,8 @@ export const hash = {
   blake3,
   whirlpool,
   ripemd160,
+  ascon_hash256,
+  ascon_xof128,
 };
 
 // Namespace alias to match crates naming
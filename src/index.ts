@@ -13,6 +13,7 @@ import { cipher } from './cipher';
 import { hmac } from './hmac';
 import { kdf } from './kdf';
 import { dsa } from './dsa';
+import { payments } from './payments';
 import { randomBytes as nodeRandomBytes, timingSafeEqual as nodeTimingSafeEqual } from 'crypto';
 import type { CryptoInput } from './types';
 import { convertToBuffer, timeSafeEqual as fallbackTimingSafeEqual } from './utils/validation';
@@ -46,6 +47,7 @@ export {
   hmac,
   kdf,
   dsa,
+  payments,
   zk
 };
 
@@ -56,6 +58,7 @@ const cryptographer: {
   hmac: typeof hmac;
   kdf: typeof kdf;
   dsa: typeof dsa;
+  payments: typeof payments;
   randomBytes: typeof randomBytes;
   timingSafeEqual: typeof timingSafeEqual;
   zk: typeof zk;
@@ -65,6 +68,7 @@ const cryptographer: {
   hmac,
   kdf,
   dsa,
+  payments,
   randomBytes,
   timingSafeEqual,
   zk
@@ -0,0 +1,113 @@
+/**
+ * Card-payment primitives: ISO 9564 PIN blocks, key check values and
+ * ANSI X9.143 (TR-31) key blocks
+ */
+import path from 'path';
+import { CryptoInput, KcvOptions, PinBlockFormat, PinBlockFunction, Tr31Function, Tr31Header } from '../types';
+
+function toBuffer(input: CryptoInput): Uint8Array {
+  if (typeof input === 'string') return Buffer.from(input, 'utf8');
+  if (Buffer.isBuffer(input)) return new Uint8Array(input);
+  if (input instanceof Uint8Array) return input;
+  throw new TypeError('Input must be string, Buffer, or Uint8Array');
+}
+
+let wasmModule: any | undefined;
+function ensure(): any {
+  if (!wasmModule) {
+    const p = path.join(__dirname, 'payments_wasm', 'payments_wasm.js');
+    // eslint-disable-next-line @typescript-eslint/no-var-requires
+    wasmModule = require(p);
+  }
+  return wasmModule;
+}
+
+// -------- PIN blocks --------
+class PinBlock implements PinBlockFunction {
+  private format(format: PinBlockFormat): number {
+    const wasm = ensure();
+    const formats: Record<number, number> = {
+      0: wasm.PinBlockFormat.Iso0,
+      1: wasm.PinBlockFormat.Iso1,
+      3: wasm.PinBlockFormat.Iso3,
+      4: wasm.PinBlockFormat.Iso4,
+    };
+    if (!(format in formats)) throw new Error(`Unsupported PIN block format: ${format}`);
+    return formats[format];
+  }
+  encode(pin: string, pan: string, format: PinBlockFormat = 0): Buffer {
+    return Buffer.from(ensure().pin_block_encode(pin, pan, this.format(format)));
+  }
+  decode(block: CryptoInput, pan: string, format: PinBlockFormat = 0): string {
+    return ensure().pin_block_decode(toBuffer(block), pan, this.format(format));
+  }
+  encrypt(pin: string, pan: string, key: CryptoInput, format: PinBlockFormat = 0): Buffer {
+    return Buffer.from(ensure().pin_block_encrypt(pin, pan, toBuffer(key), this.format(format)));
+  }
+  decrypt(block: CryptoInput, pan: string, key: CryptoInput, format: PinBlockFormat = 0): string {
+    return ensure().pin_block_decrypt(toBuffer(block), pan, toBuffer(key), this.format(format));
+  }
+}
+
+// -------- Key check values --------
+export function kcv(key: CryptoInput, options: KcvOptions): Buffer {
+  const wasm = ensure();
+  const algorithms: Record<string, number> = { des: wasm.KeyAlgorithm.Tdes, aes: wasm.KeyAlgorithm.Aes };
+  const methods: Record<string, number> = { 'zero-block': wasm.KcvMethod.ZeroBlock, cmac: wasm.KcvMethod.Cmac };
+  const algo = algorithms[options.algorithm];
+  const method = methods[options.method ?? 'zero-block'];
+  if (algo === undefined) throw new Error(`Unsupported KCV algorithm: ${options.algorithm}`);
+  if (method === undefined) throw new Error(`Unsupported KCV method: ${options.method}`);
+  return Buffer.from(wasm.kcv(toBuffer(key), algo, method));
+}
+
+// -------- TR-31 key blocks --------
+function renderHeader(header: Tr31Header): string {
+  const blocks = header.optionalBlocks ?? [];
+  const body = blocks
+    .map((b) => b.id + (4 + b.data.length).toString(16).toUpperCase().padStart(2, '0') + b.data)
+    .join('');
+  return (
+    header.version +
+    '0000' +
+    header.keyUsage +
+    header.algorithm +
+    header.modeOfUse +
+    (header.keyVersion ?? '00') +
+    (header.exportability ?? 'E') +
+    String(blocks.length).padStart(2, '0') +
+    '00' +
+    body
+  );
+}
+
+class Tr31 implements Tr31Function {
+  wrap(kbpk: CryptoInput, header: Tr31Header | string, key: CryptoInput): string {
+    const h = typeof header === 'string' ? header : renderHeader(header);
+    return ensure().tr31_wrap(toBuffer(kbpk), h, toBuffer(key));
+  }
+  unwrap(kbpk: CryptoInput, keyBlock: string): { header: Required<Tr31Header>; key: Buffer } {
+    const res = ensure().tr31_unwrap(toBuffer(kbpk), keyBlock);
+    try {
+      return {
+        header: {
+          version: res.version,
+          keyUsage: res.key_usage,
+          algorithm: res.algorithm,
+          modeOfUse: res.mode_of_use,
+          keyVersion: res.key_version,
+          exportability: res.exportability,
+          optionalBlocks: (res.optional_blocks as string[][]).map(([id, data]) => ({ id, data })),
+        },
+        key: Buffer.from(res.key),
+      };
+    } finally {
+      res.free();
+    }
+  }
+}
+
+export const pinBlock = new PinBlock();
+export const tr31 = new Tr31();
+
+export const payments = { pinBlock, kcv, tr31 };
@@ -0,0 +1,36 @@
+export enum KeyAlgorithm {
+  Tdes,
+  Aes
+}
+
+export enum KcvMethod {
+  ZeroBlock,
+  Cmac
+}
+
+export enum PinBlockFormat {
+  Iso0,
+  Iso1,
+  Iso3,
+  Iso4
+}
+
+export function kcv(key: Uint8Array, algo: KeyAlgorithm, method: KcvMethod): Uint8Array;
+export function pin_block_encode(pin: string, pan: string, format: PinBlockFormat): Uint8Array;
+export function pin_block_decode(block: Uint8Array, pan: string, format: PinBlockFormat): string;
+export function pin_block_encrypt(pin: string, pan: string, key: Uint8Array, format: PinBlockFormat): Uint8Array;
+export function pin_block_decrypt(block: Uint8Array, pan: string, key: Uint8Array, format: PinBlockFormat): string;
+export function tr31_wrap(kbpk: Uint8Array, header: string, key: Uint8Array): string;
+export function tr31_unwrap(kbpk: Uint8Array, key_block: string): UnwrappedKeyBlock;
+
+export class UnwrappedKeyBlock {
+  free(): void;
+  readonly version: string;
+  readonly key_usage: string;
+  readonly algorithm: string;
+  readonly mode_of_use: string;
+  readonly key_version: string;
+  readonly exportability: string;
+  readonly optional_blocks: string[][];
+  readonly key: Uint8Array;
+}
@@ -23,6 +23,16 @@ export interface HashOptions {
   outputFormat?: HashOutput;
 }
 
+/**
+ * Options for extendable-output functions (Ascon-XOF128)
+ */
+export interface XofOptions extends HashOptions {
+  /** Output length in bytes (default 32; camelCase variant) */
+  hashLength?: number;
+  /** Output length in bytes (default 32; snake_case variant) */
+  hash_length?: number;
+}
+
 /**
  * Options for BLAKE3
  * Note: We accept both camelCase and snake_case for convenience.
@@ -56,7 +66,20 @@ export interface HmacOptions extends HashOptions {
   key: CryptoInput;
 }
 
-export type CipherMode = 'cbc' | 'ecb' | 'ctr' | 'gcm' | 'ccm' | 'siv';
+export type CipherMode =
+  | 'cbc'
+  | 'ecb'
+  | 'cfb'
+  | 'cfb8'
+  | 'ofb'
+  | 'ctr'
+  | 'gcm'
+  | 'ccm'
+  | 'siv'
+  | 'gcm-siv'
+  | 'ocb3'
+  | 'eax'
+  | 'xts';
 
 export type Argon2Variant = 'id' | 'i' | 'd';
 
@@ -74,7 +97,7 @@ export interface CipherOptions {
   key: CryptoInput;
 
   /**
-   * Initialization vector
+   * Initialization vector (the 16-byte tweak in XTS mode)
    */
   iv?: CryptoInput;
 
@@ -84,9 +107,25 @@ export interface CipherOptions {
   mode?: CipherMode;
 
   /**
-   * Padding scheme
+   * Associated data authenticated (but not encrypted) by AEAD modes: GCM, CCM,
+   * SIV, GCM-SIV, OCB3, EAX and ChaCha20-Poly1305. AES-SIV also accepts an array of components,
+   * which become the RFC 5297 header vector ahead of the nonce.
    */
-  padding?: 'PKCS7' | 'NoPadding' | 'ZeroPadding';
+  aad?: CryptoInput | CryptoInput[];
+
+  /**
+   * Authentication tag length in bytes for `encryptDetached`: 12-16 for
+   * AES-GCM, an even number in 4-16 for AES-CCM, 8, 12 or 16 for AES-OCB3
+   * and AES-EAX (default 16).
+   */
+  tagLength?: number;
+
+  /**
+   * Block padding for AES and DES/3DES in CBC and ECB modes (default `PKCS7`).
+   * Stream modes (CTR, CFB, CFB8, OFB) accept only `NoPadding`. Bad padding on decryption
+   * is reported with the same error as any other failure.
+   */
+  padding?: 'PKCS7' | 'ISO7816' | 'ANSIX923' | 'ISO10126' | 'ZeroPadding' | 'NoPadding';
 }
 
 /**
@@ -206,10 +245,700 @@ export interface CipherFunction {
   decrypt(data: CryptoInput, options: CipherOptions): Buffer;
 }
 
+/**
+ * Ciphertext and authentication tag returned by `encryptDetached`
+ */
+export interface DetachedCiphertext {
+  ciphertext: Buffer;
+  tag: Buffer;
+}
+
+/**
+ * Options for STREAM (segmented AEAD) encryption
+ */
+export interface StreamingCipherOptions {
+  /**
+   * Encryption/Decryption key
+   */
+  key: CryptoInput;
+
+  /**
+   * 7-byte nonce prefix, unique per stream under a key
+   */
+  iv: CryptoInput;
+
+  /**
+   * Plaintext bytes per segment (default 65536); must match on both sides
+   */
+  segmentSize?: number;
+}
+
+/**
+ * Incremental STREAM encryptor or decryptor
+ */
+export interface StreamingCipherInstance {
+  /**
+   * Feed more input; returns every segment completed so far (may be empty)
+   */
+  update(data: CryptoInput): Buffer;
+
+  /**
+   * Flush the last segment. Decryption fails here if the stream was truncated
+   */
+  final(): Buffer;
+}
+
+/**
+ * Options for a cipher keyed once by `createKeyedCipher`
+ */
+export interface KeyedCipherOptions {
+  /**
+   * Encryption/Decryption key
+   */
+  key: CryptoInput;
+
+  /**
+   * AES only: `gcm` (default, 16/24/32-byte key) or `siv` (32/64-byte key)
+   */
+  mode?: 'gcm' | 'siv';
+
+  /**
+   * ChaCha20-Poly1305 only: 24 selects XChaCha20-Poly1305 (default 12)
+   */
+  nonceLength?: 12 | 24;
+}
+
+/**
+ * AEAD cipher bound to one key, for encrypting many messages without
+ * re-expanding the key or copying it into wasm on every call
+ */
+export interface KeyedCipherInstance {
+  /**
+   * Encrypt under the bound key; output matches the one-shot `encrypt`
+   */
+  encrypt(data: CryptoInput, options: Pick<CipherOptions, 'iv' | 'aad'>): Buffer;
+
+  /**
+   * Decrypt under the bound key
+   */
+  decrypt(data: CryptoInput, options: Pick<CipherOptions, 'iv' | 'aad'>): Buffer;
+
+  /**
+   * Zeroize the key in wasm memory; the instance cannot be used afterwards
+   */
+  free(): void;
+}
+
+/**
+ * Cipher interface for AEAD algorithms that can carry the tag separately,
+ * as WebCrypto, JWE and COSE expect, and encrypt inputs too large to buffer
+ */
+export interface AeadCipherFunction extends CipherFunction {
+  /**
+   * Encrypt data, returning the tag apart from the ciphertext
+   */
+  encryptDetached(data: CryptoInput, options: CipherOptions): DetachedCiphertext;
+
+  /**
+   * Decrypt data whose tag is supplied separately
+   */
+  decryptDetached(data: CryptoInput, tag: CryptoInput, options: CipherOptions): Buffer;
+
+  /**
+   * Start a STREAM encryption; each segment carries its own 16-byte tag
+   */
+  createStreamingEncryptor(options: StreamingCipherOptions): StreamingCipherInstance;
+
+  /**
+   * Start a STREAM decryption; reordered, altered or truncated input throws
+   */
+  createStreamingDecryptor(options: StreamingCipherOptions): StreamingCipherInstance;
+
+  /**
+   * Expand a key once and reuse it; call `free()` when done to zeroize it
+   */
+  createKeyedCipher(options: KeyedCipherOptions): KeyedCipherInstance;
+}
+
+/**
+ * Where the counter sits in the IV block and how it wraps: a 32, 64 or
+ * 128-bit big-endian counter at the end of the block, or a 32-bit
+ * little-endian counter at the start (ChaCha20's only layout)
+ */
+export type CounterLayout = 'ctr32be' | 'ctr64be' | 'ctr128be' | 'ctr32le';
+
+/**
+ * Options for `createKeystream`
+ */
+export interface KeystreamOptions {
+  /**
+   * Encryption/Decryption key
+   */
+  key: CryptoInput;
+
+  /**
+   * Initial counter block (16 bytes for AES, 8 for DES/3DES) or nonce (12
+   * bytes for ChaCha20, 24 for XChaCha20)
+   */
+  iv: CryptoInput;
+
+  /**
+   * Counter layout; defaults to the one used by `encrypt` in CTR mode:
+   * `ctr128be` for AES, `ctr64be` for DES/3DES, `ctr32le` for ChaCha20.
+   * `ctr128be` is AES only
+   */
+  counter?: CounterLayout;
+}
+
+/**
+ * Seekable CTR or ChaCha20 keystream
+ */
+export interface KeystreamInstance {
+  /**
+   * XOR the keystream into `data` (encrypts or decrypts) and advance past it
+   */
+  apply(data: CryptoInput): Buffer;
+
+  /**
+   * Jump to a byte offset, forwards or backwards
+   */
+  seek(offset: number | bigint): KeystreamInstance;
+
+  /**
+   * Current byte offset
+   */
+  readonly position: number;
+
+  /**
+   * Zeroize the key in wasm memory; the instance cannot be used afterwards
+   */
+  free(): void;
+}
+
+/**
+ * Cipher interface for stream ciphers whose keystream can be entered at any
+ * byte offset, e.g. to serve HTTP range requests from an encrypted blob
+ */
+export interface KeystreamCipherFunction extends CipherFunction {
+  /**
+   * Start a keystream at byte 0; use `seek` to move to another offset
+   */
+  createKeystream(options: KeystreamOptions): KeystreamInstance;
+}
+
+/**
+ * Result of `des.checkKey`. Weak, semi-weak and degenerate keys still
+ * encrypt; rejecting them is up to the caller.
+ */
+export interface DesKeyCheck {
+  /**
+   * Every byte has odd parity (low bit set so the byte has an odd bit count)
+   */
+  oddParity: boolean;
+
+  /**
+   * Some 8-byte component is one of the four DES weak keys
+   */
+  weak: boolean;
+
+  /**
+   * Some 8-byte component is one of the twelve DES semi-weak keys
+   */
+  semiWeak: boolean;
+
+  /**
+   * Adjacent 3DES components are equal, so the key reduces to single DES
+   */
+  degenerate: boolean;
+}
+
+/**
+ * Options for ISO/IEC 9797-1 MACs over DES/3DES
+ */
+export interface DesMacOptions {
+  /**
+   * 8, 16 or 24 bytes for algorithm 1; 16 bytes (K || K') for algorithm 3
+   */
+  key: CryptoInput;
+
+  /**
+   * ISO 9797-1 MAC algorithm: 1 is CBC-MAC under the whole key (FIPS 113 /
+   * ANSI X9.9 with an 8-byte key), 3 the retail MAC of ANSI X9.19 (default 1)
+   */
+  algorithm?: 1 | 3;
+
+  /**
+   * ISO 9797-1 padding method: 1 pads with zeros, 2 with 0x80 then zeros
+   * (default 1)
+   */
+  padding?: 1 | 2;
+
+  /**
+   * MAC length in bytes, 4 to 8; the leftmost bytes are kept (default 8)
+   */
+  length?: number;
+}
+
+/**
+ * Incremental DES/3DES MAC. `digest` and `verify` finish the instance.
+ */
+export interface DesMacInstance {
+  update(data: CryptoInput): DesMacInstance;
+
+  /**
+   * The MAC, truncated to `length`
+   */
+  digest(): Buffer;
+
+  /**
+   * Compare a full or truncated (4 to 8-byte) MAC in constant time
+   */
+  verify(mac: CryptoInput): boolean;
+
+  /**
+   * Drop the key schedule in wasm memory without finishing
+   */
+  free(): void;
+}
+
+/**
+ * DES/3DES: the cipher interface, a seekable CTR keystream, ISO 9797-1 MACs
+ * and key checks.
+ * 16-byte keys select two-key 3DES (EDE2), 24-byte keys three-key 3DES (EDE3).
+ */
+export interface DesCipherFunction extends KeystreamCipherFunction {
+  /**
+   * ISO/IEC 9797-1 MAC algorithm 1 or 3 of `data`
+   */
+  mac(data: CryptoInput, options: DesMacOptions): Buffer;
+
+  /**
+   * Check a full or truncated (4 to 8-byte) MAC in constant time
+   */
+  verifyMac(data: CryptoInput, mac: CryptoInput, options: DesMacOptions): boolean;
+
+  /**
+   * Start an incremental MAC for messages that arrive in pieces
+   */
+  createMac(options: DesMacOptions): DesMacInstance;
+
+  /**
+   * Check an 8, 16 or 24-byte key for parity and weak, semi-weak or
+   * degenerate components; parity bits are ignored when matching
+   */
+  checkKey(key: CryptoInput): DesKeyCheck;
+
+  /**
+   * Return a copy of the key with each byte's low bit adjusted for odd parity
+   */
+  setOddParity(key: CryptoInput): Buffer;
+}
+
+/**
+ * ChaCha20: the AEAD interface plus a seekable raw ChaCha20/XChaCha20
+ * keystream (a 24-byte `iv` selects XChaCha20)
+ */
+export interface ChaCha20CipherFunction extends AeadCipherFunction, KeystreamCipherFunction {}
+
+/**
+ * NaCl/libsodium crypto_secretbox (XSalsa20-Poly1305)
+ */
+export interface SecretboxFunction {
+  /**
+   * Encrypt and authenticate; returns the 16-byte tag followed by the
+   * ciphertext (crypto_secretbox_easy)
+   */
+  seal(data: CryptoInput, key: CryptoInput, nonce: CryptoInput): Buffer;
+
+  /**
+   * Verify and decrypt a sealed box (crypto_secretbox_open_easy)
+   */
+  open(boxed: CryptoInput, key: CryptoInput, nonce: CryptoInput): Buffer;
+}
+
+/**
+ * Options for AES key wrapping
+ */
+export interface KeyWrapOptions {
+  /**
+   * Use AES-KWP (RFC 5649), which wraps keys of any length, instead of
+   * AES-KW (RFC 3394), which needs a multiple of 8 bytes (default false)
+   */
+  padding?: boolean;
+}
+
+/**
+ * Options for AES-XTS sector encryption
+ */
+export interface XtsSectorOptions {
+  /**
+   * Two concatenated AES keys with different halves: 32 bytes for
+   * AES-128-XTS, 64 bytes for AES-256-XTS
+   */
+  key: CryptoInput;
+
+  /**
+   * Bytes per sector (at least 16); a shorter final sector of at least 16
+   * bytes is handled with ciphertext stealing
+   */
+  sectorSize: number;
+
+  /**
+   * Number of the first sector, used as the little-endian tweak (default 0)
+   */
+  firstSector?: number | bigint;
+}
+
+/**
+ * AES: the AEAD interface plus RFC 3394 / RFC 5649 key wrapping, IEEE 1619
+ * XTS sector encryption and a seekable AES-CTR keystream
+ */
+export interface AesCipherFunction extends AeadCipherFunction, KeystreamCipherFunction {
+  /**
+   * Wrap a key under a 16, 24 or 32-byte KEK; the result is 8 bytes longer
+   */
+  wrapKey(keyData: CryptoInput, kek: CryptoInput, options?: KeyWrapOptions): Buffer;
+
+  /**
+   * Unwrap a key; throws if the integrity check fails (wrong KEK or tampering)
+   */
+  unwrapKey(wrapped: CryptoInput, kek: CryptoInput, options?: KeyWrapOptions): Buffer;
+
+  /**
+   * Encrypt consecutive sectors with AES-XTS; output length equals input length
+   */
+  encryptSectors(data: CryptoInput, options: XtsSectorOptions): Buffer;
+
+  /**
+   * Decrypt sectors produced by `encryptSectors`. XTS is unauthenticated
+   */
+  decryptSectors(data: CryptoInput, options: XtsSectorOptions): Buffer;
+}
+
+/**
+ * HPKE (RFC 9180) cipher suite. Each KEM uses its own HKDF internally;
+ * `kdf` is the one used by the key schedule
+ */
+export interface HpkeSuiteOptions {
+  /**
+   * DHKEM group: X25519 or P-256 with HKDF-SHA256, P-384 with HKDF-SHA384
+   * (default 'x25519')
+   */
+  kem?: 'x25519' | 'p256' | 'p384';
+
+  /**
+   * Key schedule KDF (default 'sha256')
+   */
+  kdf?: 'sha256' | 'sha384' | 'sha512';
+
+  /**
+   * AEAD; 'export-only' contexts can only `export` (default 'aes-128-gcm')
+   */
+  aead?: 'aes-128-gcm' | 'aes-256-gcm' | 'chacha20-poly1305' | 'export-only';
+}
+
+/**
+ * HPKE key schedule inputs; sender and recipient must pass the same ones
+ */
+export interface HpkeSetupOptions extends HpkeSuiteOptions {
+  /**
+   * Application context bound into every key
+   */
+  info?: CryptoInput;
+
+  /**
+   * Pre-shared key, given together with `pskId`; selects the PSK modes
+   */
+  psk?: CryptoInput;
+
+  pskId?: CryptoInput;
+
+  /**
+   * Selects the auth modes: the sender's private key when sealing, its
+   * public key when opening
+   */
+  authKey?: CryptoInput;
+}
+
+/**
+ * Options for single-shot HPKE `seal` and `open`
+ */
+export interface HpkeSealOptions extends HpkeSetupOptions {
+  /**
+   * Associated data, authenticated but not encrypted
+   */
+  aad?: CryptoInput;
+}
+
+/**
+ * Output of single-shot HPKE `seal`
+ */
+export interface HpkeSealed {
+  /**
+   * Encapsulated key; the recipient needs it to open the ciphertext
+   */
+  enc: Buffer;
+
+  ciphertext: Buffer;
+}
+
+/**
+ * Sender's HPKE context: messages are sealed under consecutive nonces
+ */
+export interface HpkeSenderContext {
+  /**
+   * Encapsulated key for the recipient's `setupRecipient`
+   */
+  readonly enc: Buffer;
+
+  /**
+   * Encrypt the next message; the recipient must open them in order
+   */
+  seal(data: CryptoInput, aad?: CryptoInput): Buffer;
+
+  /**
+   * Derive `length` bytes of secret bound to `exporterContext`
+   */
+  export(exporterContext: CryptoInput, length: number): Buffer;
+
+  /**
+   * Zeroize the context in wasm memory
+   */
+  free(): void;
+}
+
+/**
+ * Recipient's HPKE context
+ */
+export interface HpkeRecipientContext {
+  /**
+   * Decrypt the next message; a failure does not advance the sequence
+   */
+  open(data: CryptoInput, aad?: CryptoInput): Buffer;
+
+  /**
+   * Derive `length` bytes of secret bound to `exporterContext`
+   */
+  export(exporterContext: CryptoInput, length: number): Buffer;
+
+  /**
+   * Zeroize the context in wasm memory
+   */
+  free(): void;
+}
+
+/**
+ * Hybrid Public Key Encryption (RFC 9180) in the base, PSK, auth and
+ * auth-PSK modes
+ */
+export interface HpkeFunction {
+  /**
+   * Random key pair for the suite's KEM; public keys are raw X25519 keys or
+   * uncompressed points
+   */
+  generateKeypair(options?: HpkeSuiteOptions): { privateKey: Buffer; publicKey: Buffer };
+
+  /**
+   * DeriveKeyPair: the key pair determined by `ikm` (at least as long as
+   * a private key)
+   */
+  deriveKeypair(ikm: CryptoInput, options?: HpkeSuiteOptions): { privateKey: Buffer; publicKey: Buffer };
+
+  /**
+   * Encrypt one message to `recipientPublicKey`
+   */
+  seal(data: CryptoInput, recipientPublicKey: CryptoInput, options?: HpkeSealOptions): HpkeSealed;
+
+  /**
+   * Decrypt a message from `seal`
+   */
+  open(
+    ciphertext: CryptoInput,
+    enc: CryptoInput,
+    recipientPrivateKey: CryptoInput,
+    options?: HpkeSealOptions
+  ): Buffer;
+
+  /**
+   * Start a sender context for several messages or exported secrets
+   */
+  setupSender(recipientPublicKey: CryptoInput, options?: HpkeSetupOptions): HpkeSenderContext;
+
+  /**
+   * Start the matching recipient context from the sender's `enc`
+   */
+  setupRecipient(
+    enc: CryptoInput,
+    recipientPrivateKey: CryptoInput,
+    options?: HpkeSetupOptions
+  ): HpkeRecipientContext;
+}
+
+/**
+ * ECIES wire format, matching eciesjs' config. The recipient detects a
+ * compressed ephemeral key by itself; everything else must match
+ */
+export interface EciesOptions {
+  /**
+   * Curve of the recipient's key (default 'secp256k1')
+   */
+  curve?: 'secp256k1' | 'p256';
+
+  /**
+   * Symmetric cipher (default 'aes-256-gcm')
+   */
+  aead?: 'aes-256-gcm' | 'xchacha20-poly1305';
+
+  /**
+   * AES-GCM nonce length, eciesjs `symmetricNonceLength` (default 16)
+   */
+  nonceLength?: 12 | 16;
+
+  /**
+   * Send a 33-byte compressed ephemeral key (default false)
+   */
+  compressedEphemeralKey?: boolean;
+
+  /**
+   * Derive the key from compressed points, eciesjs `isHkdfKeyCompressed`
+   * (default false)
+   */
+  compressedHkdfKey?: boolean;
+}
+
+/**
+ * ECIES encryption to secp256k1 and P-256 public keys: ephemeral ECDH,
+ * HKDF-SHA256 and AES-256-GCM or XChaCha20-Poly1305
+ */
+export interface EciesFunction {
+  /**
+   * Random key pair; the public key is an uncompressed point (65 bytes)
+   */
+  generateKeypair(options?: EciesOptions): { privateKey: Buffer; publicKey: Buffer };
+
+  /**
+   * Encrypt to a compressed, uncompressed or raw 64-byte public key. The
+   * output is ephemeral key || nonce || tag || ciphertext
+   */
+  encrypt(data: CryptoInput, receiverPublicKey: CryptoInput, options?: EciesOptions): Buffer;
+
+  /**
+   * Decrypt an `encrypt` (or eciesjs) ciphertext
+   */
+  decrypt(ciphertext: CryptoInput, receiverPrivateKey: CryptoInput, options?: EciesOptions): Buffer;
+}
+
 // DSA types
 export type EcdsaCurve = 'p256' | 'secp256k1';
 export type RsaHash = 'sha256' | 'sha384' | 'sha512';
 
+// Payments types
+
+/**
+ * ISO 9564-1 PIN block format. Formats 0, 1 and 3 are 8-byte DES/3DES
+ * blocks; format 4 is a 16-byte AES block that only exists enciphered
+ */
+export type PinBlockFormat = 0 | 1 | 3 | 4;
+
+/**
+ * ISO 9564-1 PIN blocks. PINs are 4 to 12 digits and PANs up to 19 digits,
+ * check digit included; format 1 ignores the PAN
+ */
+export interface PinBlockFunction {
+  /**
+   * Clear PIN block in format 0 (default), 1 or 3
+   */
+  encode(pin: string, pan: string, format?: PinBlockFormat): Buffer;
+
+  /**
+   * Recover the PIN from a clear PIN block, checking its layout
+   */
+  decode(block: CryptoInput, pan: string, format?: PinBlockFormat): string;
+
+  /**
+   * Enciphered PIN block: DES/3DES ECB for formats 0, 1 and 3, AES for format 4
+   */
+  encrypt(pin: string, pan: string, key: CryptoInput, format?: PinBlockFormat): Buffer;
+
+  /**
+   * Decipher a PIN block and recover the PIN
+   */
+  decrypt(block: CryptoInput, pan: string, key: CryptoInput, format?: PinBlockFormat): string;
+}
+
+/**
+ * Options for key check values
+ */
+export interface KcvOptions {
+  /**
+   * Cipher of the key: 'des' covers single DES and 3DES keys
+   */
+  algorithm: 'des' | 'aes';
+
+  /**
+   * 'zero-block' (default): leftmost 3 bytes of an encrypted zero block.
+   * 'cmac': leftmost 5 bytes of the CMAC of a zero block (X9.24-1:2017)
+   */
+  method?: 'zero-block' | 'cmac';
+}
+
+/**
+ * Header of an ANSI X9.143 (TR-31) key block. The length field is computed
+ * when wrapping and `PB` padding blocks are handled internally
+ */
+export interface Tr31Header {
+  /**
+   * 'B' (3DES KBPK) or 'D' (AES KBPK)
+   */
+  version: 'B' | 'D';
+
+  /**
+   * Two characters, e.g. 'P0' (PIN encryption) or 'K0' (key encryption)
+   */
+  keyUsage: string;
+
+  /**
+   * Algorithm of the wrapped key: 'T' (3DES), 'A' (AES), ...
+   */
+  algorithm: string;
+
+  /**
+   * 'E' encrypt only, 'D' decrypt only, 'B' both, 'N' no restrictions, ...
+   */
+  modeOfUse: string;
+
+  /**
+   * Two characters (default '00')
+   */
+  keyVersion?: string;
+
+  /**
+   * 'E' exportable, 'N' not exportable, 'S' sensitive (default 'E')
+   */
+  exportability?: string;
+
+  /**
+   * Optional blocks in order, e.g. `[{ id: 'KS', data: '00604B120F9292800000' }]`
+   */
+  optionalBlocks?: { id: string; data: string }[];
+}
+
+/**
+ * ANSI X9.143 (TR-31) key blocks, versions B and D
+ */
+export interface Tr31Function {
+  /**
+   * Wrap a key under a key block protection key (16 or 24 bytes for
+   * version B, 16, 24 or 32 for version D). Returns the printable key block
+   */
+  wrap(kbpk: CryptoInput, header: Tr31Header | string, key: CryptoInput): string;
+
+  /**
+   * Verify the MAC and unwrap a key block
+   */
+  unwrap(kbpk: CryptoInput, keyBlock: string): { header: Required<Tr31Header>; key: Buffer };
+}
+
 /**
  * Result of a benchmark run
  */
@@ -1,6 +1,6 @@
 /* eslint-disable no-console */
 const path = require('path');
-const { generateKeyPairSync, randomBytes: nodeRandomBytes } = require('crypto');
+const { createCipheriv, createHash, createHmac, generateKeyPairSync, randomBytes: nodeRandomBytes } = require('crypto');
 
 function hr() {
   console.log('\n' + '='.repeat(80) + '\n');
@@ -14,6 +14,15 @@ function assert(cond, msg) {
   if (!cond) throw new Error('Assertion failed: ' + msg);
 }
 
+function assertThrows(fn, msg) {
+  try {
+    fn();
+  } catch (_e) {
+    return;
+  }
+  throw new Error('Assertion failed (expected throw): ' + msg);
+}
+
 function hex(buf, len = 32) {
   return Buffer.from(buf).toString('hex').slice(0, len);
 }
@@ -50,6 +59,29 @@ function hex(buf, len = 32) {
   const streamHash = h.digest('hex');
   assert(streamHash === hashes.sha256, 'streaming sha256 matches');
 
+  // Inputs that are views into a larger ArrayBuffer must hash their own bytes,
+  // not whatever sits at `byteOffset` in wasm memory.
+  section('Buffer offsets');
+  const backing = Buffer.from('xxxxxxxxHello Worldyyyyyyyy');
+  const views = {
+    'Buffer.subarray': backing.subarray(8, 19),
+    'Uint8Array.subarray': new Uint8Array(backing.buffer, backing.byteOffset, backing.length).subarray(8, 19),
+  };
+  for (const [name, view] of Object.entries(views)) {
+    assert(view.byteOffset !== 0, `${name} has a non-zero byteOffset`);
+    assert(lib.sha.sha256(view) === createHash('sha256').update(view).digest('hex'), `sha256 of ${name}`);
+    assert(lib.sha.md5(view) === createHash('md5').update(view).digest('hex'), `md5 of ${name}`);
+    assert(lib.sha.sha3_256(view) === createHash('sha3-256').update(view).digest('hex'), `sha3_256 of ${name}`);
+    const hv = lib.sha.sha256.create();
+    hv.update(view.subarray(0, 5)).update(view.subarray(5));
+    assert(hv.digest('hex') === hashes.sha256, `streaming sha256 of ${name}`);
+    assert(
+      lib.hmac.sha256(view, { key: 'secret' }) === createHmac('sha256', 'secret').update(view).digest('hex'),
+      `hmac sha256 of ${name}`
+    );
+  }
+  console.table(Object.entries(views).map(([k, v]) => ({ view: k, byteOffset: v.byteOffset, len: v.length })));
+
   // HMAC
   section('HMAC');
   const key = 'secret';
@@ -104,30 +136,300 @@ function hex(buf, len = 32) {
   const encSiv = lib.cipher.aes.encrypt(aesMsg, { key: keySiv128, iv: n16, mode: 'siv' });
   const decSiv = lib.cipher.aes.decrypt(encSiv, { key: keySiv128, iv: n16, mode: 'siv' });
   assert(decSiv.equals(aesMsg), 'AES-128-SIV roundtrip');
-  // CBC alias (maps to GCM internally)
+  // AAD: GCM against Node's OpenSSL, SIV with an RFC 5297 header vector
+  const aad = Buffer.from('header v1');
+  const encAad = lib.cipher.aes.encrypt(aesMsg, { key: key256, iv: n12, mode: 'gcm', aad });
+  const nodeGcm = createCipheriv('aes-256-gcm', key256, n12);
+  nodeGcm.setAAD(aad);
+  const nodeAad = Buffer.concat([nodeGcm.update(aesMsg), nodeGcm.final(), nodeGcm.getAuthTag()]);
+  assert(encAad.equals(nodeAad), 'AES-256-GCM with AAD matches Node crypto');
+  assert(
+    lib.cipher.aes.decrypt(encAad, { key: key256, iv: n12, mode: 'gcm', aad }).equals(aesMsg),
+    'AES-256-GCM with AAD roundtrip'
+  );
+  assertThrows(
+    () => lib.cipher.aes.decrypt(encAad, { key: key256, iv: n12, mode: 'gcm' }),
+    'AES-GCM rejects missing AAD'
+  );
+  const sivHeaders = [Buffer.from('a'), Buffer.from('b')];
+  const encSivAad = lib.cipher.aes.encrypt(aesMsg, { key: keySiv128, iv: n16, mode: 'siv', aad: sivHeaders });
+  assert(
+    lib.cipher.aes.decrypt(encSivAad, { key: keySiv128, iv: n16, mode: 'siv', aad: sivHeaders }).equals(aesMsg),
+    'AES-SIV header vector roundtrip'
+  );
+  assertThrows(
+    () => lib.cipher.aes.decrypt(encSivAad, { key: keySiv128, iv: n16, mode: 'siv', aad: sivHeaders.slice().reverse() }),
+    'AES-SIV rejects reordered headers'
+  );
+  // GCM-SIV against RFC 8452 appendix C.1
+  const gcmSivKey = Buffer.from('ee8e1ed9ff2540ae8f2ba9f50bc2f27c', 'hex');
+  const gcmSivNonce = Buffer.from('752abad3e0afb5f434dc4310', 'hex');
+  const encGcmSiv = lib.cipher.aes.encrypt('Hello world', { key: gcmSivKey, iv: gcmSivNonce, mode: 'gcm-siv', aad: 'example' });
+  assert(
+    encGcmSiv.toString('hex') === '5d349ead175ef6b1def6fd4fbcdeb7e4793f4a1d7e4faa70100af1',
+    'AES-128-GCM-SIV matches RFC 8452'
+  );
+  assert(
+    lib.cipher.aes.decrypt(encGcmSiv, { key: gcmSivKey, iv: gcmSivNonce, mode: 'gcm-siv', aad: 'example' }).toString() === 'Hello world',
+    'AES-128-GCM-SIV roundtrip'
+  );
+  // Detached tags: GCM (full and truncated) against Node, CCM with a short nonce and tag
+  for (const tagLength of [16, 12]) {
+    const det = lib.cipher.aes.encryptDetached(aesMsg, { key: key256, iv: n12, mode: 'gcm', aad, tagLength });
+    const nodeDet = createCipheriv('aes-256-gcm', key256, n12, { authTagLength: tagLength });
+    nodeDet.setAAD(aad);
+    const nodeDetCt = Buffer.concat([nodeDet.update(aesMsg), nodeDet.final()]);
+    assert(
+      det.ciphertext.equals(nodeDetCt) && det.tag.equals(nodeDet.getAuthTag()),
+      `AES-256-GCM detached ${tagLength}-byte tag matches Node crypto`
+    );
+    assert(
+      lib.cipher.aes.decryptDetached(det.ciphertext, det.tag, { key: key256, iv: n12, mode: 'gcm', aad }).equals(aesMsg),
+      `AES-256-GCM detached ${tagLength}-byte tag roundtrip`
+    );
+  }
+  const n8 = nodeRandomBytes(8);
+  const detCcm = lib.cipher.aes.encryptDetached(aesMsg, { key: key128, iv: n8, mode: 'ccm', tagLength: 8 });
+  const nodeCcm = createCipheriv('aes-128-ccm', key128, n8, { authTagLength: 8 });
+  nodeCcm.setAAD(Buffer.alloc(0), { plaintextLength: aesMsg.length });
+  const nodeCcmCt = Buffer.concat([nodeCcm.update(aesMsg), nodeCcm.final()]);
+  assert(
+    detCcm.ciphertext.equals(nodeCcmCt) && detCcm.tag.equals(nodeCcm.getAuthTag()),
+    'AES-128-CCM detached 8-byte tag, 8-byte nonce matches Node crypto'
+  );
+  assertThrows(
+    () => lib.cipher.aes.decryptDetached(detCcm.ciphertext, Buffer.alloc(8), { key: key128, iv: n8, mode: 'ccm' }),
+    'AES-CCM detached rejects a wrong tag'
+  );
+  assertThrows(
+    () => lib.cipher.aes.encryptDetached(aesMsg, { key: key256, iv: n12, mode: 'gcm', tagLength: 8 }),
+    'AES-GCM rejects tags shorter than 12 bytes'
+  );
+  // OCB3 (RFC 7253) against OpenSSL, combined and detached with short nonces and tags
+  const encOcb = lib.cipher.aes.encrypt(aesMsg, { key: key256, iv: n12, mode: 'ocb3', aad });
+  const nodeOcb = createCipheriv('aes-256-ocb', key256, n12, { authTagLength: 16 });
+  nodeOcb.setAAD(aad);
+  const nodeOcbCt = Buffer.concat([nodeOcb.update(aesMsg), nodeOcb.final(), nodeOcb.getAuthTag()]);
+  assert(encOcb.equals(nodeOcbCt), 'AES-256-OCB3 matches Node crypto');
+  assert(
+    lib.cipher.aes.decrypt(encOcb, { key: key256, iv: n12, mode: 'ocb3', aad }).equals(aesMsg),
+    'AES-256-OCB3 roundtrip'
+  );
+  for (const [nonceLength, tagLength] of [[6, 8], [15, 12]]) {
+    const nonce = nodeRandomBytes(nonceLength);
+    const det = lib.cipher.aes.encryptDetached(aesMsg, { key: key128, iv: nonce, mode: 'ocb3', aad, tagLength });
+    const node = createCipheriv('aes-128-ocb', key128, nonce, { authTagLength: tagLength });
+    node.setAAD(aad);
+    const nodeCt = Buffer.concat([node.update(aesMsg), node.final()]);
+    assert(
+      det.ciphertext.equals(nodeCt) && det.tag.equals(node.getAuthTag()),
+      `AES-128-OCB3 ${nonceLength}-byte nonce, ${tagLength}-byte tag matches Node crypto`
+    );
+  }
+  // EAX: Node has no EAX, so check the roundtrip, tag truncation and tampering
+  const eaxNonce = nodeRandomBytes(16);
+  const encEax = lib.cipher.aes.encrypt(aesMsg, { key: key192, iv: eaxNonce, mode: 'eax', aad });
+  assert(
+    lib.cipher.aes.decrypt(encEax, { key: key192, iv: eaxNonce, mode: 'eax', aad }).equals(aesMsg),
+    'AES-192-EAX roundtrip'
+  );
+  const detEax = lib.cipher.aes.encryptDetached(aesMsg, { key: key192, iv: eaxNonce, mode: 'eax', aad, tagLength: 8 });
+  assert(
+    Buffer.concat([detEax.ciphertext, detEax.tag]).equals(encEax.subarray(0, aesMsg.length + 8)),
+    'AES-EAX short tags are truncations of the full tag'
+  );
+  const badEax = Buffer.from(encEax);
+  badEax[badEax.length - 1] ^= 1;
+  assertThrows(
+    () => lib.cipher.aes.decrypt(badEax, { key: key192, iv: eaxNonce, mode: 'eax', aad }),
+    'AES-EAX rejects a modified tag'
+  );
+  // STREAM: segment boundaries are independent of update() chunking
+  const streamMsg = nodeRandomBytes(1000);
+  const n7 = nodeRandomBytes(7);
+  const sealStream = (cipher, key, input, step) => {
+    const s = cipher.createStreamingEncryptor({ key, iv: n7, segmentSize: 64 });
+    const parts = [];
+    for (let i = 0; i < input.length; i += step) parts.push(s.update(input.subarray(i, i + step)));
+    parts.push(s.final());
+    return Buffer.concat(parts);
+  };
+  const openStream = (cipher, key, input) => {
+    const s = cipher.createStreamingDecryptor({ key, iv: n7, segmentSize: 64 });
+    return Buffer.concat([s.update(input), s.final()]);
+  };
+  const streamCt = sealStream(lib.cipher.aes, key256, streamMsg, 100);
+  assert(streamCt.equals(sealStream(lib.cipher.aes, key256, streamMsg, 7)), 'AES-GCM STREAM is chunking-independent');
+  assert(streamCt.length === streamMsg.length + 16 * 16, 'AES-GCM STREAM adds one tag per segment');
+  assert(openStream(lib.cipher.aes, key256, streamCt).equals(streamMsg), 'AES-GCM STREAM roundtrip');
+  assertThrows(
+    () => openStream(lib.cipher.aes, key256, streamCt.subarray(0, 80 * 15)),
+    'AES-GCM STREAM detects truncation'
+  );
+  assertThrows(
+    () => openStream(lib.cipher.aes, key256, Buffer.concat([streamCt.subarray(80, 160), streamCt.subarray(0, 80), streamCt.subarray(160)])),
+    'AES-GCM STREAM detects reordering'
+  );
+  // Keyed ciphers: same output as the one-shot API, reusable until free()
+  const gcmKeyed = lib.cipher.aes.createKeyedCipher({ key: key256 });
+  for (let i = 0; i < 3; i++) {
+    const nonce = nodeRandomBytes(12);
+    const ct = gcmKeyed.encrypt(aesMsg, { iv: nonce, aad });
+    assert(ct.equals(lib.cipher.aes.encrypt(aesMsg, { key: key256, iv: nonce, aad })), `AES-GCM keyed cipher matches one-shot (${i})`);
+    assert(gcmKeyed.decrypt(ct, { iv: nonce, aad }).equals(aesMsg), `AES-GCM keyed cipher roundtrip (${i})`);
+  }
+  gcmKeyed.free();
+  assertThrows(() => gcmKeyed.encrypt(aesMsg, { iv: n12 }), 'AES-GCM keyed cipher is unusable after free()');
+  const sivKeyed = lib.cipher.aes.createKeyedCipher({ key: keySiv128, mode: 'siv' });
+  assert(sivKeyed.encrypt(aesMsg, { iv: n16 }).equals(encSiv), 'AES-SIV keyed cipher matches one-shot');
+  assert(
+    sivKeyed.encrypt(aesMsg, { iv: n16, aad: sivHeaders }).equals(encSivAad),
+    'AES-SIV keyed cipher matches one-shot with a header vector'
+  );
+  assert(sivKeyed.decrypt(encSivAad, { iv: n16, aad: sivHeaders }).equals(aesMsg), 'AES-SIV keyed cipher roundtrip');
+  sivKeyed.free();
+  assertThrows(() => lib.cipher.aes.createKeyedCipher({ key: key128, mode: 'siv' }), 'AES-SIV keyed cipher rejects a 16-byte key');
+  // Seekable keystreams: any range decrypts on its own, matching OpenSSL CTR
+  const ctrMsg = nodeRandomBytes(1000);
+  const nodeCtr = createCipheriv('aes-256-ctr', key256, n16);
+  const ctrCt = Buffer.concat([nodeCtr.update(ctrMsg), nodeCtr.final()]);
+  const aesKs = lib.cipher.aes.createKeystream({ key: key256, iv: n16 });
+  assert(
+    Buffer.concat([aesKs.apply(ctrMsg.subarray(0, 100)), aesKs.apply(ctrMsg.subarray(100))]).equals(ctrCt),
+    'AES-CTR keystream matches Node across apply() calls'
+  );
+  assert(aesKs.position === 1000, 'AES-CTR keystream position advances');
+  assert(aesKs.seek(517).apply(ctrCt.subarray(517, 700)).equals(ctrMsg.subarray(517, 700)), 'AES-CTR keystream seeks forwards');
+  assert(aesKs.seek(3n).apply(ctrCt.subarray(3, 40)).equals(ctrMsg.subarray(3, 40)), 'AES-CTR keystream seeks backwards');
+  aesKs.free();
+  // ctr32be wraps within the last 4 bytes; OpenSSL's counter would carry
+  const ivWrap = Buffer.concat([nodeRandomBytes(12), Buffer.alloc(4, 0xff)]);
+  const wrapBlocks = Buffer.concat([ivWrap, ivWrap.subarray(0, 12), Buffer.alloc(4)]);
+  const nodeEcbKs = createCipheriv('aes-128-ecb', key128, null).setAutoPadding(false);
+  assert(
+    lib.cipher.aes.createKeystream({ key: key128, iv: ivWrap, counter: 'ctr32be' }).apply(Buffer.alloc(32)).equals(nodeEcbKs.update(wrapBlocks)),
+    'AES-CTR ctr32be counter wraps mod 2^32'
+  );
+  assertThrows(() => lib.cipher.aes.createKeystream({ key: key128, iv: n12 }), 'AES-CTR keystream rejects a 12-byte IV');
+  assertThrows(() => aesKs.seek(-1), 'AES-CTR keystream rejects a negative offset');
+  // CBC / ECB / CFB / OFB must match OpenSSL byte for byte
   const ivCBC = nodeRandomBytes(16);
   const encCBC = lib.cipher.aes.encrypt(aesMsg, { key: key256, iv: ivCBC, mode: 'cbc' });
+  const nodeCbc = createCipheriv('aes-256-cbc', key256, ivCBC);
+  assert(encCBC.equals(Buffer.concat([nodeCbc.update(aesMsg), nodeCbc.final()])), 'AES-CBC matches Node (PKCS#7)');
   const decCBC = lib.cipher.aes.decrypt(encCBC, { key: key256, iv: ivCBC, mode: 'cbc' });
-  assert(decCBC.equals(aesMsg), 'AES-CBC alias roundtrip');
-  // ECB alias (emulated via CTR zero-IV)
+  assert(decCBC.equals(aesMsg), 'AES-CBC roundtrip');
   const encECB = lib.cipher.aes.encrypt(aesMsg, { key: key128, mode: 'ecb' });
+  const nodeEcb = createCipheriv('aes-128-ecb', key128, null);
+  assert(encECB.equals(Buffer.concat([nodeEcb.update(aesMsg), nodeEcb.final()])), 'AES-ECB matches Node');
   const decECB = lib.cipher.aes.decrypt(encECB, { key: key128, mode: 'ecb' });
-  assert(decECB.equals(aesMsg), 'AES-ECB alias roundtrip');
+  assert(decECB.equals(aesMsg), 'AES-ECB roundtrip');
+  for (const [mode, nodeMode] of [['cfb', 'cfb'], ['cfb8', 'cfb8'], ['ofb', 'ofb']]) {
+    const enc = lib.cipher.aes.encrypt(aesMsg, { key: key128, iv: ivCBC, mode });
+    const node = createCipheriv(`aes-128-${nodeMode}`, key128, ivCBC);
+    assert(enc.equals(Buffer.concat([node.update(aesMsg), node.final()])), `AES-${mode.toUpperCase()} matches Node`);
+    assert(lib.cipher.aes.decrypt(enc, { key: key128, iv: ivCBC, mode }).equals(aesMsg), `AES-${mode.toUpperCase()} roundtrip`);
+  }
+  for (const padding of ['PKCS7', 'ISO7816', 'ANSIX923', 'ISO10126', 'ZeroPadding']) {
+    const enc = lib.cipher.aes.encrypt('legacy', { key: key128, iv: ivCBC, mode: 'cbc', padding });
+    assert(enc.length === 16, `AES-CBC ${padding} pads to one block`);
+    const dec = lib.cipher.aes.decrypt(enc, { key: key128, iv: ivCBC, mode: 'cbc', padding });
+    assert(dec.toString() === 'legacy', `AES-CBC ${padding} roundtrip`);
+  }
+  const block = Buffer.alloc(32, 7);
+  const encNoPad = lib.cipher.aes.encrypt(block, { key: key128, iv: ivCBC, mode: 'cbc', padding: 'NoPadding' });
+  assert(encNoPad.length === 32, 'AES-CBC NoPadding adds nothing');
+  assertThrows(
+    () => lib.cipher.aes.encrypt('odd', { key: key128, iv: ivCBC, mode: 'cbc', padding: 'NoPadding' }),
+    'AES-CBC NoPadding rejects unaligned input'
+  );
+  // Every bad-padding variant must surface as the same error
+  const padErrors = [0x05, 0x1b, 0x01].map((flip) => {
+    const badIv = Buffer.from(ivCBC);
+    badIv[15] ^= flip;
+    try {
+      lib.cipher.aes.decrypt(encCBC, { key: key256, iv: badIv, mode: 'cbc' });
+      return null;
+    } catch (e) {
+      return String(e && e.message ? e.message : e);
+    }
+  });
+  padErrors.push((() => {
+    try {
+      lib.cipher.aes.decrypt(encCBC.subarray(1), { key: key256, iv: ivCBC, mode: 'cbc' });
+      return null;
+    } catch (e) {
+      return String(e && e.message ? e.message : e);
+    }
+  })());
+  assert(padErrors.every((m) => m !== null && m === padErrors[0]), 'AES-CBC padding errors are indistinguishable');
+  assertThrows(
+    () => lib.cipher.aes.encrypt(aesMsg, { key: key128, iv: ivCBC, mode: 'ofb', padding: 'PKCS7' }),
+    'AES-OFB rejects padding'
+  );
+  // AES-KW (RFC 3394) and AES-KWP (RFC 5649) against OpenSSL
+  const dek = nodeRandomBytes(32);
+  const kw = lib.cipher.aes.wrapKey(dek, key256);
+  const nodeKw = createCipheriv('id-aes256-wrap', key256, Buffer.from('A6A6A6A6A6A6A6A6', 'hex'));
+  assert(kw.equals(Buffer.concat([nodeKw.update(dek), nodeKw.final()])), 'AES-KW matches Node');
+  assert(lib.cipher.aes.unwrapKey(kw, key256).equals(dek), 'AES-KW unwrap');
+  const oddKey = nodeRandomBytes(21);
+  const kwp = lib.cipher.aes.wrapKey(oddKey, key128, { padding: true });
+  const nodeKwp = createCipheriv('id-aes128-wrap-pad', key128, Buffer.from('A65959A6', 'hex'));
+  assert(kwp.equals(Buffer.concat([nodeKwp.update(oddKey), nodeKwp.final()])), 'AES-KWP matches Node');
+  assert(lib.cipher.aes.unwrapKey(kwp, key128, { padding: true }).equals(oddKey), 'AES-KWP unwrap');
+  const badKw = Buffer.from(kw);
+  badKw[0] ^= 1;
+  assertThrows(() => lib.cipher.aes.unwrapKey(badKw, key256), 'AES-KW detects tampering');
+  assertThrows(() => lib.cipher.aes.wrapKey(oddKey, key128), 'AES-KW rejects unaligned key data');
+  // AES-XTS against OpenSSL: a single data unit, then 512-byte sectors with a stolen tail
+  const xtsKey = nodeRandomBytes(64);
+  const xtsTweak = nodeRandomBytes(16);
+  const xtsData = nodeRandomBytes(37);
+  const encXts = lib.cipher.aes.encrypt(xtsData, { key: xtsKey, iv: xtsTweak, mode: 'xts' });
+  const nodeXts = createCipheriv('aes-256-xts', xtsKey, xtsTweak);
+  assert(encXts.equals(Buffer.concat([nodeXts.update(xtsData), nodeXts.final()])), 'AES-256-XTS matches Node');
+  assert(lib.cipher.aes.decrypt(encXts, { key: xtsKey, iv: xtsTweak, mode: 'xts' }).equals(xtsData), 'AES-XTS decrypt');
+  const volume = nodeRandomBytes(512 * 2 + 100);
+  const xtsKey128 = xtsKey.subarray(0, 32);
+  const sectors = lib.cipher.aes.encryptSectors(volume, { key: xtsKey128, sectorSize: 512, firstSector: 7 });
+  for (let i = 0; i < 3; i++) {
+    const tweak = Buffer.alloc(16);
+    tweak.writeBigUInt64LE(BigInt(7 + i));
+    const node = createCipheriv('aes-128-xts', xtsKey128, tweak);
+    const chunk = volume.subarray(i * 512, (i + 1) * 512);
+    const expected = Buffer.concat([node.update(chunk), node.final()]);
+    assert(sectors.subarray(i * 512, (i + 1) * 512).equals(expected), `AES-XTS sector ${7 + i} matches Node`);
+  }
+  assert(
+    lib.cipher.aes.decryptSectors(sectors, { key: xtsKey128, sectorSize: 512, firstSector: 7n }).equals(volume),
+    'AES-XTS sector round trip'
+  );
+  const sameHalves = Buffer.concat([key128, key128]);
+  assertThrows(
+    () => lib.cipher.aes.encrypt(xtsData, { key: sameHalves, iv: xtsTweak, mode: 'xts' }),
+    'AES-XTS rejects identical key halves'
+  );
+  assertThrows(
+    () => lib.cipher.aes.encrypt(xtsData.subarray(0, 15), { key: xtsKey, iv: xtsTweak, mode: 'xts' }),
+    'AES-XTS rejects data units shorter than a block'
+  );
   console.table([
     { mode: 'gcm', n: 12, sample: hex(encG256) + '…' },
     { mode: 'ctr', n: 16, sample: hex(encC256) + '…' },
     { mode: 'ccm', n: 13, sample: hex(encCCM128) + '…' },
     { mode: 'siv', n: 16, sample: hex(encSiv) + '…' },
-    { mode: 'cbc (alias→gcm)', n: 16, sample: hex(encCBC) + '…' },
-    { mode: 'ecb (alias→ctr)', n: 0, sample: hex(encECB) + '…' },
+    { mode: 'cbc', n: 16, sample: hex(encCBC) + '…' },
+    { mode: 'ecb', n: 0, sample: hex(encECB) + '…' },
+    { mode: 'xts', n: 16, sample: hex(encXts) + '…' },
   ]);
   console.table([
     { mode: 'gcm', n: 12, sample: hex(encG256) + '…' },
     { mode: 'ctr', n: 16, sample: hex(encC256) + '…' },
     { mode: 'ccm', n: 13, sample: hex(encCCM128) + '…' },
     { mode: 'siv', n: 16, sample: hex(encSiv) + '…' },
-    { mode: 'cbc (alias→gcm)', n: 16, sample: hex(encCBC) + '…' },
-    { mode: 'ecb (alias→ctr)', n: 0, sample: hex(encECB) + '…' },
+    { mode: 'cbc', n: 16, sample: hex(encCBC) + '…' },
+    { mode: 'ecb', n: 0, sample: hex(encECB) + '…' },
   ]);
 
   // DES / 3DES
@@ -142,9 +444,55 @@ function hex(buf, len = 32) {
   const enc3Ctr = lib.cipher.des.encrypt(dMsg, { key: k3Des, iv: iv8, mode: 'ctr' });
   const dec3Ctr = lib.cipher.des.decrypt(enc3Ctr, { key: k3Des, iv: iv8, mode: 'ctr' });
   assert(dec3Ctr.equals(dMsg), '3DES-CTR roundtrip');
+  const desKs = lib.cipher.des.createKeystream({ key: k3Des, iv: iv8 });
+  assert(desKs.apply(dMsg).equals(enc3Ctr), '3DES-CTR keystream matches one-shot CTR');
+  assert(desKs.seek(5).apply(enc3Ctr.subarray(5)).equals(dMsg.subarray(5)), '3DES-CTR keystream seeks mid-block');
+  desKs.free();
+  assert(lib.cipher.des.createKeystream({ key: kDes, iv: iv8, counter: 'ctr32le' }).apply(dMsg).length === dMsg.length, 'DES-CTR ctr32le keystream');
+  assertThrows(() => lib.cipher.des.createKeystream({ key: kDes, iv: iv8, counter: 'ctr128be' }), 'DES-CTR keystream rejects ctr128be');
+  const fips81Key = Buffer.from('0123456789abcdef', 'hex');
+  const fips81Pt = Buffer.from('Now is the time for all ');
+  const encDesEcb = lib.cipher.des.encrypt(fips81Pt, { key: fips81Key, mode: 'ecb', padding: 'NoPadding' });
+  assert(hex(encDesEcb) === '3fa40e8a984d48156a271787ab8883f9893d51ec4b563b53', 'DES-ECB FIPS 81 vector');
+  assert(lib.cipher.des.decrypt(encDesEcb, { key: fips81Key, mode: 'ecb', padding: 'NoPadding' }).equals(fips81Pt), 'DES-ECB no-padding roundtrip');
+  assertThrows(() => lib.cipher.des.encrypt(dMsg, { key: kDes, mode: 'ecb', padding: 'NoPadding' }), 'DES-ECB no-padding rejects partial blocks');
+  const k2Des = nodeRandomBytes(16);
+  const k2As3 = Buffer.concat([k2Des, k2Des.subarray(0, 8)]);
+  const enc2Cbc = lib.cipher.des.encrypt(dMsg, { key: k2Des, iv: iv8, mode: 'cbc' });
+  assert(enc2Cbc.equals(lib.cipher.des.encrypt(dMsg, { key: k2As3, iv: iv8, mode: 'cbc' })), '3DES-EDE2-CBC equals EDE3 with K3 = K1');
+  assert(lib.cipher.des.decrypt(enc2Cbc, { key: k2Des, iv: iv8, mode: 'cbc' }).equals(dMsg), '3DES-EDE2-CBC roundtrip');
+  const enc2Ecb = lib.cipher.des.encrypt(dMsg, { key: k2Des, mode: 'ecb', padding: 'ISO7816' });
+  assert(lib.cipher.des.decrypt(enc2Ecb, { key: k2Des, mode: 'ecb', padding: 'ISO7816' }).equals(dMsg), '3DES-EDE2-ECB ISO7816 roundtrip');
+  const ks2 = lib.cipher.des.createKeystream({ key: k2Des, iv: iv8 });
+  assert(ks2.apply(dMsg).equals(lib.cipher.des.encrypt(dMsg, { key: k2Des, iv: iv8, mode: 'ctr' })), '3DES-EDE2-CTR keystream matches one-shot CTR');
+  ks2.free();
+  assertThrows(() => lib.cipher.des.encrypt(dMsg, { key: k3Des, iv: iv8, mode: 'ctr', padding: 'PKCS7' }), '3DES-CTR rejects padding');
+  assertThrows(() => lib.cipher.des.encrypt(dMsg, { key: nodeRandomBytes(12), iv: iv8 }), 'DES rejects 12-byte keys');
+  const keyCheck = lib.cipher.des.checkKey(fips81Key);
+  assert(keyCheck.oddParity && !keyCheck.weak && !keyCheck.semiWeak && !keyCheck.degenerate, 'DES checkKey on FIPS 81 key');
+  const parityKey = lib.cipher.des.setOddParity(Buffer.alloc(8));
+  assert(hex(parityKey) === '0101010101010101', 'DES setOddParity');
+  assert(lib.cipher.des.checkKey(parityKey).weak, 'DES checkKey flags weak keys');
+  assert(lib.cipher.des.checkKey(Buffer.from('01fe01fe01fe01fe', 'hex')).semiWeak, 'DES checkKey flags semi-weak keys');
+  assert(lib.cipher.des.checkKey(Buffer.concat([fips81Key, fips81Key])).degenerate, 'DES checkKey flags K1 = K2');
+  const retailKey = Buffer.from('0123456789abcdeffedcba9876543210', 'hex');
+  const fips113 = lib.cipher.des.mac('7654321 Now is the time for ', { key: fips81Key, length: 4 });
+  assert(hex(fips113) === 'f1d30f68', 'DES MAC FIPS 113 vector (4 bytes)');
+  const retailMac = lib.cipher.des.mac(fips81Pt, { key: retailKey, algorithm: 3 });
+  assert(hex(retailMac) === 'a1c72e74ea3fa9b6', 'Retail MAC ISO 9797-1 Annex B vector');
+  assert(hex(lib.cipher.des.mac(fips81Pt, { key: retailKey, algorithm: 3, padding: 2 })) === 'e9086230ca3be796', 'Retail MAC padding method 2');
+  assert(lib.cipher.des.verifyMac(fips81Pt, retailMac.subarray(0, 4), { key: retailKey, algorithm: 3 }), 'Retail MAC verifies truncated MAC');
+  assert(!lib.cipher.des.verifyMac(dMsg, retailMac, { key: retailKey, algorithm: 3 }), 'Retail MAC rejects other data');
+  const macStream = lib.cipher.des.createMac({ key: retailKey, algorithm: 3 });
+  macStream.update(fips81Pt.subarray(0, 5)).update(fips81Pt.subarray(5, 13)).update(fips81Pt.subarray(13));
+  assert(macStream.digest().equals(retailMac), 'Retail MAC streaming matches one-shot');
+  assert(lib.cipher.des.createMac({ key: k3Des, padding: 2 }).update(dMsg).verify(lib.cipher.des.mac(dMsg, { key: k3Des, padding: 2 })), '3DES CBC-MAC streaming verify');
+  assertThrows(() => lib.cipher.des.mac(dMsg, { key: fips81Key, algorithm: 3 }), 'Retail MAC requires a 16-byte key');
+  assertThrows(() => lib.cipher.des.mac(dMsg, { key: fips81Key, length: 3 }), 'DES MAC rejects lengths below 4');
   console.table([
     { mode: 'cbc', n: 8, sample: hex(encDesCbc) + '…' },
     { mode: 'ctr', n: 8, sample: hex(enc3Ctr) + '…' },
+    { mode: 'ecb', n: 0, sample: hex(encDesEcb) + '…' },
   ]);
 
   // ChaCha20
@@ -157,11 +505,166 @@ function hex(buf, len = 32) {
   const ccAead = lib.cipher.chacha20.encrypt(aesMsg, { key: ccKey, iv: ccN, mode: 'cbc' });
   const ccAeadDec = lib.cipher.chacha20.decrypt(ccAead, { key: ccKey, iv: ccN, mode: 'cbc' });
   assert(ccAeadDec.equals(aesMsg), 'ChaCha20-Poly1305 AEAD roundtrip');
+  const ccAad = Buffer.from('header v1');
+  const ccWithAad = lib.cipher.chacha20.encrypt(aesMsg, { key: ccKey, iv: ccN, mode: 'cbc', aad: ccAad });
+  const nodeCc = createCipheriv('chacha20-poly1305', ccKey, ccN, { authTagLength: 16 });
+  nodeCc.setAAD(ccAad, { plaintextLength: aesMsg.length });
+  const nodeCcOut = Buffer.concat([nodeCc.update(aesMsg), nodeCc.final(), nodeCc.getAuthTag()]);
+  assert(ccWithAad.equals(nodeCcOut), 'ChaCha20-Poly1305 with AAD matches Node crypto');
+  assert(
+    lib.cipher.chacha20.decrypt(ccWithAad, { key: ccKey, iv: ccN, mode: 'cbc', aad: ccAad }).equals(aesMsg),
+    'ChaCha20-Poly1305 with AAD roundtrip'
+  );
+  const ccDet = lib.cipher.chacha20.encryptDetached(aesMsg, { key: ccKey, iv: ccN, aad: ccAad });
+  assert(
+    Buffer.concat([ccDet.ciphertext, ccDet.tag]).equals(nodeCcOut),
+    'ChaCha20-Poly1305 detached tag matches combined output'
+  );
+  assert(
+    lib.cipher.chacha20.decryptDetached(ccDet.ciphertext, ccDet.tag, { key: ccKey, iv: ccN, aad: ccAad }).equals(aesMsg),
+    'ChaCha20-Poly1305 detached roundtrip'
+  );
+  // XChaCha20-Poly1305 is ChaCha20-Poly1305 under the HChaCha20 subkey
+  const xN = nodeRandomBytes(24);
+  const xct = lib.cipher.chacha20.encrypt(aesMsg, { key: ccKey, iv: xN, mode: 'cbc', aad: ccAad });
+  const xSub = lib.cipher.hchacha20(ccKey, xN.subarray(0, 16));
+  const nodeX = createCipheriv('chacha20-poly1305', xSub, Buffer.concat([Buffer.alloc(4), xN.subarray(16)]), { authTagLength: 16 });
+  nodeX.setAAD(ccAad, { plaintextLength: aesMsg.length });
+  assert(
+    xct.equals(Buffer.concat([nodeX.update(aesMsg), nodeX.final(), nodeX.getAuthTag()])),
+    'XChaCha20-Poly1305 matches HChaCha20 + Node ChaCha20-Poly1305'
+  );
+  assert(
+    lib.cipher.chacha20.decrypt(xct, { key: ccKey, iv: xN, mode: 'cbc', aad: ccAad }).equals(aesMsg),
+    'XChaCha20-Poly1305 roundtrip'
+  );
+  const xCtr = lib.cipher.chacha20.encrypt(aesMsg, { key: ccKey, iv: xN, mode: 'ctr' });
+  assert(lib.cipher.chacha20.decrypt(xCtr, { key: ccKey, iv: xN, mode: 'ctr' }).equals(aesMsg), 'XChaCha20 roundtrip');
+  const ccStreamCt = sealStream(lib.cipher.chacha20, ccKey, streamMsg, 33);
+  assert(openStream(lib.cipher.chacha20, ccKey, ccStreamCt).equals(streamMsg), 'ChaCha20-Poly1305 STREAM roundtrip');
+  assertThrows(
+    () => openStream(lib.cipher.chacha20, ccKey, ccStreamCt.subarray(0, ccStreamCt.length - 1)),
+    'ChaCha20-Poly1305 STREAM detects a cut-short final segment'
+  );
+  const ccKeyed = lib.cipher.chacha20.createKeyedCipher({ key: ccKey });
+  assert(ccKeyed.encrypt(aesMsg, { iv: ccN, aad: ccAad }).equals(nodeCcOut), 'ChaCha20-Poly1305 keyed cipher matches Node crypto');
+  assert(ccKeyed.decrypt(nodeCcOut, { iv: ccN, aad: ccAad }).equals(aesMsg), 'ChaCha20-Poly1305 keyed cipher roundtrip');
+  assertThrows(() => ccKeyed.encrypt(aesMsg, { iv: xN }), 'ChaCha20-Poly1305 keyed cipher rejects a 24-byte nonce');
+  ccKeyed.free();
+  const xKeyed = lib.cipher.chacha20.createKeyedCipher({ key: ccKey, nonceLength: 24 });
+  assert(xKeyed.encrypt(aesMsg, { iv: xN, aad: ccAad }).equals(xct), 'XChaCha20-Poly1305 keyed cipher matches one-shot');
+  xKeyed.free();
+  assertThrows(() => xKeyed.decrypt(xct, { iv: xN, aad: ccAad }), 'XChaCha20-Poly1305 keyed cipher is unusable after free()');
+  for (const [name, nonce] of [['ChaCha20', ccN], ['XChaCha20', xN]]) {
+    const oneShot = lib.cipher.chacha20.encrypt(ctrMsg, { key: ccKey, iv: nonce, mode: 'ctr' });
+    const ks = lib.cipher.chacha20.createKeystream({ key: ccKey, iv: nonce });
+    assert(ks.seek(700).apply(oneShot.subarray(700)).equals(ctrMsg.subarray(700)), `${name} keystream seeks across blocks`);
+    assert(ks.seek(0).apply(ctrMsg).equals(oneShot), `${name} keystream matches one-shot`);
+    ks.free();
+  }
+  assertThrows(() => lib.cipher.chacha20.createKeystream({ key: ccKey, iv: ccN, counter: 'ctr32be' }), 'ChaCha20 keystream rejects ctr32be');
   console.table([
     { mode: 'ctr', n: 12, sample: hex(ccEnc) + '…' },
     { mode: 'cbc (alias→gcm)', n: 12, sample: hex(ccAead) + '…' },
   ]);
 
+  section('Salsa20 / secretbox');
+  // libsodium test/default/secretbox.c (from NaCl)
+  const naclKey = Buffer.from('1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389', 'hex');
+  const naclNonce = Buffer.from('69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37', 'hex');
+  const naclMsg = Buffer.from(
+    'be075fc53c81f2d5cf141316ebeb0c7b5228c52a4c62cbd44b66849b64244ffce5ecbaaf33bd751a1ac728d45e6c61296cdc3c01233561f41db66cce314adb31' +
+      '0e3be8250c46f06dceea3a7fa1348057e2f6556ad6b1318a024a838f21af1fde048977eb48f59ffd4924ca1c60902e52f0a089bc76897040e082f937763848645e0705',
+    'hex'
+  );
+  const naclBox = lib.cipher.secretbox.seal(naclMsg, naclKey, naclNonce);
+  assert(
+    naclBox.toString('hex') ===
+      'f3ffc7703f9400e52a7dfb4b3d3305d98e993b9f48681273c29650ba32fc76ce48332ea7164d96a4476fb8c531a1186ac0dfc17c98dce87b4da7f011ec48c972' +
+        '71d2c20f9b928fe2270d6fb863d51738b48eeee314a7cc8ab932164548e526ae90224368517acfeabd6bb3732bc0e9da99832b61ca01b6de56244a9e88d5f9b37973f622a43d14a6599b1f654cb45a74e355a5',
+    'secretbox matches the libsodium vector'
+  );
+  assert(lib.cipher.secretbox.open(naclBox, naclKey, naclNonce).equals(naclMsg), 'secretbox roundtrip');
+  const badBox = Buffer.from(naclBox);
+  badBox[0] ^= 1;
+  assertThrows(() => lib.cipher.secretbox.open(badBox, naclKey, naclNonce), 'secretbox rejects a forged tag');
+  const xsStream = lib.cipher.salsa20.encrypt(Buffer.alloc(32 + naclMsg.length), { key: naclKey, iv: naclNonce });
+  assert(
+    xsStream.subarray(0, 32).toString('hex') === 'eea6a7251c1e72916d11c2cb214d3c252539121d8e234e652d651fa4c8cff880',
+    'XSalsa20 matches the libsodium stream3 vector'
+  );
+  const naclBody = Buffer.from(naclMsg.map((b, i) => b ^ xsStream[32 + i]));
+  assert(naclBox.subarray(16).equals(naclBody), 'secretbox body is the XSalsa20 stream from byte 32');
+  const s20 = lib.cipher.salsa20.encrypt(ctrMsg, { key: ccKey, iv: n12.subarray(0, 8) });
+  assert(lib.cipher.salsa20.decrypt(s20, { key: ccKey, iv: n12.subarray(0, 8) }).equals(ctrMsg), 'Salsa20 roundtrip');
+  assertThrows(() => lib.cipher.salsa20.encrypt(ctrMsg, { key: ccKey, iv: n12 }), 'Salsa20 rejects a 12-byte nonce');
+
+  section('Camellia / ARIA / SM4');
+  for (const [name, api, nodeName, keyLens] of [
+    ['Camellia', lib.cipher.camellia, 'camellia', [16, 24, 32]],
+    ['ARIA', lib.cipher.aria, 'aria', [16, 24, 32]],
+    ['SM4', lib.cipher.sm4, 'sm4', [16]],
+  ]) {
+    for (const len of keyLens) {
+      const key = nodeRandomBytes(len);
+      const cipherName = nodeName === 'sm4' ? 'sm4' : `${nodeName}-${len * 8}`;
+      for (const mode of ['cbc', 'ctr']) {
+        const e = createCipheriv(`${cipherName}-${mode}`, key, n16);
+        const nodeOut = Buffer.concat([e.update(ctrMsg), e.final()]);
+        const ct = api.encrypt(ctrMsg, { key, iv: n16, mode });
+        assert(ct.equals(nodeOut), `${name}-${len * 8}-${mode.toUpperCase()} matches Node crypto`);
+        assert(api.decrypt(ct, { key, iv: n16, mode }).equals(ctrMsg), `${name}-${len * 8}-${mode.toUpperCase()} roundtrip`);
+      }
+      const gcm = api.encrypt(aesMsg, { key, iv: n12, mode: 'gcm', aad });
+      assert(api.decrypt(gcm, { key, iv: n12, mode: 'gcm', aad }).equals(aesMsg), `${name}-${len * 8}-GCM roundtrip`);
+      assertThrows(() => api.decrypt(gcm, { key, iv: n12, mode: 'gcm' }), `${name}-${len * 8}-GCM rejects missing AAD`);
+      if (nodeName === 'aria') {
+        const e = createCipheriv(`aria-${len * 8}-gcm`, key, n12);
+        e.setAAD(aad);
+        const nodeGcm = Buffer.concat([e.update(aesMsg), e.final(), e.getAuthTag()]);
+        assert(gcm.equals(nodeGcm), `ARIA-${len * 8}-GCM matches Node crypto`);
+      }
+    }
+  }
+  assertThrows(() => lib.cipher.sm4.encrypt(aesMsg, { key: key256, iv: n16 }), 'SM4 rejects a 32-byte key');
+  assertThrows(() => lib.cipher.camellia.encrypt(aesMsg, { key: key128, iv: n12, mode: 'cbc' }), 'Camellia-CBC rejects a 12-byte IV');
+  assertThrows(() => lib.cipher.aria.encrypt(aesMsg, { key: key128, iv: n16, mode: 'ecb' }), 'ARIA rejects ECB');
+
+  section('Ascon');
+  // ascon-c LWC_AEAD_KAT_128_128 Count 545: PT 20..2f, AD 30..40
+  const seqBytes = (first, len) => Buffer.from(Array.from({ length: len }, (_, i) => (first + i) & 0xff));
+  const asconKey = seqBytes(0x00, 16);
+  const asconN = seqBytes(0x10, 16);
+  const asconAd = seqBytes(0x30, 17);
+  const asconCt = lib.cipher.ascon.encrypt(seqBytes(0x20, 16), { key: asconKey, iv: asconN, aad: asconAd });
+  assert(
+    asconCt.toString('hex') === 'bf77c71b3de9f1c5b372ef273a08e89b6a67a7a5995c860ce8f00bc0fc2ebad6',
+    'Ascon-AEAD128 matches the reference KAT'
+  );
+  assert(
+    lib.cipher.ascon.decrypt(asconCt, { key: asconKey, iv: asconN, aad: asconAd }).equals(seqBytes(0x20, 16)),
+    'Ascon-AEAD128 roundtrip'
+  );
+  assertThrows(() => lib.cipher.ascon.decrypt(asconCt, { key: asconKey, iv: asconN }), 'Ascon-AEAD128 rejects missing AAD');
+  assertThrows(() => lib.cipher.ascon.encrypt(aesMsg, { key: key256, iv: asconN }), 'Ascon-AEAD128 rejects a 32-byte key');
+  assertThrows(() => lib.cipher.ascon.encrypt(aesMsg, { key: asconKey, iv: n12 }), 'Ascon-AEAD128 rejects a 12-byte nonce');
+  // ascon-c LWC_HASH_KAT_128_256 Count 1 (empty message)
+  assert(
+    lib.sha.ascon_hash256('') === '0b3be5850f2f6b98caf29f8fdea89b64a1fa70aa249b8f839bd53baa304d92b2',
+    'Ascon-Hash256 matches the reference KAT'
+  );
+  assert(
+    lib.sha.ascon_xof128('') === '473d5e6164f58b39dfd84aacdb8ae42ec2d91fed33388ee0d960d9b3993295c6',
+    'Ascon-XOF128 matches the reference KAT'
+  );
+  assert(lib.sha.ascon_xof128(msg, { hashLength: 64 }).length === 128, 'Ascon-XOF128 honours hashLength');
+  const asconH = lib.sha.ascon_hash256.create();
+  asconH.update('Hello').update(' World');
+  assert(asconH.digest('hex') === lib.sha.ascon_hash256(msg), 'streaming Ascon-Hash256 matches one-shot');
+  const asconX = lib.sha.ascon_xof128.create({ hashLength: 48 });
+  asconX.update(msg);
+  assert(asconX.digest('hex') === lib.sha.ascon_xof128(msg, { hashLength: 48 }), 'streaming Ascon-XOF128 matches one-shot');
+
   // Key exchange
   section('Key Exchange');
   const x1 = lib.cipher.x25519.generateKeypair();
@@ -169,6 +672,33 @@ function hex(buf, len = 32) {
   const xs1 = lib.cipher.x25519.deriveSharedSecret(x1.privateKey, x2.publicKey);
   const xs2 = lib.cipher.x25519.deriveSharedSecret(x2.privateKey, x1.publicKey);
   assert(Buffer.compare(xs1, xs2) === 0, 'X25519 shared secret matches');
+  // libsodium test/default/box.c: Alice boxes the secretbox message to Bob
+  const aliceSk = Buffer.from('77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a', 'hex');
+  const alicePk = Buffer.from('8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a', 'hex');
+  const bobSk = Buffer.from('5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb', 'hex');
+  const bobPk = Buffer.from('de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f', 'hex');
+  assert(lib.cipher.x25519.boxBeforenm(bobPk, aliceSk).equals(naclKey), 'crypto_box_beforenm matches libsodium');
+  const naclCryptoBox = lib.cipher.x25519.boxSeal(naclMsg, naclNonce, bobPk, aliceSk);
+  assert(naclCryptoBox.equals(naclBox), 'crypto_box matches the libsodium vector');
+  assert(lib.cipher.x25519.boxOpen(naclCryptoBox, naclNonce, alicePk, bobSk).equals(naclMsg), 'crypto_box roundtrip');
+  assertThrows(() => lib.cipher.x25519.boxOpen(badBox, naclNonce, alicePk, bobSk), 'crypto_box rejects a forged tag');
+  assertThrows(() => lib.cipher.x25519.boxBeforenm(Buffer.alloc(32), aliceSk), 'crypto_box rejects a small-order key');
+  // Produced by libsodium crypto_box_seal
+  const libsodiumSealed = Buffer.from(
+    'b47fb7572e3019668217bdc4052c468bdd815e5933aac6d7a32aff058085575c094f41579b111a550abc9e2e5423182efeff340b512ae2ec228c80103cdb',
+    'hex'
+  );
+  assert(
+    lib.cipher.x25519.sealedBoxOpen(libsodiumSealed, bobPk, bobSk).toString() === 'sealed for bob',
+    'sealed box opens libsodium output'
+  );
+  const sealed = lib.cipher.x25519.sealedBoxSeal(msg, x2.publicKey);
+  assert(sealed.length === Buffer.byteLength(msg) + 48, 'sealed box adds 48 bytes');
+  assert(
+    lib.cipher.x25519.sealedBoxOpen(sealed, x2.publicKey, x2.privateKey).equals(Buffer.from(msg)),
+    'sealed box roundtrip'
+  );
+  assertThrows(() => lib.cipher.x25519.sealedBoxOpen(sealed, x1.publicKey, x1.privateKey), 'sealed box rejects the wrong recipient');
   console.table([{ algo: 'x25519', shared: hex(xs1, 48) + '…' }]);
 
   // ECDH
@@ -188,6 +718,104 @@ function hex(buf, len = 32) {
     { algo: 'ecdh (p384)', shared: hex(rs1, 48) + '…' },
   ]);
 
+  // HPKE (RFC 9180)
+  section('HPKE');
+  // RFC 9180 A.1.1: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, AES-128-GCM, base mode
+  const hpkeR = lib.cipher.hpke.deriveKeypair(
+    Buffer.from('6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037', 'hex')
+  );
+  const hpkeInfo = Buffer.from('Ode on a Grecian Urn');
+  const rfcOpened = lib.cipher.hpke.open(
+    Buffer.from('f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a', 'hex'),
+    Buffer.from('37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431', 'hex'),
+    hpkeR.privateKey,
+    { info: hpkeInfo, aad: Buffer.from('436f756e742d30', 'hex') }
+  );
+  assert(rfcOpened.toString() === 'Beauty is truth, truth beauty', 'HPKE opens the RFC 9180 A.1.1 vector');
+
+  for (const [kem, kdf, aead] of [
+    ['x25519', 'sha256', 'aes-128-gcm'],
+    ['p256', 'sha256', 'aes-256-gcm'],
+    ['p384', 'sha384', 'chacha20-poly1305'],
+  ]) {
+    const suite = { kem, kdf, aead };
+    const r = lib.cipher.hpke.generateKeypair(suite);
+    const { enc, ciphertext } = lib.cipher.hpke.seal(msg, r.publicKey, { ...suite, info: hpkeInfo, aad: 'hdr' });
+    const opened = lib.cipher.hpke.open(ciphertext, enc, r.privateKey, { ...suite, info: hpkeInfo, aad: 'hdr' });
+    assert(opened.equals(Buffer.from(msg)), `HPKE ${kem}/${kdf}/${aead} roundtrip`);
+    assertThrows(
+      () => lib.cipher.hpke.open(ciphertext, enc, r.privateKey, { ...suite, info: 'other', aad: 'hdr' }),
+      `HPKE ${kem} rejects a different info`
+    );
+  }
+
+  const hpkeS = lib.cipher.hpke.generateKeypair();
+  const hpkePsk = { psk: Buffer.alloc(32, 7), pskId: 'psk-1' };
+  const authPsk = lib.cipher.hpke.seal(msg, hpkeR.publicKey, { ...hpkePsk, authKey: hpkeS.privateKey });
+  assert(
+    lib.cipher.hpke
+      .open(authPsk.ciphertext, authPsk.enc, hpkeR.privateKey, { ...hpkePsk, authKey: hpkeS.publicKey })
+      .equals(Buffer.from(msg)),
+    'HPKE auth-PSK roundtrip'
+  );
+  assertThrows(
+    () => lib.cipher.hpke.open(authPsk.ciphertext, authPsk.enc, hpkeR.privateKey, { authKey: hpkeS.publicKey }),
+    'HPKE auth-PSK rejects a missing PSK'
+  );
+  assertThrows(
+    () => lib.cipher.hpke.open(authPsk.ciphertext, authPsk.enc, hpkeR.privateKey, { ...hpkePsk, authKey: x1.publicKey }),
+    'HPKE auth-PSK rejects the wrong sender'
+  );
+
+  const hpkeTx = lib.cipher.hpke.setupSender(hpkeR.publicKey, { info: hpkeInfo });
+  const hpkeRx = lib.cipher.hpke.setupRecipient(hpkeTx.enc, hpkeR.privateKey, { info: hpkeInfo });
+  const hpkeCt0 = hpkeTx.seal('first');
+  const hpkeCt1 = hpkeTx.seal('second');
+  assertThrows(() => hpkeRx.open(hpkeCt1), 'HPKE context rejects out-of-order messages');
+  assert(hpkeRx.open(hpkeCt0).toString() === 'first', 'HPKE context opens message 0');
+  assert(hpkeRx.open(hpkeCt1).toString() === 'second', 'HPKE context opens message 1');
+  assert(
+    hpkeTx.export('TestContext', 32).equals(hpkeRx.export('TestContext', 32)),
+    'HPKE exported secrets match'
+  );
+  hpkeTx.free();
+  hpkeRx.free();
+  console.table([{ algo: 'hpke (x25519)', enc: hex(authPsk.enc, 48) + '…' }]);
+
+  // ECIES (eciesjs wire format)
+  section('ECIES');
+  const eciesjsCt = Buffer.from(
+    '047be1885aeb48d4d4db0c992996725d3264784fef88c5b60782f8d0f940c213227fc3f904f846d5ec3d0fba6653754501e8ebadc421aa3892a20fef33cff0206047058a4cfb4efbeae96b2d019b4ab2edce33328748a0d008a69c8f5816b72d45bd9b5a41bb6ea0127ab23057ec6fcd',
+    'hex'
+  );
+  const eciesjsSk = Buffer.from('e520872701d9ec44dbac2eab85512ad14ad0c42e01de56d7b528abd8524fcb47', 'hex');
+  assert(
+    lib.cipher.ecies.decrypt(eciesjsCt, eciesjsSk).toString() === 'hello world🌍',
+    'ECIES decrypts an eciesjs ciphertext'
+  );
+  for (const [curve, aead, compressedEphemeralKey] of [
+    ['secp256k1', 'aes-256-gcm', false],
+    ['secp256k1', 'xchacha20-poly1305', true],
+    ['p256', 'aes-256-gcm', true],
+  ]) {
+    const opts = { curve, aead, compressedEphemeralKey };
+    const k = lib.cipher.ecies.generateKeypair(opts);
+    const ct = lib.cipher.ecies.encrypt(msg, k.publicKey, opts);
+    const eciesLen = (compressedEphemeralKey ? 33 : 65) + (aead === 'aes-256-gcm' ? 16 : 24) + 16 + Buffer.byteLength(msg);
+    assert(ct.length === eciesLen, `ECIES ${curve}/${aead} length`);
+    assert(lib.cipher.ecies.decrypt(ct, k.privateKey, opts).equals(Buffer.from(msg)), `ECIES ${curve}/${aead} roundtrip`);
+    const badCt = Buffer.from(ct);
+    badCt[badCt.length - 1] ^= 1;
+    assertThrows(() => lib.cipher.ecies.decrypt(badCt, k.privateKey, opts), `ECIES ${curve}/${aead} rejects tampering`);
+  }
+  const k1 = lib.cipher.ecies.generateKeypair();
+  const rawCt = lib.cipher.ecies.encrypt(msg, k1.publicKey.subarray(1), { nonceLength: 12 });
+  assert(
+    lib.cipher.ecies.decrypt(rawCt, k1.privateKey, { nonceLength: 12 }).equals(Buffer.from(msg)),
+    'ECIES accepts a raw 64-byte public key'
+  );
+  assertThrows(() => lib.cipher.ecies.decrypt(rawCt, k1.privateKey), 'ECIES rejects a mismatched nonce length');
+
   // DSA – Ed25519 / ECDSA / RSA
   section('DSA');
   // Ed25519
@@ -232,10 +860,72 @@ function hex(buf, len = 32) {
   const wrapped = lib.cipher.rsa_oaep.encrypt(dataKey, publicKey, { hash: 'sha256' });
   const unwrapped = lib.cipher.rsa_oaep.decrypt(wrapped, privateKey, { hash: 'sha256' });
   assert(unwrapped.equals(dataKey), 'RSA-OAEP unwrap matches');
+  const document = nodeRandomBytes(100000);
+  const envelope = lib.cipher.rsa_oaep.envelopeEncrypt(document, publicKey, { hash: 'sha256', label: 'doc' });
+  assert(envelope[0] === 1 && envelope[1] === 2, 'RSA envelope header is version 1, SHA-256');
+  assert(lib.cipher.rsa_oaep.envelopeDecrypt(envelope, privateKey, { label: 'doc' }).equals(document), 'RSA envelope roundtrip');
+  const badEnvelope = Buffer.from(envelope);
+  badEnvelope[badEnvelope.length - 1] ^= 1;
+  assertThrows(() => lib.cipher.rsa_oaep.envelopeDecrypt(badEnvelope, privateKey, { label: 'doc' }), 'RSA envelope rejects tampering');
+  assertThrows(() => lib.cipher.rsa_oaep.envelopeDecrypt(envelope, privateKey), 'RSA envelope rejects a missing label');
   console.table([
     { mode: 'rsa-oaep', n: 12, sample: hex(wrapped) + '…' },
   ]);
 
+  // Payments – PIN blocks / KCV / TR-31
+  section('Payments');
+  const payments = lib.payments;
+  const tdesKey = Buffer.from('0123456789abcdeffedcba9876543210', 'hex');
+  const aesKey = Buffer.from('2b7e151628aed2a6abf7158809cf4f3c', 'hex');
+  assert(hex(payments.kcv(tdesKey, { algorithm: 'des' })) === '08d7b4', 'KCV 3DES zero block');
+  const cmacTdesKey = Buffer.from('8aa83bf8cbda10620bc1bf19fbb6cd58bc313d4a371ca8b5', 'hex');
+  assert(hex(payments.kcv(cmacTdesKey, { algorithm: 'des', method: 'cmac' })) === '89dbdd55e0', 'KCV 3DES CMAC');
+  assert(hex(payments.kcv(aesKey, { algorithm: 'aes' })) === '7df76b', 'KCV AES zero block');
+  assert(hex(payments.kcv(aesKey, { algorithm: 'aes', method: 'cmac' })) === '7ad386c376', 'KCV AES CMAC');
+
+  const pan = '43219876543210987';
+  assert(hex(payments.pinBlock.encode('1234', pan)) === '0412ac89abcdef67', 'PIN block ISO-0 encode');
+  assert(payments.pinBlock.decode(Buffer.from('0412ac89abcdef67', 'hex'), pan) === '1234', 'PIN block ISO-0 decode');
+  for (const format of [0, 1, 3]) {
+    const epb = payments.pinBlock.encrypt('123456', pan, tdesKey, format);
+    assert(epb.length === 8, `PIN block ISO-${format} length`);
+    assert(payments.pinBlock.decrypt(epb, pan, tdesKey, format) === '123456', `PIN block ISO-${format} roundtrip`);
+  }
+  const iso4Key = Buffer.from('00112233445566778899aabbccddeeff', 'hex');
+  const iso4Pan = '432198765432109870';
+  const epb4 = payments.pinBlock.encrypt('1234', iso4Pan, iso4Key, 4);
+  assert(payments.pinBlock.decrypt(epb4, iso4Pan, iso4Key, 4) === '1234', 'PIN block ISO-4 roundtrip');
+  assertThrows(() => payments.pinBlock.decrypt(epb4, '432198765432109871', iso4Key, 4), 'PIN block ISO-4 wrong PAN');
+  assertThrows(() => payments.pinBlock.encode('123', pan), 'PIN block rejects a 3-digit PIN');
+
+  const kbpkD = Buffer.from('88e1ab2a2e3dd38c1fa039a536500cc8a87ab9d62dc92c01058fa79f44657de6', 'hex');
+  const knownD =
+    'D0112P0AE00E0000B82679114F470F540165EDFBF7E250FCEA43F810D215F8D207E2E417C07156A27E8E31DA05F7425509593D03A457DC34';
+  const tr31Known = payments.tr31.unwrap(kbpkD, knownD);
+  assert(hex(tr31Known.key) === '3f419e1cb7079442aa37474c2efbf8b8', 'TR-31 version D known block');
+  assert(tr31Known.header.keyUsage === 'P0' && tr31Known.header.modeOfUse === 'E', 'TR-31 header fields');
+  const tr31KnownB = payments.tr31.unwrap(
+    Buffer.from('dd7515f2bfc17f85ce48f3ca25cb21f6', 'hex'),
+    'B0080P0TE00E000094B420079CC80BA3461F86FE26EFC4A3B8E4FA4C5F5341176EED7B727B8A248E'
+  );
+  assert(hex(tr31KnownB.key) === '3f419e1cb7079442aa37474c2efbf8b8', 'TR-31 version B known block');
+  const tr31Header = {
+    version: 'B',
+    keyUsage: 'P0',
+    algorithm: 'T',
+    modeOfUse: 'E',
+    optionalBlocks: [{ id: 'KS', data: '00604B120F929280000' }],
+  };
+  const kbpkB = Buffer.from('89e88cf7931444f334bd7547fc3f380c', 'hex');
+  const keyBlock = payments.tr31.wrap(kbpkB, tr31Header, tdesKey);
+  assert(keyBlock.startsWith('B0112P0TE00E0200KS1700604B120F929280000PB0900000'), 'TR-31 version B header');
+  const roundtrip = payments.tr31.unwrap(kbpkB, keyBlock);
+  assert(roundtrip.key.equals(tdesKey), 'TR-31 version B roundtrip');
+  assert(roundtrip.header.optionalBlocks[0].id === 'KS', 'TR-31 optional blocks');
+  const tampered = keyBlock.slice(0, -1) + (keyBlock.endsWith('0') ? '1' : '0');
+  assertThrows(() => payments.tr31.unwrap(kbpkB, tampered), 'TR-31 rejects tampering');
+  console.table([{ format: 'tr31 B', sample: keyBlock.slice(0, 32) + '…' }]);
+
   // KDF
   section('KDF');
   const salt = nodeRandomBytes(16);